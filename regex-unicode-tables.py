@@ -20,6 +20,19 @@ import urllib2
 BASE_URL = 'http://www.unicode.org/Public/6.3.0/ucd/'
 DATA = 'UnicodeData.txt'
 SCRIPTS = 'Scripts.txt'
+SCRIPT_EXTS = 'ScriptExtensions.txt'
+ALIASES = 'PropertyValueAliases.txt'
+PROPS = 'PropList.txt'
+CORE_PROPS = 'DerivedCoreProperties.txt'
+BLOCKS = 'Blocks.txt'
+
+# Binary properties that are available as classes, e.g., \p{Alphabetic}.
+binary_props = [
+    'Alphabetic', 'ASCII_Hex_Digit', 'Case_Ignorable', 'Cased', 'Dash',
+    'Diacritic', 'Hex_Digit', 'ID_Continue', 'ID_Start', 'Ideographic',
+    'Join_Control', 'Lowercase', 'Math', 'Quotation_Mark', 'Uppercase',
+    'White_Space', 'XID_Continue', 'XID_Start',
+]
 
 # Mapping taken from Table 12 from:
 # http://www.unicode.org/reports/tr44/#General_Category_Values
@@ -80,6 +93,62 @@ def read_scripts(f):
     return assigned
 
 
+def read_script_aliases(f):
+    aliases = {}
+    for line in f:
+        line = line.strip()
+        if not line or line.startswith('#'):
+            continue
+        fields = map(str.strip, line.split('#')[0].split(';'))
+        if fields[0] == 'sc':
+            aliases[fields[1]] = fields[2]
+    return aliases
+
+
+def read_script_exts(f, scripts, aliases):
+    # Characters not listed in ScriptExtensions.txt have a single script
+    # extension: their script. Listed characters have exactly the scripts
+    # given (with short names), which replace their script.
+    listed = defaultdict(list)
+    for line in f:
+        line = line.strip()
+        if not line or line.startswith('#'):
+            continue
+        hexes, names = map(str.strip, line.split('#')[0].split(';'))
+        if '..' not in hexes:
+            hex1 = hex2 = int(hexes, 16)
+        else:
+            hex1, hex2 = map(lambda s: int(s, 16), hexes.split('..'))
+        for hex in xrange(hex1, hex2 + 1):
+            if is_valid_unicode(hex):
+                listed[hex].extend(aliases[name] for name in names.split())
+
+    assigned = defaultdict(list)
+    for name, letters in scripts.items():
+        assigned[name] = [hex for hex in letters if hex not in listed]
+    for hex, names in listed.items():
+        for name in names:
+            assigned[name].append(hex)
+    return assigned
+
+
+def read_blocks(f):
+    # Block names have spaces, e.g., 'Greek and Coptic'. Surrogate blocks
+    # have no valid characters, so they're left out.
+    blocks = {}
+    for line in f:
+        line = line.split('#')[0].strip()
+        if not line:
+            continue
+        hexes, name = map(str.strip, line.split(';'))
+        hex1, hex2 = map(lambda s: int(s, 16), hexes.split('..'))
+        letters = [hex for hex in xrange(hex1, hex2 + 1)
+                   if is_valid_unicode(hex)]
+        if letters:
+            blocks[name.replace(' ', '_')] = letters
+    return blocks
+
+
 def group(letters):
     letters = sorted(set(letters))
     grouped = []
@@ -116,23 +185,34 @@ if __name__ == '__main__':
         description='Generate Unicode character class tables.')
     aa = parser.add_argument
     aa('--local', action='store_true',
-       help='When set, Scripts.txt and UnicodeData.txt will be read from '
-            'the CWD.')
+       help='When set, the Unicode data files will be read from the CWD.')
     aa('--base-url', type=str, default=BASE_URL,
        help='The base URL to use for downloading Unicode data files.')
     args = parser.parse_args()
 
-    if args.local:
-        cats = read_cats(open(DATA))
-        scripts = read_scripts(open(SCRIPTS))
-    else:
-        cats = read_cats(urllib2.urlopen(args.base_url + '/' + DATA))
-        scripts = read_scripts(urllib2.urlopen(args.base_url + '/' + SCRIPTS))
-
-    # Get Rust code for all Unicode general categories and scripts.
+    def open_data(name):
+        if args.local:
+            return open(name)
+        return urllib2.urlopen(args.base_url + '/' + name)
+
+    cats = read_cats(open_data(DATA))
+    scripts = read_scripts(open_data(SCRIPTS))
+    aliases = read_script_aliases(open_data(ALIASES))
+    script_exts = read_script_exts(open_data(SCRIPT_EXTS), scripts, aliases)
+    # Property files have the same format as Scripts.txt.
+    props = dict(read_scripts(open_data(PROPS)),
+                 **read_scripts(open_data(CORE_PROPS)))
+
+    # Get Rust code for all Unicode general categories, scripts and binary
+    # properties.
     combined = dict(cats, **scripts)
+    combined.update((name, props[name]) for name in binary_props)
     unigroups = groups_to_rust({k: group(letters)
                                 for k, letters in combined.items()})
+    scxgroups = groups_to_rust({k: group(letters)
+                                for k, letters in script_exts.items()})
+    blockgroups = groups_to_rust({k: group(letters) for k, letters
+                                  in read_blocks(open_data(BLOCKS)).items()})
 
     # Now get Perl character classes that are Unicode friendly.
     perld = range(ord('0'), ord('9') + 1)
@@ -166,6 +246,18 @@ pub static UNICODE_CLASSES: NamedClasses = &[
 
 ];
 
+pub static SCRIPT_EXTENSIONS: NamedClasses = &[
+
+{scxgroups}
+
+];
+
+pub static UNICODE_BLOCKS: NamedClasses = &[
+
+{blockgroups}
+
+];
+
 pub static PERLD: Class = &[
     {dgroups}
 ];
@@ -179,5 +271,6 @@ pub static PERLW: Class = &[
 ];
 '''
     now = datetime.datetime.now()
-    print(tpl.format(date=str(now), groups=unigroups,
-                     dgroups=dgroups, sgroups=sgroups, wgroups=wgroups))
+    print(tpl.format(date=str(now), groups=unigroups, scxgroups=scxgroups,
+                     blockgroups=blockgroups, dgroups=dgroups,
+                     sgroups=sgroups, wgroups=wgroups))
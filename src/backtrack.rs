@@ -0,0 +1,485 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This module implements a backtracking engine for programs that the VM in
+// `vm.rs` can't execute (i.e., those with backreferences, lookarounds or
+// atomic groups). Such programs can only be written when backtracking has
+// been enabled with `RegexBuilder::backtrack`.
+//
+// The engine follows one path through the program at a time, trying the
+// alternatives of each `Split` in order of preference and backing up when a
+// path fails. So the first match found is the leftmost-first match, just
+// like the VM.
+//
+// Backtracking can take exponential time, so each state (an instruction at a
+// position in the input) is remembered once it has been tried. When a state
+// is reached again, it must have failed before, so it's skipped. Without
+// backreferences, whether a state can lead to a match doesn't depend on how
+// it was reached, which bounds the work done by a search to `O(nm)`, like the
+// VM (not counting the separate searches described below). With
+// backreferences, the capture locations matter too, so they're made part of
+// each state. There can be exponentially many of those, so the number of
+// states that may be tried from any one starting position is limited by
+// `Program.backtrack_limit`. If the limit is exceeded, the whole search stops
+// and reports that there is no match.
+// (The counters of counted loops are always part of each state, since they
+// decide where a loop can go, but there are only as many of them as there
+// are repetitions.)
+//
+// The body of a lookaround or atomic group is matched by a separate search
+// that starts where the group is and stops at the first `LookEnd` reached.
+// (So the rest of the expression can't make an atomic group backtrack into a
+// different match.) It has its own set of visited states, since the states
+// that led to a match of the body didn't fail, and its steps count towards
+// the same limit. The body of a lookbehind is compiled in reverse, so its
+// search reads the input backwards (like the VM does with a reverse
+// program).
+
+use collections::HashSet;
+use std::cmp;
+use std::mem;
+use compile::{
+    Program,
+    Match, OneChar, CharClass, Any, EmptyBegin, EmptyEnd, EmptyWordBoundary,
+    Save, OneBackref, LookStart, AtomicStart, LookEnd, Jump, Split,
+    CountedLoop, CountedNext,
+};
+use parse::{FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED,
+            FLAG_UNICODE, FLAG_FINAL_NEWLINE};
+use vm::{CaptureLocs, MatchKind, Exists, Location, Submatches, CharReader};
+use vm::class_cmp;
+
+/// Runs the program given on the search text `input` with a backtracking
+/// search. The arguments and the return value are the same as for `vm::run`.
+///
+/// If the search takes more than `prog.backtrack_limit` steps from any one
+/// starting position, then it stops and no match is returned. (Use
+/// `try_run` to find out when that happens.)
+pub fn run<'r, 't>(which: MatchKind, prog: &'r Program, input: &'t str,
+                   start: uint, end: uint) -> CaptureLocs {
+    match try_run(which, prog, input, start, end) {
+        Some(caps) => caps,
+        None => match which {
+            Exists | Location => vec![None, None],
+            Submatches => Vec::from_elem(prog.num_slots(), None),
+        },
+    }
+}
+
+/// Runs the program like `run`, except that `None` is returned if the
+/// search takes more than `prog.backtrack_limit` steps from any one starting
+/// position (in which case it can't tell whether there's a match).
+pub fn try_run<'r, 't>(which: MatchKind, prog: &'r Program, input: &'t str,
+                       start: uint, end: uint) -> Option<CaptureLocs> {
+    let nslots = prog.num_slots();
+    let keyed_caps = prog.insts.iter().any(|inst| {
+        match *inst {
+            OneBackref(_, _) => true,
+            _ => false,
+        }
+    });
+    let mut b = Backtrack {
+        prog: prog,
+        input: input,
+        end: end,
+        caps: Vec::from_elem(nslots, None),
+        counts: Vec::from_elem(prog.num_counters(), 0u),
+        jobs: vec!(),
+        visited: HashSet::new(),
+        keyed_caps: keyed_caps,
+        steps: 0,
+        exceeded: false,
+        reverse: false,
+    };
+    let matched = b.run(start);
+    if b.exceeded {
+        return None
+    }
+    Some(match which {
+        Exists if matched => vec![Some(0), Some(0)],
+        Exists => vec![None, None],
+        Location if matched => vec![*b.caps.get(0), *b.caps.get(1)],
+        Location => vec![None, None],
+        Submatches if matched => b.caps,
+        Submatches => Vec::from_elem(nslots, None),
+    })
+}
+
+// A job on the backtracking stack.
+enum Job {
+    // Try the instruction at the given position.
+    Step(uint, uint),
+    // Restore a capture slot to its value before the path being abandoned
+    // changed it.
+    RestoreCapture(uint, Option<uint>),
+    // Set a counter, either to restore it or to prepare for trying an
+    // alternative.
+    RestoreCount(uint, uint),
+}
+
+struct Backtrack<'r, 't> {
+    prog: &'r Program,
+    input: &'t str,
+    end: uint,
+    caps: CaptureLocs,
+    counts: Vec<uint>,
+    jobs: Vec<Job>,
+    // The states that have been tried. The capture locations are empty
+    // unless `keyed_caps` is true.
+    visited: HashSet<(uint, uint, CaptureLocs, Vec<uint>)>,
+    keyed_caps: bool,
+    // The number of states tried from the current starting position.
+    steps: uint,
+    // Whether `steps` went over the limit. No more states are tried once it
+    // has, and the search reports no match.
+    exceeded: bool,
+    // Whether the input is being read backwards (in a lookbehind).
+    reverse: bool,
+}
+
+impl<'r, 't> Backtrack<'r, 't> {
+    // Tries every starting position in order and returns true at the first
+    // one that leads to a match. (If the program is anchored at the start,
+    // then only `start` is tried.) The capture locations are left in `caps`.
+    // Returns false without trying the rest if the step limit is exceeded.
+    fn run(&mut self, start: uint) -> bool {
+        let mut s = start;
+        loop {
+            if self.prog.has_prefix() {
+                let haystack = self.input.as_bytes().slice(s, self.end);
+                match self.prog.find_prefix(haystack) {
+                    None => return false,
+                    Some(i) => s += i,
+                }
+            }
+            if self.run_from(s) {
+                return true
+            }
+            if self.exceeded || s >= self.end || self.prog.anchored_start {
+                return false
+            }
+            s = self.input.char_range_at(s).next;
+        }
+    }
+
+    // Returns true if there is a match starting at `start`.
+    fn run_from(&mut self, start: uint) -> bool {
+        self.steps = 0;
+        if self.keyed_caps {
+            // States from other starting positions can't be reached again,
+            // since the start of the match is part of every state.
+            self.visited.clear();
+        }
+        for slot in self.caps.mut_iter() {
+            *slot = None;
+        }
+        for count in self.counts.mut_iter() {
+            *count = 0;
+        }
+        self.search(0, start).is_some()
+    }
+
+    // Searches for a path from `pc` at `pos` to a `Match` or `LookEnd`
+    // instruction, trying alternatives in order. If one is found, then the
+    // position where it ends is returned. Either way, the jobs pushed during
+    // the search are removed. It gives up if the step limit is exceeded.
+    fn search(&mut self, pc: uint, pos: uint) -> Option<uint> {
+        let base = self.jobs.len();
+        self.jobs.push(Step(pc, pos));
+        while self.jobs.len() > base {
+            if self.exceeded {
+                self.jobs.truncate(base);
+                return None
+            }
+            match self.jobs.pop().unwrap() {
+                RestoreCapture(slot, old) => *self.caps.get_mut(slot) = old,
+                RestoreCount(k, n) => *self.counts.get_mut(k) = n,
+                Step(pc, pos) => {
+                    let end = self.step(pc, pos);
+                    if end.is_some() {
+                        self.jobs.truncate(base);
+                        return end
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    // Runs a separate search for the body of a lookaround or atomic group
+    // starting at `pc`, which reads the input backwards if `reverse` is true.
+    // Returns where the first match of the body ends, if there is one. The
+    // captures set by the body are only kept if it matches and `keep_caps`
+    // is true.
+    fn sub_search(&mut self, pc: uint, pos: uint, reverse: bool,
+                  keep_caps: bool) -> Option<uint> {
+        let visited = mem::replace(&mut self.visited, HashSet::new());
+        let old_reverse = self.reverse;
+        let caps = self.caps.clone();
+        self.reverse = reverse;
+        let end = self.search(pc, pos);
+        self.reverse = old_reverse;
+        self.visited = visited;
+        if end.is_some() && keep_caps {
+            // Restore the captures if the path that led here fails.
+            for (slot, &old) in caps.iter().enumerate() {
+                if *self.caps.get(slot) != old {
+                    self.jobs.push(RestoreCapture(slot, old));
+                }
+            }
+        } else {
+            self.caps = caps;
+        }
+        end
+    }
+
+    // Follows a single path through the program starting at `pc` and `pos`.
+    // Alternatives that aren't taken are pushed on to the job stack. If the
+    // path leads to a match (or the end of the body of a lookaround or atomic
+    // group), then the position where it ends is returned.
+    fn step(&mut self, mut pc: uint, mut pos: uint) -> Option<uint> {
+        let prog = self.prog;
+        loop {
+            if !self.visit(pc, pos) {
+                return None
+            }
+            match *prog.insts.get(pc) {
+                Match | LookEnd => return Some(pos),
+                OneChar(c, flags) => {
+                    match self.char_at(pos) {
+                        Some((textc, next))
+                            if char_eq(flags & FLAG_NOCASE > 0, textc, c) => {
+                            pc += 1;
+                            pos = next;
+                        }
+                        _ => return None,
+                    }
+                }
+                CharClass(ref ranges, flags) => {
+                    let (textc, next) = match self.char_at(pos) {
+                        None => return None,
+                        Some(x) => x,
+                    };
+                    let negate = flags & FLAG_NEGATED > 0;
+                    let casei = flags & FLAG_NOCASE > 0;
+                    let found = ranges.as_slice();
+                    let found =
+                        found.bsearch(|&rc| class_cmp(casei, textc, rc));
+                    if found.is_some() == negate {
+                        return None
+                    }
+                    pc += 1;
+                    pos = next;
+                }
+                Any(flags) => {
+                    match self.char_at(pos) {
+                        Some((textc, next))
+                            if flags & FLAG_DOTNL > 0 || textc != '\n' => {
+                            pc += 1;
+                            pos = next;
+                        }
+                        _ => return None,
+                    }
+                }
+                EmptyBegin(flags) => {
+                    let chars = self.chars_at(pos);
+                    let multi = flags & FLAG_MULTI > 0;
+                    if !chars.is_begin()
+                       && !(multi && chars.prev == Some('\n')) {
+                        return None
+                    }
+                    pc += 1;
+                }
+                EmptyEnd(flags) => {
+                    let chars = self.chars_at(pos);
+                    let ok =
+                        if flags & FLAG_FINAL_NEWLINE > 0 {
+                            chars.is_end_or_final_newline()
+                        } else {
+                            chars.is_end()
+                            || (flags & FLAG_MULTI > 0
+                                && chars.cur == Some('\n'))
+                        };
+                    if !ok {
+                        return None
+                    }
+                    pc += 1;
+                }
+                EmptyWordBoundary(flags) => {
+                    let chars = self.chars_at(pos);
+                    let boundary =
+                        if flags & FLAG_UNICODE > 0 {
+                            chars.is_word_boundary()
+                        } else {
+                            chars.is_ascii_word_boundary()
+                        };
+                    if boundary == (flags & FLAG_NEGATED > 0) {
+                        return None
+                    }
+                    pc += 1;
+                }
+                Save(slot) => {
+                    let old = *self.caps.get(slot);
+                    self.jobs.push(RestoreCapture(slot, old));
+                    *self.caps.get_mut(slot) = Some(pos);
+                    pc += 1;
+                }
+                OneBackref(cap, flags) => {
+                    let (s, e) = match (*self.caps.get(2 * cap),
+                                        *self.caps.get(2 * cap + 1)) {
+                        (Some(s), Some(e)) => (s, e),
+                        _ => return None,
+                    };
+                    match self.match_text(s, e, pos, flags & FLAG_NOCASE > 0) {
+                        None => return None,
+                        Some(next) => {
+                            pc += 1;
+                            pos = next;
+                        }
+                    }
+                }
+                LookStart(flags, behind, next) => {
+                    let negated = flags & FLAG_NEGATED > 0;
+                    let end = self.sub_search(pc + 1, pos, behind, !negated);
+                    // A body that gave up didn't fail, so a negative
+                    // lookaround can't succeed because of it.
+                    if self.exceeded || end.is_some() == negated {
+                        return None
+                    }
+                    pc = next;
+                }
+                AtomicStart(next) => {
+                    let reverse = self.reverse;
+                    match self.sub_search(pc + 1, pos, reverse, true) {
+                        None => return None,
+                        Some(end) => {
+                            pc = next;
+                            pos = end;
+                        }
+                    }
+                }
+                Jump(to) => pc = to,
+                Split(x, y) => {
+                    self.jobs.push(Step(y, pos));
+                    pc = x;
+                }
+                CountedLoop(k, min, max, greedy, exit) => {
+                    let n = *self.counts.get(k);
+                    if n < min {
+                        pc += 1;
+                    } else if max == Some(n) {
+                        self.set_count(k, 0);
+                        pc = exit;
+                    } else if greedy {
+                        // Leave the loop (with the counter reset) if the
+                        // body fails.
+                        self.jobs.push(RestoreCount(k, n));
+                        self.jobs.push(Step(exit, pos));
+                        self.jobs.push(RestoreCount(k, 0));
+                        pc += 1;
+                    } else {
+                        self.jobs.push(Step(pc + 1, pos));
+                        self.set_count(k, 0);
+                        pc = exit;
+                    }
+                }
+                CountedNext(k, limit, start) => {
+                    let n = *self.counts.get(k);
+                    self.set_count(k, cmp::min(n + 1, limit));
+                    pc = start;
+                }
+            }
+        }
+    }
+
+    // Sets a counter on the current path. It's restored if the path fails.
+    fn set_count(&mut self, k: uint, n: uint) {
+        let old = *self.counts.get(k);
+        self.jobs.push(RestoreCount(k, old));
+        *self.counts.get_mut(k) = n;
+    }
+
+    // Records that the state given is being tried. If it has been tried
+    // before, or the step limit has been exceeded, then false is returned.
+    fn visit(&mut self, pc: uint, pos: uint) -> bool {
+        if self.exceeded {
+            return false
+        }
+        let caps = if self.keyed_caps { self.caps.clone() } else { vec!() };
+        let counts = self.counts.clone();
+        if !self.visited.insert((pc, pos, caps, counts)) {
+            return false
+        }
+        self.steps += 1;
+        if self.steps > self.prog.backtrack_limit {
+            self.exceeded = true;
+            return false
+        }
+        true
+    }
+
+    // Returns the next character to read at `pos` and the position after
+    // it, if it's before the end of the search. When reading backwards,
+    // this is the character before `pos` and the position where it starts.
+    fn char_at(&self, pos: uint) -> Option<(char, uint)> {
+        if self.reverse {
+            if pos == 0 {
+                return None
+            }
+            let r = self.input.char_range_at_reverse(pos);
+            return Some((r.ch, r.next))
+        }
+        if pos >= self.end {
+            return None
+        }
+        let r = self.input.char_range_at(pos);
+        Some((r.ch, r.next))
+    }
+
+    // Returns a reader (in the current direction) for checking the
+    // characters around `pos`.
+    fn chars_at(&self, pos: uint) -> CharReader<'t> {
+        let mut chars =
+            if self.reverse {
+                CharReader::new_reverse(self.input)
+            } else {
+                CharReader::new(self.input)
+            };
+        chars.set(pos);
+        chars
+    }
+
+    // Checks whether the text read from `pos` is the same as the text
+    // between `s` and `e`. If it is, then the position after it is returned.
+    fn match_text(&self, s: uint, e: uint, pos: uint, casei: bool)
+                 -> Option<uint> {
+        let text: Vec<char> = self.input.slice(s, e).chars().collect();
+        let mut pos = pos;
+        for i in range(0, text.len()) {
+            let c =
+                if self.reverse {
+                    *text.get(text.len() - 1 - i)
+                } else {
+                    *text.get(i)
+                };
+            match self.char_at(pos) {
+                Some((textc, next)) if char_eq(casei, textc, c) => pos = next,
+                _ => return None,
+            }
+        }
+        Some(pos)
+    }
+}
+
+// The same comparison as the VM uses. (See the FIXME there.)
+#[inline]
+fn char_eq(casei: bool, textc: char, regc: char) -> bool {
+    regc == textc || (casei && regc.to_uppercase() == textc.to_uppercase())
+}
@@ -0,0 +1,132 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A global cache of compiled regular expressions.
+//!
+//! `cached::new` compiles an expression the first time it's given, and
+//! returns the same compiled regex (shared with an `Arc`) each time after
+//! that. So code that has no convenient place to keep a `Regex` (like a
+//! function that handles one request at a time) doesn't have to compile the
+//! same expression over and over.
+//!
+//! The cache holds at most `CAPACITY` expressions. When it's full, the one
+//! used least recently is dropped to make room. (Regexes that are still in
+//! use elsewhere aren't affected.) It's shared by every task, and locked
+//! while an expression is looked up or added, but not while one is being
+//! compiled.
+//!
+//! If an expression is known when your program is compiled, the `regex!`
+//! macro is better still.
+//!
+//! # Example
+//!
+//! ```rust
+//! use regex::cached;
+//!
+//! fn is_id(s: &str) -> bool {
+//!     cached::new(r"^[a-z]\w*$").unwrap().is_match(s)
+//! }
+//! assert!(is_id("abc"));
+//! assert!(!is_id("1bc"));
+//! ```
+
+use collections::HashMap;
+use std::cell::Cell;
+use std::mem;
+use sync::{Arc, Mutex};
+use sync::one::{Once, ONCE_INIT};
+
+use parse;
+use re::Regex;
+
+/// The maximum number of expressions in the cache.
+pub static CAPACITY: uint = 64;
+
+/// Returns the compiled regex for the expression given, compiling it and
+/// adding it to the cache if it isn't already there. (The expression is
+/// compiled as it is by `Regex::new`.)
+///
+/// If an invalid expression is given, then an error is returned. Errors
+/// aren't cached.
+pub fn new(re: &str) -> Result<Arc<Regex>, parse::Error> {
+    {
+        let mut cache = cache().lock();
+        cache.clock += 1;
+        let clock = cache.clock;
+        match cache.regexes.find_equiv(&re) {
+            None => {}
+            Some(&(ref regex, ref used)) => {
+                used.set(clock);
+                return Ok(regex.clone())
+            }
+        }
+    }
+    let regex = Arc::new(try!(Regex::new(re)));
+    let mut cache = cache().lock();
+    // Another task may have added it while it was being compiled.
+    match cache.regexes.find_equiv(&re) {
+        None => {}
+        Some(&(ref regex, _)) => return Ok(regex.clone()),
+    }
+    if cache.regexes.len() >= CAPACITY {
+        cache.evict();
+    }
+    let clock = cache.clock;
+    cache.regexes.insert(re.to_owned(), (regex.clone(), Cell::new(clock)));
+    Ok(regex)
+}
+
+/// Returns the number of expressions in the cache.
+pub fn len() -> uint {
+    cache().lock().regexes.len()
+}
+
+/// Removes every expression from the cache.
+pub fn clear() {
+    cache().lock().regexes.clear()
+}
+
+struct Cache {
+    // Each regex is stored with the time it was last used.
+    regexes: HashMap<~str, (Arc<Regex>, Cell<uint>)>,
+    // Counts the lookups done so far, to order them in time.
+    clock: uint,
+}
+
+impl Cache {
+    // Removes the expression that was used least recently.
+    fn evict(&mut self) {
+        let oldest = self.regexes.iter()
+                                 .min_by(|&(_, &(_, ref used))| used.get())
+                                 .map(|(re, _)| re.clone());
+        match oldest {
+            None => {}
+            Some(re) => { self.regexes.remove(&re); }
+        }
+    }
+}
+
+static mut INIT: Once = ONCE_INIT;
+static mut CACHE: *Mutex<Cache> = 0 as *Mutex<Cache>;
+
+// Returns the global cache, creating it the first time it's needed. It's
+// never freed.
+fn cache() -> &'static Mutex<Cache> {
+    unsafe {
+        INIT.doit(|| {
+            let cache = ~Mutex::new(Cache {
+                regexes: HashMap::new(),
+                clock: 0,
+            });
+            CACHE = mem::transmute(cache);
+        });
+        &*CACHE
+    }
+}
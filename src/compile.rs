@@ -12,15 +12,23 @@
 // for use with the regex! macro. See lib.rs for explanation.
 #![allow(visible_private_types)]
 
+use std::char;
 use std::cmp;
+use std::fmt;
 use std::iter;
+use std::mem;
+use std::str;
+use sync::{Arc, Mutex};
+use literals::{AhoCorasick, find_prefix, prefix_skip_table};
 use parse;
 use parse::{
-    Flags, FLAG_EMPTY,
+    Flags, FLAG_EMPTY, FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_SWAP_GREED,
+    FLAG_NEGATED, FLAG_UNICODE, FLAG_FINAL_NEWLINE,
     Nothing, Literal, Dot, Class, Begin, End, WordBoundary, Capture, Cat, Alt,
-    Rep,
-    ZeroOne, ZeroMore, OneMore,
+    Rep, Backref, Lookahead, Lookbehind, Atomic,
+    ZeroOne, ZeroMore, OneMore, Counted, Greed,
 };
+use simplify;
 
 type InstIdx = uint;
 
@@ -62,6 +70,29 @@ pub enum Inst {
     // Saves the current position in the input string to the Nth save slot.
     Save(uint),
 
+    // Matches the text last matched by the Nth capture group. If the group
+    // hasn't matched, then this fails. The flags indicate whether to do a
+    // case insensitive match.
+    // Only the backtracking engine can execute this instruction.
+    OneBackref(uint, Flags),
+
+    // Checks whether the instructions that follow, up to a LookEnd, match at
+    // the current position, and consumes no characters. If they do (or
+    // don't, when negated), then execution continues at the index given.
+    // When the bool is true, the instructions match backwards from the
+    // current position (for a lookbehind).
+    // Only the backtracking engine can execute this instruction.
+    LookStart(Flags, bool, InstIdx),
+
+    // Matches the instructions that follow, up to a LookEnd, at the current
+    // position, and then continues at the index given from where they
+    // matched. Only the first way that they match is ever tried.
+    // Only the backtracking engine can execute this instruction.
+    AtomicStart(InstIdx),
+
+    // Ends the body of a lookaround or atomic group successfully.
+    LookEnd,
+
     // Jumps to the instruction at the index given.
     Jump(InstIdx),
 
@@ -69,6 +100,24 @@ pub enum Inst {
     // a failing state, then the instruction at the second index given is
     // tried.
     Split(InstIdx, InstIdx),
+
+    // Starts the next repetition of a counted loop, whose body is the
+    // instructions that follow, up to a CountedNext. The first number is
+    // the loop's counter, which holds the number of repetitions so far.
+    // Until it reaches the minimum, the body is repeated. Once it reaches
+    // the maximum (if there is one), the counter is reset to zero and
+    // execution continues at the index given. In between, both are tried
+    // (the body first, if the bool is true, i.e., the loop is greedy).
+    //
+    // Every thread carries its own counters, and threads at the same
+    // instruction with different counters are different states.
+    CountedLoop(uint, uint, Option<uint>, bool, InstIdx),
+
+    // Ends the body of a counted loop by adding one to the counter given
+    // (but never beyond the limit given, after which the number doesn't
+    // matter) and jumping back to the CountedLoop instruction at the index
+    // given.
+    CountedNext(uint, uint, InstIdx),
 }
 
 /// Program represents a compiled regular expression. Once an expression is
@@ -85,21 +134,153 @@ pub struct Program {
     /// match, that prefix is stored here. (It's used in the VM to implement
     /// an optimization.)
     pub prefix: ~str,
+    /// A skip table for finding `prefix` quickly. (It's empty if `prefix` is
+    /// too short to benefit from one.)
+    pub prefix_skip: Vec<uint>,
+    /// If the regular expression must begin with one of several literal
+    /// strings (and `prefix` is empty), then an automaton for finding any of
+    /// them is stored here. This is only used by dynamic regexes.
+    pub prefix_set: Option<AhoCorasick>,
+    /// Whether every match must end at the end of the input (e.g., the
+    /// regular expression ends with a `$` that isn't in multi-line mode).
+    /// When true, `reverse` is always available.
+    pub anchored_end: bool,
+    /// Whether every match must start where the search starts. (See
+    /// `Program::anchor_start`.)
+    pub anchored_start: bool,
+    /// If the regular expression must match a literal string followed by the
+    /// end of the input (e.g., `[a-z]+foo$`), then that literal is stored
+    /// here.
+    pub suffix: ~str,
+    /// A copy of the program compiled to match the reverse of the input.
+    /// It finds where a match starts when run backwards from where the match
+    /// ends. (See `vm::run_reverse`.)
+    pub reverse: Option<~Program>,
+    /// A literal string that every match must contain somewhere other than
+    /// at its beginning (e.g., `@gmail.com` in `\w+@gmail\.com`). If the
+    /// literal can't be found, then there is no match.
+    pub inner: ~str,
+    /// A skip table for finding `inner` quickly.
+    pub inner_skip: Vec<uint>,
+    /// The maximum number of bytes that can precede `inner` in a match, if
+    /// it's bounded.
+    pub inner_offset: Option<uint>,
+    /// The fewest bytes that a match can span.
+    pub min_len: uint,
+    /// The most bytes that a match can span, if it's bounded.
+    pub max_len: Option<uint>,
+    /// Whether the program has instructions that only the backtracking
+    /// engine can execute (e.g., backreferences).
+    pub backtrack: bool,
+    /// The maximum number of steps the backtracking engine may take in a
+    /// single search before giving up.
+    pub backtrack_limit: uint,
+    /// The number of top-level alternates whose matches are reported, or
+    /// zero if they aren't. Each alternate begins by saving the current
+    /// position to a slot of its own (after the capture slots), so the
+    /// slot that is set says which alternate matched.
+    pub alternates: uint,
+    /// The flags that the whole expression was parsed with. (They're only
+    /// kept to tell apart programs compiled from the same expression.)
+    pub flags: Flags,
+}
+
+/// The default value of `Program.backtrack_limit`.
+pub static DEFAULT_BACKTRACK_LIMIT: uint = 1_000_000;
+
+/// LazyProgram holds a program that isn't compiled until the first time
+/// it's needed. Once compiled, it's shared by every search that uses it.
+pub struct LazyProgram {
+    prog: Mutex<Option<Arc<Program>>>,
+}
+
+impl LazyProgram {
+    /// Creates a holder for a program that hasn't been compiled yet.
+    pub fn new() -> LazyProgram {
+        LazyProgram { prog: Mutex::new(None) }
+    }
+
+    /// Returns the program, calling `compile` to compile it if this is the
+    /// first time it's needed.
+    pub fn get(&self, compile: || -> Program) -> Arc<Program> {
+        let mut prog = self.prog.lock();
+        if prog.is_none() {
+            *prog = Some(Arc::new(compile()));
+        }
+        prog.get_ref().clone()
+    }
+}
+
+impl Clone for LazyProgram {
+    fn clone(&self) -> LazyProgram {
+        LazyProgram { prog: Mutex::new(self.prog.lock().clone()) }
+    }
 }
 
 impl Program {
-    /// Compiles a Regex given its AST.
-    pub fn new(ast: ~parse::Ast) -> (Program, ~[Option<~str>]) {
+    /// Compiles a Regex given its AST. The AST is simplified first.
+    ///
+    /// If `alternates` is true, then the program also records which of the
+    /// expression's top-level alternates matched.
+    pub fn new(ast: ~parse::Ast, alternates: bool)
+              -> (Program, ~[Option<~str>]) {
+        // Each alternate is simplified on its own, so that none of them are
+        // merged together.
+        let alts =
+            if alternates {
+                top_alternates(ast.clone())
+            } else {
+                vec!()
+            };
+        let ast = simplify::simplify(ast);
+        // The reverse program and inner literal are only used by the VM, so
+        // don't bother with them if the backtracking engine is needed.
+        let backtrack = parse::needs_backtrack(&*ast);
+        let anchored_end = !backtrack && is_anchored_end(&*ast);
+        let suffix = anchored_suffix(&*ast);
+        let (inner, inner_offset) =
+            if anchored_end || backtrack {
+                (~"", None)
+            } else {
+                inner_literal(&*ast)
+            };
+        let reverse =
+            if anchored_end {
+                Some(~Program::new_reverse(ast.clone()))
+            } else {
+                None
+            };
+        let (min, max) = (min_len(&*ast), max_len(&*ast));
+
         let mut c = Compiler {
             insts: Vec::with_capacity(100),
             names: Vec::with_capacity(10),
+            reverse: false,
+            counters: 0,
         };
 
         c.insts.push(Save(0));
-        c.compile(ast);
+        let alt_saves =
+            if alts.len() > 0 {
+                c.compile_alternates(alts)
+            } else {
+                c.compile(ast);
+                vec!()
+            };
         c.insts.push(Save(1));
         c.insts.push(Match);
 
+        // The slots for alternates come after every capture slot.
+        let first_alt_slot = c.insts.iter().fold(0, |n, inst| {
+            match *inst {
+                Save(slot) => cmp::max(n, slot + 1),
+                _ => n,
+            }
+        });
+        for (i, &pc) in alt_saves.iter().enumerate() {
+            *c.insts.get_mut(pc) = Save(first_alt_slot + i);
+        }
+
         // Try to discover a literal string prefix.
         // This is a bit hacky since we have to skip over the initial
         // 'Save' instruction.
@@ -111,14 +292,118 @@ impl Program {
             }
         }
 
+        // If there's no single literal prefix, maybe every match must
+        // start with one of a small set of literals.
+        let mut prefix_set = None;
+        if pre.len() == 0 {
+            match prefix_literals(c.insts.as_slice()) {
+                None => {}
+                Some(mut lits) => {
+                    if lits.len() == 1 {
+                        pre = StrBuf::from_owned_str(lits.pop().unwrap());
+                    } else {
+                        prefix_set = Some(AhoCorasick::new(lits));
+                    }
+                }
+            }
+        }
+
         let names = c.names.as_slice().into_owned();
+        let skip = prefix_skip_table(pre.as_slice().as_bytes());
         let prog = Program {
             insts: c.insts,
             prefix: pre.into_owned(),
+            prefix_skip: skip,
+            prefix_set: prefix_set,
+            anchored_end: anchored_end,
+            anchored_start: false,
+            suffix: suffix,
+            reverse: reverse,
+            inner_skip: prefix_skip_table(inner.as_bytes()),
+            inner: inner,
+            inner_offset: inner_offset,
+            min_len: min,
+            max_len: max,
+            backtrack: backtrack,
+            backtrack_limit: DEFAULT_BACKTRACK_LIMIT,
+            alternates: alt_saves.len(),
+            flags: FLAG_UNICODE,
         };
         (prog, names)
     }
 
+    /// Compiles the reverse of the expression given. The instructions match
+    /// the reverse of any string matched by `ast`, with the anchors swapped.
+    /// The save slots for each capture are swapped too, so `Save(0)` still
+    /// records the start of the match (which is reached last).
+    ///
+    /// The reverse program has exactly as many instructions as the forward
+    /// program, so they can share thread queues.
+    pub fn new_reverse(ast: ~parse::Ast) -> Program {
+        let mut c = Compiler {
+            insts: Vec::with_capacity(100),
+            names: Vec::with_capacity(10),
+            reverse: true,
+            counters: 0,
+        };
+        let (min, max) = (min_len(&*ast), max_len(&*ast));
+
+        c.insts.push(Save(1));
+        c.compile(ast);
+        c.insts.push(Save(0));
+        c.insts.push(Match);
+
+        Program {
+            insts: c.insts,
+            prefix: ~"",
+            prefix_skip: vec!(),
+            prefix_set: None,
+            anchored_end: false,
+            anchored_start: false,
+            suffix: ~"",
+            reverse: None,
+            inner: ~"",
+            inner_skip: vec!(),
+            inner_offset: None,
+            min_len: min,
+            max_len: max,
+            backtrack: false,
+            backtrack_limit: DEFAULT_BACKTRACK_LIMIT,
+            alternates: 0,
+            flags: FLAG_UNICODE,
+        }
+    }
+
+    /// Makes every match of the program start where the search starts, as
+    /// if the expression began with `\A` at that position. (This is what a
+    /// `Scanner` needs.) The literals used to skip ahead to where a match
+    /// might start are dropped, since nothing can be skipped.
+    pub fn anchor_start(&mut self) {
+        self.anchored_start = true;
+        self.prefix = ~"";
+        self.prefix_skip = vec!();
+        self.prefix_set = None;
+        self.anchored_end = false;
+        self.suffix = ~"";
+        self.reverse = None;
+        self.inner = ~"";
+        self.inner_skip = vec!();
+        self.inner_offset = None;
+    }
+
+    /// Returns true if every match must start at the beginning of the input,
+    /// i.e., the program begins with `EmptyBegin` (not in multi-line mode).
+    pub fn is_anchored_start(&self) -> bool {
+        for inst in self.insts.iter().skip(1) {
+            match *inst {
+                Save(_) => {}
+                EmptyBegin(flags) => return flags & FLAG_MULTI == 0,
+                _ => return false,
+            }
+        }
+        false
+    }
+
     /// Returns the total number of capture groups in the regular expression.
     /// This includes the zeroth capture.
     pub fn num_captures(&self) -> uint {
@@ -129,14 +414,577 @@ impl Program {
                 _ => {}
             }
         }
-        // There's exactly 2 Save slots for every capture.
-        n / 2
+        // There's exactly 2 Save slots for every capture, plus the slots
+        // for alternates.
+        (n - self.alternates) / 2
+    }
+
+    /// Returns the number of save slots that a search for submatches needs.
+    pub fn num_slots(&self) -> uint {
+        2 * self.num_captures() + self.alternates
+    }
+
+    /// Returns the number of counters used by counted loops.
+    pub fn num_counters(&self) -> uint {
+        let mut n = 0;
+        for inst in self.insts.iter() {
+            match *inst {
+                CountedLoop(k, _, _, _, _) => n = cmp::max(n, k + 1),
+                _ => {}
+            }
+        }
+        n
+    }
+
+    /// Returns the approximate number of bytes that the program uses on the
+    /// heap, including its reverse program (if it has one).
+    pub fn heap_size(&self) -> uint {
+        let mut size = self.insts.capacity() * mem::size_of::<Inst>();
+        for inst in self.insts.iter() {
+            match *inst {
+                CharClass(ref ranges, _) =>
+                    size += ranges.capacity() * mem::size_of::<(char, char)>(),
+                _ => {}
+            }
+        }
+        size += self.prefix.len() + self.suffix.len() + self.inner.len();
+        size += (self.prefix_skip.capacity() + self.inner_skip.capacity())
+                * mem::size_of::<uint>();
+        size += self.prefix_set.as_ref().map_or(0, |ac| ac.heap_size());
+        size += self.reverse.as_ref().map_or(0, |rev| {
+            mem::size_of::<Program>() + rev.heap_size()
+        });
+        size
+    }
+
+    /// Returns true if every match of this program must begin with a
+    /// literal string (or one of a set of literal strings).
+    #[inline]
+    pub fn has_prefix(&self) -> bool {
+        self.prefix.len() > 0 || self.prefix_set.is_some()
+    }
+
+    /// Returns the starting location of the first literal prefix found in
+    /// `haystack`. If there is no literal prefix, or none of them are in
+    /// `haystack`, then `None` is returned.
+    #[inline]
+    pub fn find_prefix(&self, haystack: &[u8]) -> Option<uint> {
+        match self.prefix_set {
+            Some(ref ac) => ac.find(haystack),
+            None => find_prefix(self.prefix.as_slice().as_bytes(),
+                                self.prefix_skip.as_slice(), haystack),
+        }
+    }
+
+    /// Returns the program's instructions as a graph in the Graphviz dot
+    /// language. Each instruction is a node, and each edge goes to an
+    /// instruction that can be executed after it. An edge that consumes a
+    /// character is labeled with what it matches, and the two edges of a
+    /// split are numbered in the order they're tried.
+    pub fn to_dot(&self) -> ~str {
+        let mut dot = StrBuf::from_str("digraph program {\n");
+        for (pc, inst) in self.insts.iter().enumerate() {
+            let desc = show_inst(inst);
+            let (label, shape) = match *inst {
+                Match => (pc.to_str(), "doublecircle"),
+                OneChar(_, _) | CharClass(_, _) | Any(_) | OneBackref(_, _) =>
+                    (pc.to_str(), "circle"),
+                _ => (format!("{}: {}", pc, desc), "box"),
+            };
+            dot.push_str(format!("    {} [label=\"{}\", shape={}];\n",
+                                 pc, dot_escape(label), shape));
+            let edges = match *inst {
+                Match | LookEnd => vec!(),
+                OneChar(_, _) | CharClass(_, _) | Any(_) | OneBackref(_, _) =>
+                    vec!((pc + 1, desc)),
+                Jump(to) => vec!((to, ~"")),
+                Split(x, y) => vec!((x, ~"1"), (y, ~"2")),
+                LookStart(_, _, next) | AtomicStart(next) =>
+                    vec!((pc + 1, ~"body"), (next, ~"")),
+                CountedLoop(_, _, _, _, exit) =>
+                    vec!((pc + 1, ~"body"), (exit, ~"exit")),
+                CountedNext(_, _, start) => vec!((start, ~"")),
+                _ => vec!((pc + 1, ~"")),
+            };
+            for (to, label) in edges.move_iter() {
+                if label.len() == 0 {
+                    dot.push_str(format!("    {} -> {};\n", pc, to));
+                } else {
+                    dot.push_str(format!("    {} -> {} [label=\"{}\"];\n",
+                                         pc, to, dot_escape(label)));
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot.into_owned()
     }
 }
 
+impl fmt::Show for Program {
+    /// Shows a listing of the program's instructions, one per line, along
+    /// with any literals extracted for optimizations. This is only meant for
+    /// debugging.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (pc, inst) in self.insts.iter().enumerate() {
+            try!(write!(f.buf, "{:04u} {}\n", pc, show_inst(inst)));
+        }
+        if self.prefix.len() > 0 {
+            try!(write!(f.buf, "prefix: \"{}\"\n",
+                        self.prefix.escape_default()));
+        }
+        match self.prefix_set {
+            None => {}
+            Some(ref ac) => {
+                try!(write!(f.buf, "prefixes:"));
+                for pat in ac.patterns().iter() {
+                    try!(write!(f.buf, " \"{}\"", pat.escape_default()));
+                }
+                try!(write!(f.buf, "\n"));
+            }
+        }
+        if self.suffix.len() > 0 {
+            try!(write!(f.buf, "suffix: \"{}\"\n",
+                        self.suffix.escape_default()));
+        }
+        if self.inner.len() > 0 {
+            try!(write!(f.buf, "inner: \"{}\"\n",
+                        self.inner.escape_default()));
+        }
+        Ok(())
+    }
+}
+
+// Returns a description of an instruction, as shown in a program listing.
+fn show_inst(inst: &Inst) -> ~str {
+    match *inst {
+        Match => ~"match",
+        OneChar(c, flags) =>
+            format!("char '{}'{}", escape(c), show_flags(flags)),
+        CharClass(ref ranges, flags) => {
+            let mut s = StrBuf::from_str("class [");
+            for &(start, end) in ranges.iter() {
+                s.push_str(escape(start).as_slice());
+                if start != end {
+                    s.push_char('-');
+                    s.push_str(escape(end).as_slice());
+                }
+            }
+            format!("{}]{}", s, show_flags(flags))
+        }
+        Any(flags) => format!("any{}", show_flags(flags)),
+        EmptyBegin(flags) => format!("begin{}", show_flags(flags)),
+        EmptyEnd(flags) => format!("end{}", show_flags(flags)),
+        EmptyWordBoundary(flags) if flags & FLAG_UNICODE > 0 =>
+            format!("word-boundary{}", show_flags(flags)),
+        EmptyWordBoundary(flags) =>
+            format!("ascii-word-boundary{}", show_flags(flags)),
+        Save(slot) => format!("save {}", slot),
+        OneBackref(cap, flags) =>
+            format!("backref {}{}", cap, show_flags(flags)),
+        LookStart(flags, false, next) =>
+            format!("lookahead {}{}", next, show_flags(flags)),
+        LookStart(flags, true, next) =>
+            format!("lookbehind {}{}", next, show_flags(flags)),
+        AtomicStart(next) => format!("atomic {}", next),
+        LookEnd => ~"look-end",
+        Jump(to) => format!("jump {}", to),
+        Split(x, y) => format!("split {}, {}", x, y),
+        CountedLoop(k, min, max, greedy, exit) => {
+            let max = match max {
+                None => ~"",
+                Some(max) => max.to_str(),
+            };
+            let lazy = if greedy { "" } else { "?" };
+            format!("counted {} \\{{},{}\\}{}, {}", k, min, max, lazy, exit)
+        }
+        CountedNext(k, limit, start) =>
+            format!("counted-next {} (limit {}), {}", k, limit, start),
+    }
+}
+
+// Escapes a string for use in a double quoted Graphviz string.
+fn dot_escape(s: &str) -> ~str {
+    s.replace("\\", "\\\\").replace("\"", "\\\"")
+}
+
+// Shows the flags set on an instruction, e.g., ` (i, negated)`. If no flags
+// are set, then an empty string is returned.
+fn show_flags(flags: Flags) -> ~str {
+    let names = [(FLAG_NOCASE, "i"), (FLAG_MULTI, "m"), (FLAG_DOTNL, "s"),
+                 (FLAG_SWAP_GREED, "U"), (FLAG_NEGATED, "negated"),
+                 (FLAG_FINAL_NEWLINE, "final-newline")];
+    let set: Vec<&str> = names.iter()
+                              .filter(|&&(flag, _)| flags & flag > 0)
+                              .map(|&(_, name)| name)
+                              .collect();
+    if set.len() == 0 {
+        ~""
+    } else {
+        format!(" ({})", set.connect(", "))
+    }
+}
+
+fn escape(c: char) -> ~str {
+    let mut s = StrBuf::new();
+    c.escape_default(|c| s.push_char(c));
+    s.into_owned()
+}
+
+// The maximum number of literals to extract for a prefix set. Any more than
+// this and the prefilter is unlikely to be worth it.
+static MAX_PREFIX_LITERALS: uint = 32;
+
+// The maximum number of paths through a program that are followed when
+// extracting prefix literals. Paths that end in the same literal are all
+// counted, since following them is what takes time.
+static MAX_PREFIX_PATHS: uint = 256;
+
+// The maximum number of characters in a class that is expanded when
+// extracting prefix literals.
+static MAX_CLASS_EXPAND: uint = 10;
+
+/// Returns a set of literal strings such that every match of the program
+/// given must begin with one of them.
+///
+/// The literals are found by following every path through the program
+/// (starting after the initial `Save`) until something other than a case
+/// sensitive literal character (or a small character class) is found. A
+/// case insensitive ASCII character is followed too, as long as there aren't
+/// too many paths. If any path doesn't start with a literal, or if there are
+/// too many paths or literals otherwise, then `None` is returned.
+fn prefix_literals(insts: &[Inst]) -> Option<Vec<~str>> {
+    let mut lits: Vec<~str> = vec!();
+    let mut stack = vec!((1u, StrBuf::new()));
+    // The number of paths that have been followed to their end.
+    let mut paths = 0u;
+    while stack.len() > 0 {
+        let (mut pc, mut lit) = stack.pop().unwrap();
+        loop {
+            match insts[pc] {
+                OneChar(c, FLAG_EMPTY) => { lit.push_char(c); pc += 1 }
+                // A small class is expanded into one path for each character.
+                CharClass(ref ranges, FLAG_EMPTY)
+                        if ranges.len() > 0
+                           && class_size(ranges.as_slice())
+                              <= MAX_CLASS_EXPAND => {
+                    let mut chars = ranges.iter().flat_map(|&(s, e)| {
+                        iter::range_inclusive(s as u32, e as u32)
+                    }).filter_map(char::from_u32);
+                    let first = chars.next().unwrap();
+                    for c in chars {
+                        let mut lit2 = lit.clone();
+                        lit2.push_char(c);
+                        stack.push((pc + 1, lit2));
+                    }
+                    lit.push_char(first);
+                    pc += 1;
+                }
+                // A case insensitive character is expanded into one path for
+                // each character it matches. If that would make too many
+                // paths, then the literal simply ends here.
+                OneChar(c, FLAG_NOCASE) => {
+                    let cs = match case_variants(c) {
+                        None => break,
+                        Some(cs) => cs,
+                    };
+                    if lits.len() + stack.len() + cs.len()
+                       > MAX_PREFIX_LITERALS {
+                        break
+                    }
+                    for &c in cs.slice_from(1).iter() {
+                        let mut lit2 = lit.clone();
+                        lit2.push_char(c);
+                        stack.push((pc + 1, lit2));
+                    }
+                    lit.push_char(*cs.get(0));
+                    pc += 1;
+                }
+                Save(_) => pc += 1,
+                // Never follow a jump backwards, since that means we're in
+                // a loop and the literal can't be extended further.
+                Jump(to) if to > pc => pc = to,
+                Split(x, y) if x > pc && y > pc => {
+                    stack.push((y, lit.clone()));
+                    pc = x;
+                }
+                _ => break,
+            }
+        }
+        if lit.len() == 0 {
+            return None
+        }
+        let lit = lit.into_owned();
+        if !lits.contains(&lit) {
+            lits.push(lit);
+        }
+        paths += 1;
+        if lits.len() > MAX_PREFIX_LITERALS
+           || paths + stack.len() > MAX_PREFIX_PATHS {
+            return None
+        }
+    }
+    // A literal that has another literal as a prefix is redundant, since
+    // any match of the former is also found by searching for the latter.
+    let keep = lits.iter().filter(|&l1| {
+        !lits.iter().any(|l2| l1 != l2 && l1.starts_with(l2.as_slice()))
+    }).map(|l| l.clone()).collect();
+    Some(keep)
+}
+
+// Returns every character that matches the ASCII character `c` case
+// insensitively, i.e., every character with the same uppercase. If `c` isn't
+// ASCII, then `None` is returned.
+fn case_variants(c: char) -> Option<Vec<char>> {
+    if c as u32 >= 0x80 {
+        return None
+    }
+    let (upper, lower) = (c.to_uppercase(), c.to_lowercase());
+    let mut cs = vec!(upper);
+    if lower != upper {
+        cs.push(lower);
+    }
+    // These are the only non-ASCII characters whose uppercase is ASCII.
+    match upper {
+        'S' => cs.push('\u017f'), // LATIN SMALL LETTER LONG S
+        'I' => cs.push('\u0131'), // LATIN SMALL LETTER DOTLESS I
+        _ => {}
+    }
+    Some(cs)
+}
+
+// Returns the number of characters in a (non-negated) character class.
+fn class_size(ranges: &[(char, char)]) -> uint {
+    ranges.iter().fold(0, |n, &(s, e)| n + (e as uint - s as uint + 1))
+}
+
+/// Returns the top-level alternates of the expression given, in order.
+/// (Alternations are nested to the right by the parser.)
+fn top_alternates(mut ast: ~parse::Ast) -> Vec<~parse::Ast> {
+    let mut alts = vec!();
+    loop {
+        match ast {
+            ~Alt(x, y) => {
+                alts.push(x);
+                ast = y;
+            }
+            x => {
+                alts.push(x);
+                return alts
+            }
+        }
+    }
+}
+
+/// Returns true if every match of the expression given must end at the end
+/// of the input.
+pub fn is_anchored_end(ast: &parse::Ast) -> bool {
+    parse::fold(ast, |ast, xs: Vec<bool>| {
+        match *ast {
+            End(flags) => flags & (FLAG_MULTI | FLAG_FINAL_NEWLINE) == 0,
+            Capture(_, _, _) | Alt(_, _) => xs.iter().all(|&x| x),
+            Cat(_) => xs.last().map_or(false, |&x| x),
+            _ => false,
+        }
+    })
+}
+
+/// Returns the literal string that must appear at the very end of the input
+/// for the expression given to match. This only finds literals immediately
+/// before a `$` (that isn't in multi-line mode) at the end of the expression.
+/// If there is no such literal, then an empty string is returned.
+fn anchored_suffix(ast: &parse::Ast) -> ~str {
+    let xs = match *ast {
+        Cat(ref xs) => xs,
+        _ => return ~"",
+    };
+    match xs.last() {
+        Some(&~End(flags))
+            if flags & (FLAG_MULTI | FLAG_FINAL_NEWLINE) == 0 => {}
+        _ => return ~"",
+    }
+    let mut suffix = vec!();
+    for x in xs.as_slice().init().iter().rev() {
+        match **x {
+            Literal(c, flags) if flags & FLAG_NOCASE == 0 => suffix.push(c),
+            _ => break,
+        }
+    }
+    suffix.reverse();
+    str::from_chars(suffix.as_slice())
+}
+
+/// Returns the longest literal string that every match of the expression
+/// given must contain, but not at its beginning. (A literal at the beginning
+/// is already found by the prefix optimization.) Along with it, the maximum
+/// number of bytes in a match that can precede the literal is returned, if
+/// it's bounded.
+///
+/// Only literals at the top level of the expression are found. If there is
+/// no such literal, then an empty string is returned.
+fn inner_literal(ast: &parse::Ast) -> (~str, Option<uint>) {
+    let xs = match *ast {
+        Cat(ref xs) => xs,
+        _ => return (~"", None),
+    };
+    let is_lit = |x: &parse::Ast| {
+        match *x {
+            Literal(_, flags) => flags & FLAG_NOCASE == 0,
+            _ => false,
+        }
+    };
+    // Find the longest run of literals that doesn't start the expression.
+    let (mut best_start, mut best_len) = (0, 0);
+    let mut i = 1;
+    while i < xs.len() {
+        if !is_lit(&**xs.get(i)) || is_lit(&**xs.get(i - 1)) {
+            i += 1;
+            continue
+        }
+        let mut j = i;
+        while j < xs.len() && is_lit(&**xs.get(j)) {
+            j += 1;
+        }
+        if j - i > best_len {
+            best_start = i;
+            best_len = j - i;
+        }
+        i = j;
+    }
+    if best_len == 0 {
+        return (~"", None)
+    }
+    let mut lit = StrBuf::with_capacity(best_len);
+    for x in xs.slice(best_start, best_start + best_len).iter() {
+        match **x {
+            Literal(c, _) => lit.push_char(c),
+            _ => unreachable!(),
+        }
+    }
+    let mut offset = Some(0u);
+    for x in xs.slice_to(best_start).iter() {
+        offset = match (offset, max_len(&**x)) {
+            (Some(a), Some(b)) => Some(a + b),
+            _ => None,
+        };
+    }
+    (lit.into_owned(), offset)
+}
+
+/// Returns the maximum number of bytes that the expression given can match,
+/// if it's bounded.
+pub fn max_len(ast: &parse::Ast) -> Option<uint> {
+    parse::fold(ast, |ast, lens: Vec<Option<uint>>| {
+        match *ast {
+            Nothing | Begin(_) | End(_) | WordBoundary(_)
+            | Lookahead(_, _) | Lookbehind(_, _) => Some(0),
+            Literal(c, flags) => Some(literal_lens(c, flags).val1()),
+            Class(ref ranges, flags) =>
+                Some(class_lens(ranges.as_slice(), flags).val1()),
+            // Any character may be up to 4 bytes long when encoded as UTF-8.
+            Dot(_) => Some(4),
+            Capture(_, _, _) | Rep(_, ZeroOne, _) | Atomic(_) =>
+                *lens.get(0),
+            Rep(_, Counted(_, Some(max)), _) => lens.get(0).map(|n| n * max),
+            Rep(_, ZeroMore, _) | Rep(_, OneMore, _)
+            | Rep(_, Counted(_, None), _) | Backref(_, _) => None,
+            Cat(_) => {
+                lens.iter().fold(Some(0), |n, &m| {
+                    match (n, m) {
+                        (Some(a), Some(b)) => Some(a + b),
+                        _ => None,
+                    }
+                })
+            }
+            Alt(_, _) => {
+                match (*lens.get(0), *lens.get(1)) {
+                    (Some(a), Some(b)) => Some(cmp::max(a, b)),
+                    _ => None,
+                }
+            }
+        }
+    })
+}
+
+/// Returns the minimum number of bytes that the expression given can match.
+pub fn min_len(ast: &parse::Ast) -> uint {
+    parse::fold(ast, |ast, lens: Vec<uint>| {
+        match *ast {
+            Nothing | Begin(_) | End(_) | WordBoundary(_)
+            | Lookahead(_, _) | Lookbehind(_, _) | Backref(_, _) => 0,
+            Literal(c, flags) => literal_lens(c, flags).val0(),
+            Class(ref ranges, flags) =>
+                class_lens(ranges.as_slice(), flags).val0(),
+            Dot(_) => 1,
+            Capture(_, _, _) | Rep(_, OneMore, _) | Atomic(_) =>
+                *lens.get(0),
+            Rep(_, ZeroOne, _) | Rep(_, ZeroMore, _) => 0,
+            Rep(_, Counted(min, _), _) => *lens.get(0) * min,
+            Cat(_) => lens.iter().fold(0, |n, &m| n + m),
+            Alt(_, _) => cmp::min(*lens.get(0), *lens.get(1)),
+        }
+    })
+}
+
+// Returns the fewest and most bytes that a character matched by the literal
+// `c` can take when encoded as UTF-8. (A case insensitive literal matches
+// characters other than `c`, which may not be the same length, e.g., `s`
+// matches `ſ`.)
+fn literal_lens(c: char, flags: Flags) -> (uint, uint) {
+    if flags & FLAG_NOCASE == 0 {
+        let n = c.len_utf8_bytes();
+        return (n, n)
+    }
+    match case_variants(c) {
+        None => (1, 4),
+        Some(cs) => cs.iter().fold((4, 1), |(min, max), c| {
+            let n = c.len_utf8_bytes();
+            (cmp::min(min, n), cmp::max(max, n))
+        }),
+    }
+}
+
+// Returns the fewest and most bytes that a character matched by a class can
+// take when encoded as UTF-8. Only classes that are neither negated nor case
+// insensitive are narrowed down by their ranges (which are sorted).
+fn class_lens(ranges: &[(char, char)], flags: Flags) -> (uint, uint) {
+    if flags & (FLAG_NEGATED | FLAG_NOCASE) > 0 || ranges.len() == 0 {
+        return (1, 4)
+    }
+    let (start, _) = ranges[0];
+    let (_, end) = ranges[ranges.len() - 1];
+    (start.len_utf8_bytes(), end.len_utf8_bytes())
+}
+
+// The work left to do while compiling an expression. The compiler keeps a
+// stack of these instead of recursing over the expression, so that deeply
+// nested expressions can't overflow the stack. Each `Finish*` task finishes
+// an expression whose sub-expressions have been compiled, given the index of
+// the first instruction that was pushed for it.
+enum Task {
+    // Compiles an expression.
+    Compile(~parse::Ast),
+    // Pushes an instruction.
+    Emit(Inst),
+    // Finishes the first alternate of an alternation and starts the second.
+    FinishAltFirst(InstIdx, ~parse::Ast),
+    // Patches the jump at the end of the first alternate of an alternation.
+    FinishAlt(InstIdx),
+    FinishZeroOne(InstIdx, Greed),
+    FinishZeroMore(InstIdx, Greed),
+    FinishOneMore(InstIdx, Greed),
+    // Ends the body of the counted loop that starts at the index given.
+    FinishCounted(InstIdx),
+    FinishAtomic(InstIdx),
+    // Finishes a lookaround and restores the direction that was being
+    // compiled in before it.
+    FinishLook(InstIdx, Flags, bool, bool),
+}
+
 struct Compiler<'r> {
     insts: Vec<Inst>,
     names: Vec<Option<~str>>,
+    reverse: bool,
+    counters: uint,
 }
 
 // The compiler implemented here is extremely simple. Most of the complexity
@@ -145,83 +993,198 @@ struct Compiler<'r> {
 // the right instruction.
 impl<'r> Compiler<'r> {
     fn compile(&mut self, ast: ~parse::Ast) {
+        let mut tasks = vec!(Compile(ast));
+        loop {
+            match tasks.pop() {
+                None => break,
+                Some(Compile(ast)) => self.compile_one(ast, &mut tasks),
+                Some(Emit(inst)) => self.push(inst),
+                Some(FinishAltFirst(split, y)) => {
+                    let jmp = self.empty_jump();    // push: jmp 0
+                    let j2 = self.insts.len();
+                    // split 0, 0 -> split j1, j2
+                    self.set_split(split, split + 1, j2);
+                    tasks.push(FinishAlt(jmp));
+                    tasks.push(Compile(y));         // push: insts for y
+                }
+                Some(FinishAlt(jmp)) => {
+                    let j3 = self.insts.len();
+                    self.set_jump(jmp, j3);         // jmp 0 -> jmp j3
+                }
+                Some(FinishZeroOne(split, g)) => {
+                    let (j1, j2) = (split + 1, self.insts.len());
+                    if g.is_greedy() {
+                        self.set_split(split, j1, j2);
+                    } else {
+                        self.set_split(split, j2, j1);
+                    }
+                }
+                Some(FinishZeroMore(split, g)) => {
+                    let jmp = self.empty_jump();
+                    let (j2, j3) = (split + 1, self.insts.len());
+                    self.set_jump(jmp, split);
+                    if g.is_greedy() {
+                        self.set_split(split, j2, j3);
+                    } else {
+                        self.set_split(split, j3, j2);
+                    }
+                }
+                Some(FinishOneMore(j1, g)) => {
+                    let split = self.empty_split();
+                    let j2 = self.insts.len();
+                    if g.is_greedy() {
+                        self.set_split(split, j1, j2);
+                    } else {
+                        self.set_split(split, j2, j1);
+                    }
+                }
+                Some(FinishCounted(start)) => {
+                    let (k, min, max, greedy) = match *self.insts.get(start) {
+                        CountedLoop(k, min, max, greedy, _) =>
+                            (k, min, max, greedy),
+                        _ => fail!("BUG: Invalid counted loop index."),
+                    };
+                    self.push(CountedNext(k, max.unwrap_or(min), start));
+                    let exit = self.insts.len();
+                    *self.insts.get_mut(start) =
+                        CountedLoop(k, min, max, greedy, exit);
+                }
+                Some(FinishAtomic(atomic)) => {
+                    self.push(LookEnd);
+                    let next = self.insts.len();
+                    *self.insts.get_mut(atomic) = AtomicStart(next);
+                }
+                Some(FinishLook(look, flags, behind, reverse)) => {
+                    self.reverse = reverse;
+                    self.push(LookEnd);
+                    let next = self.insts.len();
+                    *self.insts.get_mut(look) = LookStart(flags, behind, next);
+                }
+            }
+        }
+    }
+
+    // Pushes the instructions that come before the sub-expressions of `ast`,
+    // and then adds tasks to compile them and finish `ast`. (The tasks are
+    // added in reverse order, since the last one added is done first.)
+    fn compile_one(&mut self, ast: ~parse::Ast, tasks: &mut Vec<Task>) {
         match ast {
             ~Nothing => {},
             ~Literal(c, flags) => self.push(OneChar(c, flags)),
             ~Dot(nl) => self.push(Any(nl)),
             ~Class(ranges, flags) =>
                 self.push(CharClass(ranges, flags)),
+            ~Begin(flags) if self.reverse => self.push(EmptyEnd(flags)),
+            // \Z is the same in both directions. (See CharReader.)
+            ~End(flags) if self.reverse && flags & FLAG_FINAL_NEWLINE == 0 =>
+                self.push(EmptyBegin(flags)),
             ~Begin(flags) => self.push(EmptyBegin(flags)),
             ~End(flags) => self.push(EmptyEnd(flags)),
             ~WordBoundary(flags) => self.push(EmptyWordBoundary(flags)),
+            ~Backref(cap, flags) => self.push(OneBackref(cap, flags)),
+            ~Lookahead(x, flags) => self.compile_look(x, flags, false, tasks),
+            ~Lookbehind(x, flags) => self.compile_look(x, flags, true, tasks),
+            ~Atomic(x) => {
+                let atomic = self.insts.len();
+                self.push(AtomicStart(0));
+                tasks.push(FinishAtomic(atomic));
+                tasks.push(Compile(x));
+            }
             ~Capture(cap, name, x) => {
                 let len = self.names.len();
                 if cap >= len {
-                    self.names.grow(10 + cap - len, &None)
+                    self.names.grow(cap + 1 - len, &None)
                 }
                 *self.names.get_mut(cap) = name;
 
-                self.push(Save(2 * cap));
-                self.compile(x);
-                self.push(Save(2 * cap + 1));
+                let (first, last) =
+                    if self.reverse {
+                        (2 * cap + 1, 2 * cap)
+                    } else {
+                        (2 * cap, 2 * cap + 1)
+                    };
+                self.push(Save(first));
+                tasks.push(Emit(Save(last)));
+                tasks.push(Compile(x));
             }
             ~Cat(xs) => {
-                for x in xs.move_iter() {
-                    self.compile(x)
+                if self.reverse {
+                    for x in xs.move_iter() {
+                        tasks.push(Compile(x))
+                    }
+                } else {
+                    for x in xs.move_iter().rev() {
+                        tasks.push(Compile(x))
+                    }
                 }
             }
             ~Alt(x, y) => {
                 let split = self.empty_split(); // push: split 0, 0
-                let j1 = self.insts.len();
-                self.compile(x);                // push: insts for x
-                let jmp = self.empty_jump();    // push: jmp 0
-                let j2 = self.insts.len();
-                self.compile(y);                // push: insts for y
-                let j3 = self.insts.len();
-
-                self.set_split(split, j1, j2);  // split 0, 0 -> split j1, j2
-                self.set_jump(jmp, j3);         // jmp 0      -> jmp j3
+                tasks.push(FinishAltFirst(split, y));
+                tasks.push(Compile(x));         // push: insts for x
             }
             ~Rep(x, ZeroOne, g) => {
                 let split = self.empty_split();
-                let j1 = self.insts.len();
-                self.compile(x);
-                let j2 = self.insts.len();
-
-                if g.is_greedy() {
-                    self.set_split(split, j1, j2);
-                } else {
-                    self.set_split(split, j2, j1);
-                }
+                tasks.push(FinishZeroOne(split, g));
+                tasks.push(Compile(x));
             }
             ~Rep(x, ZeroMore, g) => {
-                let j1 = self.insts.len();
                 let split = self.empty_split();
-                let j2 = self.insts.len();
-                self.compile(x);
-                let jmp = self.empty_jump();
-                let j3 = self.insts.len();
-
-                self.set_jump(jmp, j1);
-                if g.is_greedy() {
-                    self.set_split(split, j2, j3);
-                } else {
-                    self.set_split(split, j3, j2);
-                }
+                tasks.push(FinishZeroMore(split, g));
+                tasks.push(Compile(x));
             }
             ~Rep(x, OneMore, g) => {
                 let j1 = self.insts.len();
-                self.compile(x);
-                let split = self.empty_split();
-                let j2 = self.insts.len();
+                tasks.push(FinishOneMore(j1, g));
+                tasks.push(Compile(x));
+            }
+            ~Rep(x, Counted(min, max), g) => {
+                let start = self.insts.len();
+                let k = self.counters;
+                self.counters += 1;
+                self.push(CountedLoop(k, min, max, g.is_greedy(), 0));
+                tasks.push(FinishCounted(start));
+                tasks.push(Compile(x));
+            }
+        }
+    }
 
-                if g.is_greedy() {
-                    self.set_split(split, j1, j2);
-                } else {
-                    self.set_split(split, j2, j1);
+    /// Compiles a lookaround. The body of a lookbehind is compiled in reverse
+    /// (and the body of a lookahead is not, even inside a lookbehind).
+    fn compile_look(&mut self, x: ~parse::Ast, flags: Flags, behind: bool,
+                    tasks: &mut Vec<Task>) {
+        let look = self.insts.len();
+        self.push(LookStart(flags, behind, 0));
+        tasks.push(FinishLook(look, flags, behind, self.reverse));
+        self.reverse = behind;
+        tasks.push(Compile(x));
+    }
+
+    /// Compiles each alternate (after simplifying it) in order of
+    /// preference. Each one begins with a `Save` whose slot is patched in
+    /// later, and the indices of those instructions are returned.
+    fn compile_alternates(&mut self, alts: Vec<~parse::Ast>) -> Vec<InstIdx> {
+        let (mut saves, mut jumps) = (vec!(), vec!());
+        let last = alts.len() - 1;
+        for (i, alt) in alts.move_iter().enumerate() {
+            let split = if i < last { Some(self.empty_split()) } else { None };
+            saves.push(self.insts.len());
+            self.push(Save(0));
+            self.compile(simplify::simplify(alt));
+            match split {
+                None => {}
+                Some(split) => {
+                    jumps.push(self.empty_jump());
+                    let next = self.insts.len();
+                    self.set_split(split, split + 1, next);
                 }
             }
         }
+        let end = self.insts.len();
+        for &jmp in jumps.iter() {
+            self.set_jump(jmp, end);
+        }
+        saves
     }
 
     /// Appends the given instruction to the program.
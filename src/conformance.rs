@@ -0,0 +1,266 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Running the tests from AT&T's testregex suite against any engine.
+//!
+//! The tests are in the `.dat` files in `src/testdata` (which have the
+//! format described at
+//! http://www2.research.att.com/~astopen/testregex/testregex.html). Each
+//! line of a file is a test with options, an expression, the text to search
+//! and the locations of the capture groups in the leftmost-first match. Only
+//! the tests of extended expressions (the `E` option) that are expected to
+//! match or not match are read. (The tests that expect an error are
+//! skipped.)
+//!
+//! The same tests are compiled into this crate's own test suite, but
+//! reading them at runtime makes it possible to check a new engine (or a
+//! change to one) against them without generating any code.
+//!
+//! # Example
+//!
+//! ```rust
+//! use regex::conformance::{parse, run, search_dynamic};
+//!
+//! let tests = parse("example", "E\ta(b)?c\tac\t(0,2)(?,?)\n\
+//!                               E\tSAME\tab\tNOMATCH\n");
+//! assert_eq!(tests.len(), 2);
+//! assert_eq!(tests.get(1).pattern, ~"a(b)?c");
+//! assert!(run(tests.as_slice(), search_dynamic).is_empty());
+//! ```
+
+use std::char;
+use std::fmt;
+use std::from_str::from_str;
+use std::io::{File, IoResult};
+use std::num;
+use std::str;
+
+use re::Regex;
+
+/// The locations of the capture groups in a match, with the entire match
+/// first.
+pub type Locations = Vec<Option<(uint, uint)>>;
+
+/// A single test from a `.dat` file.
+#[deriving(Clone, Eq, Show)]
+pub struct Test {
+    /// The name of the test, which is the name of the file it came from and
+    /// its line number, like `basic_3`.
+    pub name: ~str,
+    /// The expression to search for. (It starts with `(?i)` if the test is
+    /// case insensitive.)
+    pub pattern: ~str,
+    /// The text to search.
+    pub text: ~str,
+    /// The locations of the capture groups in the first match, or `None` if
+    /// there is no match. Only the first few groups may be given, in which
+    /// case the rest aren't checked.
+    pub expected: Option<Locations>,
+}
+
+/// A test that failed, as returned by `run`.
+#[deriving(Clone, Eq)]
+pub struct Failure {
+    /// The test.
+    pub test: Test,
+    /// What the engine returned, with any capture groups that the test
+    /// doesn't check left out.
+    pub got: Result<Option<Locations>, ~str>,
+}
+
+impl fmt::Show for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f.buf, "{}: for RE '{}' against '{}', expected {} but got {}",
+               self.test.name, self.test.pattern, self.test.text,
+               self.test.expected, self.got)
+    }
+}
+
+/// Reads the tests from a `.dat` file. The tests are named after the file
+/// (without its extension).
+pub fn read(path: &Path) -> IoResult<Vec<Test>> {
+    let data = try!(File::open(path).read_to_str());
+    let name = match path.filestem_str() {
+        None => "",
+        Some(name) => name,
+    };
+    Ok(parse(name, data.as_slice()))
+}
+
+/// Parses the tests in the contents of a `.dat` file. Each test is named
+/// with the name given and its line number.
+///
+/// The lines that aren't tests, or that aren't tests this crate can run, are
+/// skipped.
+pub fn parse(name: &str, data: &str) -> Vec<Test> {
+    let mut tests = vec!();
+    // The expression of the last test, which the next one can refer to as
+    // `SAME`.
+    let mut last = "";
+    for (i, line) in data.lines().enumerate() {
+        let fields: Vec<&str> = line.split('\t')
+                                    .map(|f| f.trim())
+                                    .filter(|f| f.len() > 0)
+                                    .collect();
+        if fields.len() < 4 || fields.len() > 5
+           || !fields.get(0).contains_char('E')
+           || fields.get(0).starts_with("#") {
+            continue
+        }
+        let (opts, text) = (*fields.get(0), *fields.get(2));
+        if *fields.get(1) != "SAME" {
+            last = *fields.get(1);
+        }
+        let expected = match parse_locations(*fields.get(3)) {
+            None => continue,
+            Some(expected) => expected,
+        };
+        let mut pattern =
+            if opts.contains_char('$') {
+                unescape(last)
+            } else {
+                last.to_owned()
+            };
+        if opts.contains_char('i') {
+            pattern = format!("(?i){}", pattern);
+        }
+        let text =
+            if text == "NULL" {
+                ~""
+            } else if opts.contains_char('$') {
+                unescape(text)
+            } else {
+                text.to_owned()
+            };
+        tests.push(Test {
+            name: format!("{}_{}", name, i + 1),
+            pattern: pattern,
+            text: text,
+            expected: expected,
+        });
+    }
+    tests
+}
+
+/// Runs each test with the search given, which returns the locations of the
+/// capture groups in the first match of an expression in a text (or an
+/// error if the expression can't be compiled). The tests that failed are
+/// returned.
+pub fn run(tests: &[Test],
+           search: |&str, &str| -> Result<Option<Locations>, ~str>)
+          -> Vec<Failure> {
+    let mut failures = vec!();
+    for test in tests.iter() {
+        let got = search(test.pattern.as_slice(), test.text.as_slice());
+        // The tests sometimes leave out capture groups, so only the ones
+        // given are compared.
+        let got = got.map(|locs| locs.map(|mut locs| {
+            match test.expected {
+                Some(ref expected) => locs.truncate(expected.len()),
+                None => {}
+            }
+            locs
+        }));
+        if got != Ok(test.expected.clone()) {
+            failures.push(Failure { test: test.clone(), got: got });
+        }
+    }
+    failures
+}
+
+/// Searches with a regex compiled by `Regex::new`. This can be given to
+/// `run`.
+pub fn search_dynamic(re: &str, text: &str)
+                     -> Result<Option<Locations>, ~str> {
+    let re = match Regex::new(re) {
+        Ok(re) => re,
+        Err(err) => return Err(err.to_str()),
+    };
+    Ok(re.captures(text).map(|caps| caps.iter_pos().collect()))
+}
+
+// Parses the expected locations of a test, like `(0,3)(?,?)(1,2)`, or
+// `NOMATCH`. If the test expects an error instead, then `None` is returned.
+fn parse_locations(s: &str) -> Option<Option<Locations>> {
+    if s == "NOMATCH" {
+        return Some(None)
+    }
+    if !s.contains_char(',') {
+        return None
+    }
+    let mut locs = vec!();
+    for group in s.trim_chars(&['(', ')']).split_str(")(") {
+        let bounds: Vec<&str> = group.split(',').map(|b| b.trim()).collect();
+        if bounds.len() != 2 {
+            return None
+        }
+        if *bounds.get(0) == "?" && *bounds.get(1) == "?" {
+            locs.push(None);
+            continue
+        }
+        match (from_str(*bounds.get(0)), from_str(*bounds.get(1))) {
+            (Some(s), Some(e)) => locs.push(Some((s, e))),
+            _ => return None,
+        }
+    }
+    Some(Some(locs))
+}
+
+// Replaces the C style escape sequences in a string (for tests with the
+// `$` option) with the characters they stand for.
+fn unescape(s: &str) -> ~str {
+    let chars: Vec<char> = s.chars().collect();
+    let mut buf = StrBuf::with_capacity(s.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = *chars.get(i);
+        i += 1;
+        if c != '\\' || i == chars.len() {
+            buf.push_char(c);
+            continue
+        }
+        let c = *chars.get(i);
+        i += 1;
+        match c {
+            'n' => buf.push_char('\n'),
+            't' => buf.push_char('\t'),
+            'r' => buf.push_char('\r'),
+            'f' => buf.push_char('\x0c'),
+            'v' => buf.push_char('\x0b'),
+            'a' => buf.push_char('\x07'),
+            'x' | '0'..'7' => {
+                let (radix, max, start) =
+                    if c == 'x' { (16, 2, i) } else { (8, 3, i - 1) };
+                let mut end = start;
+                while end < chars.len() && end - start < max
+                      && chars.get(end).is_digit_radix(radix) {
+                    end += 1;
+                }
+                let digits = str::from_chars(chars.slice(start, end));
+                match num::from_str_radix::<u32>(digits, radix)
+                          .and_then(char::from_u32) {
+                    Some(c) => {
+                        buf.push_char(c);
+                        i = end;
+                    }
+                    None => {
+                        buf.push_char('\\');
+                        buf.push_char(c);
+                    }
+                }
+            }
+            c => {
+                buf.push_char('\\');
+                buf.push_char(c);
+            }
+        }
+    }
+    buf.into_owned()
+}
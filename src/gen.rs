@@ -0,0 +1,425 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Generating random expressions and random strings from them, for fuzzing.
+//!
+//! `PatternGen` builds random abstract syntax, which is always valid, and
+//! writes it out as an expression (with `Ast::to_pattern`). The expressions
+//! can be used to fuzz code that takes regular expressions, or to compare
+//! the engines against each other.
+//!
+//! `generate` goes the other way (and is what `Regex::gen_match` uses). Each
+//! part of an expression is turned into text that it matches on its own: a
+//! random character from each class, a random alternate from each
+//! alternation, a random number of copies for each repetition and so on.
+//! Assertions (anchors, word boundaries and lookarounds) produce no text,
+//! so the result may not match when they're taken together. The caller has
+//! to check it.
+//!
+//! # Example
+//!
+//! Check that the VM and the backtracking engine find the same matches:
+//!
+//! ```rust
+//! extern crate rand;
+//! extern crate regex;
+//! use regex::{Regex, RegexBuilder};
+//! use regex::gen::PatternGen;
+//!
+//! # fn main() {
+//! let mut rng = rand::task_rng();
+//! for _ in range(0, 100) {
+//!     let pattern = PatternGen::new().gen(&mut rng);
+//!     let nfa = Regex::new(pattern.as_slice()).unwrap();
+//!     let bt = RegexBuilder::new(pattern.as_slice()).backtrack(true)
+//!                                                   .build().unwrap();
+//!     let text = "abc xyz 123";
+//!     assert_eq!(nfa.find(text), bt.find(text));
+//! }
+//! # }
+//! ```
+
+use std::char;
+use std::cmp;
+use rand::Rng;
+use parse::{
+    Ast, Nothing, Literal, Dot, Class, Begin, End, WordBoundary, Capture,
+    Backref, Lookahead, Lookbehind, Atomic, Cat, Alt, Rep,
+    ZeroOne, ZeroMore, OneMore, Counted, Greedy, Ungreedy,
+    Flags, FLAG_EMPTY, FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED,
+    FLAG_UNICODE, combine_ranges,
+};
+
+// The characters that literals and classes are made of. Most are ASCII
+// letters, so that patterns match text often, but some are meta characters
+// and some are outside of ASCII.
+static ALPHABET: &'static [char] = &[
+    'a', 'b', 'c', 'x', 'y', 'z', 'A', 'Z', '0', '9', ' ', '-',
+    '.', '*', '[', ']', '(', '^', '$', '\\', 'δ', '☃',
+];
+
+/// A generator of random expressions. Each one parses successfully with
+/// `Regex::new` (or, if `backtrack` is set, with `RegexBuilder::backtrack`).
+#[deriving(Clone, Show)]
+pub struct PatternGen {
+    depth: uint,
+    width: uint,
+    backtrack: bool,
+}
+
+impl PatternGen {
+    /// Returns a generator of expressions that are nested at most 4 deep,
+    /// with at most 4 expressions in a concatenation, and that don't
+    /// require backtracking.
+    pub fn new() -> PatternGen {
+        PatternGen { depth: 4, width: 4, backtrack: false }
+    }
+
+    /// Sets the most that expressions are nested in each other, e.g.,
+    /// `(a|b)*` is nested 4 deep (a repetition of a group of an alternation
+    /// of characters).
+    pub fn depth(&mut self, depth: uint) -> &mut PatternGen {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets the most expressions that are concatenated together.
+    pub fn width(&mut self, width: uint) -> &mut PatternGen {
+        self.width = if width == 0 { 1 } else { width };
+        self
+    }
+
+    /// When set, expressions can have backreferences, lookarounds and
+    /// atomic groups, which only the backtracking engine supports.
+    pub fn backtrack(&mut self, yes: bool) -> &mut PatternGen {
+        self.backtrack = yes;
+        self
+    }
+
+    /// Returns a random expression.
+    pub fn gen<R: Rng>(&self, rng: &mut R) -> ~str {
+        self.gen_ast(rng).to_pattern()
+    }
+
+    /// Returns the abstract syntax of a random expression. Writing it with
+    /// `Ast::to_pattern` gives an expression that parses successfully,
+    /// though not necessarily to the same abstract syntax (e.g., nested
+    /// concatenations are flattened).
+    pub fn gen_ast<R: Rng>(&self, rng: &mut R) -> ~Ast {
+        let mut g = PatternGenerator {
+            opts: self,
+            rng: rng,
+            caps: 0,
+            closed: vec!(),
+        };
+        g.gen(self.depth, false)
+    }
+}
+
+struct PatternGenerator<'a, R> {
+    opts: &'a PatternGen,
+    rng: &'a mut R,
+    // The number of capture groups opened so far.
+    caps: uint,
+    // The capture groups that have been closed (which backreferences can
+    // refer to).
+    closed: Vec<uint>,
+}
+
+impl<'a, R: Rng> PatternGenerator<'a, R> {
+    // Returns a random expression nested at most `depth` deep. When
+    // `bounded` is true, it can only match a bounded amount of text (for
+    // the body of a lookbehind).
+    fn gen(&mut self, depth: uint, bounded: bool) -> ~Ast {
+        if depth <= 1 || self.rng.gen_weighted_bool(3) {
+            return self.gen_atom()
+        }
+        let choices = if self.opts.backtrack { 8 } else { 5 };
+        match self.rng.gen_range(0u, choices) {
+            0 => {
+                let n = self.rng.gen_range(2, cmp::max(2, self.opts.width) + 1);
+                let xs = Vec::from_fn(n, |_| self.gen(depth - 1, bounded));
+                ~Cat(xs)
+            }
+            1 => {
+                let x = self.gen(depth - 1, bounded);
+                ~Alt(x, self.gen(depth - 1, bounded))
+            }
+            2 => self.gen_rep(depth, bounded),
+            3 => {
+                self.caps += 1;
+                let cap = self.caps;
+                let name =
+                    if self.rng.gen_weighted_bool(3) {
+                        Some(format!("g{}", cap))
+                    } else {
+                        None
+                    };
+                let x = self.gen(depth - 1, bounded);
+                self.closed.push(cap);
+                ~Capture(cap, name, x)
+            }
+            4 => self.gen_assertion(),
+            5 if !bounded && self.closed.len() > 0 => {
+                let i = self.rng.gen_range(0, self.closed.len());
+                let cap = *self.closed.get(i);
+                ~Backref(cap, self.random_flag(FLAG_NOCASE))
+            }
+            6 => {
+                let negated = self.random_flag(FLAG_NEGATED);
+                if self.rng.gen() {
+                    ~Lookahead(self.gen(depth - 1, bounded), negated)
+                } else {
+                    let x = self.gen(depth - 1, true);
+                    ~Lookbehind(x, negated)
+                }
+            }
+            7 => ~Atomic(self.gen(depth - 1, bounded)),
+            _ => self.gen_atom(),
+        }
+    }
+
+    // Returns a random repetition. Assertions (and groups that aren't
+    // captured) can't be repeated, so a single character is repeated
+    // instead of one.
+    fn gen_rep(&mut self, depth: uint, bounded: bool) -> ~Ast {
+        let (caps, closed) = (self.caps, self.closed.len());
+        let mut x = self.gen(depth - 1, bounded);
+        let assertion = match *x {
+            Begin(_) | End(_) | WordBoundary(_) | Backref(_, _)
+            | Lookahead(_, _) | Lookbehind(_, _) | Atomic(_) => true,
+            _ => false,
+        };
+        if assertion {
+            // Forget any groups in the expression that's thrown away, so
+            // the groups are still numbered in order.
+            self.caps = caps;
+            self.closed.truncate(closed);
+            x = self.gen_atom();
+        }
+        let rep = match self.rng.gen_range(0u, 4) {
+            0 => ZeroOne,
+            1 if !bounded => ZeroMore,
+            2 if !bounded => OneMore,
+            _ => {
+                let min = self.rng.gen_range(0u, 3);
+                if !bounded && self.rng.gen_weighted_bool(3) {
+                    Counted(min, None)
+                } else {
+                    Counted(min, Some(min + self.rng.gen_range(0u, 3)))
+                }
+            }
+        };
+        let greed =
+            if self.rng.gen_weighted_bool(4) { Ungreedy } else { Greedy };
+        ~Rep(x, rep, greed)
+    }
+
+    // Returns an expression that matches a single character.
+    fn gen_atom(&mut self) -> ~Ast {
+        match self.rng.gen_range(0u, 4) {
+            0 => ~Dot(self.random_flag(FLAG_DOTNL)),
+            1 => {
+                let mut ranges = vec!();
+                for _ in range(0, self.rng.gen_range(1u, 4)) {
+                    let (a, b) = (self.random_char(), self.random_char());
+                    ranges.push(if a <= b { (a, b) } else { (b, a) });
+                }
+                let flags = self.random_flag(FLAG_NEGATED)
+                            | self.random_flag(FLAG_NOCASE);
+                ~Class(combine_ranges(ranges), flags)
+            }
+            _ => {
+                let c = self.random_char();
+                ~Literal(c, self.random_flag(FLAG_NOCASE))
+            }
+        }
+    }
+
+    // Returns an anchor or a word boundary.
+    fn gen_assertion(&mut self) -> ~Ast {
+        match self.rng.gen_range(0u, 3) {
+            0 => ~Begin(self.random_flag(FLAG_MULTI)),
+            1 => ~End(self.random_flag(FLAG_MULTI)),
+            _ => {
+                let flags = self.random_flag(FLAG_NEGATED)
+                            | self.random_flag(FLAG_UNICODE);
+                ~WordBoundary(flags)
+            }
+        }
+    }
+
+    fn random_char(&mut self) -> char {
+        *self.rng.choose(ALPHABET)
+    }
+
+    // Returns `flag` a third of the time, and no flags otherwise.
+    fn random_flag(&mut self, flag: Flags) -> Flags {
+        if self.rng.gen_weighted_bool(3) { flag } else { FLAG_EMPTY }
+    }
+}
+
+/// Generates a random string from the expression given. Unbounded
+/// repetitions repeat at most `max_repeat` times more than they must.
+///
+/// The string isn't guaranteed to match the expression if it has any
+/// assertions.
+pub fn generate<R: Rng>(ast: &Ast, rng: &mut R, max_repeat: uint) -> ~str {
+    let mut g = Generator {
+        rng: rng,
+        max_repeat: max_repeat,
+        caps: vec!(),
+        buf: StrBuf::new(),
+    };
+    g.gen(ast);
+    g.buf.into_owned()
+}
+
+struct Generator<'a, R> {
+    rng: &'a mut R,
+    max_repeat: uint,
+    // The text generated for each capture group so far (for
+    // backreferences).
+    caps: Vec<Option<~str>>,
+    buf: StrBuf,
+}
+
+impl<'a, R: Rng> Generator<'a, R> {
+    fn gen(&mut self, ast: &Ast) {
+        match *ast {
+            Nothing | Begin(_) | End(_) | WordBoundary(_)
+            | Lookahead(_, _) | Lookbehind(_, _) => {}
+            Literal(c, flags) => {
+                let c = self.random_case(c, flags);
+                self.buf.push_char(c)
+            }
+            Dot(flags) => {
+                let c = self.any_char(flags & FLAG_DOTNL == 0);
+                self.buf.push_char(c)
+            }
+            Class(ref ranges, flags) => {
+                let c =
+                    if flags & FLAG_NEGATED > 0 {
+                        self.char_not_in(ranges.as_slice())
+                    } else {
+                        self.char_in(ranges.as_slice())
+                    };
+                match c {
+                    None => {}
+                    Some(c) => {
+                        let c = self.random_case(c, flags);
+                        self.buf.push_char(c)
+                    }
+                }
+            }
+            Capture(i, _, ref x) => {
+                let start = self.buf.len();
+                self.gen(&**x);
+                while self.caps.len() <= i {
+                    self.caps.push(None);
+                }
+                let text = self.buf.as_slice().slice_from(start).to_owned();
+                *self.caps.get_mut(i) = Some(text);
+            }
+            Backref(i, _) => {
+                let text = match self.caps.as_slice().get(i) {
+                    Some(&Some(ref text)) => text.clone(),
+                    _ => return,
+                };
+                self.buf.push_str(text.as_slice())
+            }
+            Atomic(ref x) => self.gen(&**x),
+            Cat(ref xs) => {
+                for x in xs.iter() {
+                    self.gen(&**x)
+                }
+            }
+            Alt(ref x, ref y) => {
+                if self.rng.gen() {
+                    self.gen(&**x)
+                } else {
+                    self.gen(&**y)
+                }
+            }
+            Rep(ref x, rep, _) => {
+                let (min, max) = match rep {
+                    ZeroOne => (0, 1),
+                    ZeroMore => (0, self.max_repeat),
+                    OneMore => (1, 1 + self.max_repeat),
+                    Counted(min, None) => (min, min + self.max_repeat),
+                    Counted(min, Some(max)) => (min, max),
+                };
+                let n = self.rng.gen_range(min, max + 1);
+                for _ in range(0, n) {
+                    self.gen(&**x)
+                }
+            }
+        }
+    }
+
+    // Returns a random character, which is printable ASCII most of the
+    // time. It's never `\n` if `no_newline` is true.
+    fn any_char(&mut self, no_newline: bool) -> char {
+        loop {
+            let c =
+                if self.rng.gen_weighted_bool(4) {
+                    char::from_u32(self.rng.gen_range(0u32, 0x110000))
+                } else {
+                    char::from_u32(self.rng.gen_range(0x20u32, 0x7f))
+                };
+            match c {
+                Some('\n') if no_newline => {}
+                Some(c) => return c,
+                None => {}
+            }
+        }
+    }
+
+    // Returns a random character from the ranges given, each character
+    // being equally likely.
+    fn char_in(&mut self, ranges: &[(char, char)]) -> Option<char> {
+        let total = ranges.iter().fold(0u32, |n, &(s, e)| {
+            n + (e as u32 - s as u32 + 1)
+        });
+        if total == 0 {
+            return None
+        }
+        let mut i = self.rng.gen_range(0, total);
+        for &(s, e) in ranges.iter() {
+            let size = e as u32 - s as u32 + 1;
+            if i < size {
+                return char::from_u32(s as u32 + i)
+            }
+            i -= size;
+        }
+        None
+    }
+
+    // Returns a random character that isn't in any of the ranges given. If
+    // one can't be found quickly, then `None` is returned.
+    fn char_not_in(&mut self, ranges: &[(char, char)]) -> Option<char> {
+        for _ in range(0, 100) {
+            let c = self.any_char(false);
+            if !ranges.iter().any(|&(s, e)| s <= c && c <= e) {
+                return Some(c)
+            }
+        }
+        None
+    }
+
+    // Changes the case of `c` half of the time if the expression is case
+    // insensitive.
+    fn random_case(&mut self, c: char, flags: Flags) -> char {
+        if flags & FLAG_NOCASE == 0 || self.rng.gen() {
+            return c
+        }
+        if c.is_uppercase() { c.to_lowercase() } else { c.to_uppercase() }
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Searching the lines of a `Reader`, like `grep` does.
+//!
+//! A `LineMatcher` reads its input one line at a time and yields every line
+//! that a regular expression matches, along with the line's number, where
+//! it starts in the input and where each match is in the line.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #![feature(phase)]
+//! # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+//! # fn main() {
+//! use std::io::BufReader;
+//! use regex::grep::LineMatcher;
+//!
+//! let text = "foo bar\nbaz\nbar bar\n";
+//! let re = regex!(r"bar");
+//! for m in LineMatcher::new(&re, BufReader::new(text.as_bytes())) {
+//!     let m = m.unwrap();
+//!     println!("{}: {} {}", m.line_number, m.line, m.matches);
+//! }
+//! // Output:
+//! // 1: foo bar [(4, 7)]
+//! // 3: bar bar [(0, 3), (4, 7)]
+//! # }
+//! ```
+
+use std::io::{Reader, BufferedReader, IoResult, EndOfFile};
+use re::Regex;
+
+/// A line of input that matched, as yielded by `LineMatcher`.
+#[deriving(Clone, Eq, Show)]
+pub struct LineMatch {
+    /// The number of the line in the input. The first line is `1`.
+    pub line_number: uint,
+    /// The byte offset in the input where the line starts.
+    pub offset: uint,
+    /// The text of the line, without its line terminator (`\n` or `\r\n`).
+    pub line: ~str,
+    /// The start and end byte indices in `line` of each successive
+    /// non-overlapping match (as returned by `Regex::find_iter`). There is
+    /// always at least one.
+    pub matches: Vec<(uint, uint)>,
+}
+
+/// An iterator over the lines of a `Reader` that a regular expression
+/// matches.
+///
+/// Each line is searched without its line terminator, so `$` matches at the
+/// end of every line. The iterator yields an error if one happens while
+/// reading (e.g., if the input isn't valid UTF-8), and it stops at the end
+/// of the input.
+///
+/// `'r` is the lifetime of the compiled expression and `R` is the type of
+/// the reader.
+pub struct LineMatcher<'r, R> {
+    re: &'r Regex,
+    rdr: BufferedReader<R>,
+    line_number: uint,
+    offset: uint,
+}
+
+impl<'r, R: Reader> LineMatcher<'r, R> {
+    /// Creates an iterator over the lines of `rdr` that `re` matches.
+    pub fn new(re: &'r Regex, rdr: R) -> LineMatcher<'r, R> {
+        LineMatcher {
+            re: re,
+            rdr: BufferedReader::new(rdr),
+            line_number: 0,
+            offset: 0,
+        }
+    }
+}
+
+impl<'r, R: Reader> Iterator<IoResult<LineMatch>> for LineMatcher<'r, R> {
+    fn next(&mut self) -> Option<IoResult<LineMatch>> {
+        loop {
+            let line = match self.rdr.read_line() {
+                Ok(line) => line,
+                Err(ref err) if err.kind == EndOfFile => return None,
+                Err(err) => return Some(Err(err)),
+            };
+            let offset = self.offset;
+            self.line_number += 1;
+            self.offset += line.len();
+
+            let line = trim_terminator(line.as_slice());
+            let matches: Vec<(uint, uint)> = self.re.find_iter(line).collect();
+            if matches.len() > 0 {
+                return Some(Ok(LineMatch {
+                    line_number: self.line_number,
+                    offset: offset,
+                    line: line.to_owned(),
+                    matches: matches,
+                }))
+            }
+        }
+    }
+}
+
+// Removes the `\n` or `\r\n` at the end of `line`, if there is one.
+fn trim_terminator<'a>(line: &'a str) -> &'a str {
+    if line.ends_with("\r\n") {
+        line.slice_to(line.len() - 2)
+    } else if line.ends_with("\n") {
+        line.slice_to(line.len() - 1)
+    } else {
+        line
+    }
+}
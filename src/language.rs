@@ -0,0 +1,305 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This module matches the intersections and complements of the languages
+// of regexes (i.e., the sets of strings that they match all of).
+//
+// A language is a boolean combination of the languages of some programs.
+// Each program is run as an automaton: the set of states (an instruction
+// and the counters of its counted loops) that it's in after reading a
+// prefix of the text, without any capture groups. That set is exactly what
+// the subset construction makes a DFA state of, so deciding whether the
+// text is in the program's language never needs backtracking. All of the
+// programs read the text together, one character at a time, so the text is
+// read once no matter how many languages were combined. This is the product
+// automaton, built as the text is read instead of ahead of time. At the end
+// of the text, each program either can match or can't, and the combination
+// decides whether the text is in the language. (Complement only has to
+// negate that, since the sets of states are deterministic.)
+//
+// Backreferences, lookarounds and atomic groups can't be matched like this,
+// so programs with them have no language.
+
+use collections::HashSet;
+use std::cmp;
+use std::mem;
+use compile::{
+    Program,
+    Match, OneChar, CharClass, Any, EmptyBegin, EmptyEnd, EmptyWordBoundary,
+    Save, OneBackref, LookStart, AtomicStart, LookEnd, Jump, Split,
+    CountedLoop, CountedNext,
+};
+use parse::{FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED,
+            FLAG_UNICODE, FLAG_FINAL_NEWLINE};
+use vm::{CharReader, class_cmp};
+
+/// A set of strings, made from the strings that regexes match all of with
+/// intersection and complement. (See `Regex::language`.)
+///
+/// Whether a string is in a language is decided by reading it once, with
+/// every regex in the language at the same time, and without backtracking.
+/// This takes `O(nm)` time, where `n` is the number of characters in the
+/// string and `m` is the number of instructions in all of the regexes'
+/// compiled programs.
+///
+/// # Example
+///
+/// Check that a string matches one regex but not another:
+///
+/// ```rust
+/// # use regex::Regex;
+/// let word = Regex::new(r"\w+").unwrap().language().unwrap();
+/// let number = Regex::new(r"\d+").unwrap().language().unwrap();
+/// let name = word.intersect(&number.complement());
+/// assert!(name.is_match("x86"));
+/// assert!(!name.is_match("86"));
+/// assert!(!name.is_match("x 86"));
+/// ```
+#[deriving(Clone)]
+pub struct Language {
+    progs: Vec<Program>,
+    expr: Expr,
+}
+
+// How the languages of the programs are combined.
+#[deriving(Clone)]
+enum Expr {
+    // The strings that the program at the index given matches all of.
+    Member(uint),
+    // The strings that aren't in the language.
+    Not(~Expr),
+    // The strings that are in both languages.
+    And(~Expr, ~Expr),
+}
+
+/// Returns the language of the strings that the program given matches all
+/// of, or `None` if it has backreferences, lookarounds or atomic groups.
+pub fn from_program(prog: Program) -> Option<Language> {
+    let automaton = prog.insts.iter().all(|inst| {
+        match *inst {
+            OneBackref(_, _) | LookStart(_, _, _) | AtomicStart(_)
+            | LookEnd => false,
+            _ => true,
+        }
+    });
+    if !automaton {
+        return None
+    }
+    Some(Language { progs: vec!(prog), expr: Member(0) })
+}
+
+impl Language {
+    /// Returns the language of the strings that are in both this language
+    /// and `other`.
+    pub fn intersect(&self, other: &Language) -> Language {
+        let mut progs = self.progs.clone();
+        progs.push_all(other.progs.as_slice());
+        let other = other.expr.shift(self.progs.len());
+        Language { progs: progs, expr: And(~self.expr.clone(), ~other) }
+    }
+
+    /// Returns the language of the strings that aren't in this language.
+    pub fn complement(&self) -> Language {
+        Language { progs: self.progs.clone(), expr: Not(~self.expr.clone()) }
+    }
+
+    /// Returns true if and only if `text` is in the language. All of `text`
+    /// is matched, so the regexes in the language behave as if they were
+    /// anchored with `^` and `$`.
+    pub fn is_match(&self, text: &str) -> bool {
+        let matched = run(self.progs.as_slice(), text);
+        self.expr.eval(matched.as_slice())
+    }
+}
+
+impl Expr {
+    // Returns the same combination of the programs `offset` places later.
+    fn shift(&self, offset: uint) -> Expr {
+        match *self {
+            Member(i) => Member(i + offset),
+            Not(ref x) => Not(~x.shift(offset)),
+            And(ref x, ref y) => And(~x.shift(offset), ~y.shift(offset)),
+        }
+    }
+
+    // Decides whether the text is in the language, given whether each
+    // program matches all of it.
+    fn eval(&self, matched: &[bool]) -> bool {
+        match *self {
+            Member(i) => matched[i],
+            Not(ref x) => !x.eval(matched),
+            And(ref x, ref y) => x.eval(matched) && y.eval(matched),
+        }
+    }
+}
+
+// Reads `text` once, running every program on it at the same time, and
+// returns whether each one matches all of it.
+fn run(progs: &[Program], text: &str) -> Vec<bool> {
+    let mut chars = CharReader::new(text);
+    chars.set(0);
+    let mut states: Vec<States> = progs.iter().map(|prog| {
+        let mut states = States::new(prog);
+        states.start(&chars);
+        states
+    }).collect();
+    while !chars.is_end() && states.iter().any(|s| s.list.len() > 0) {
+        chars.advance();
+        let c = chars.prev.unwrap();
+        for states in states.mut_iter() {
+            states.step(&chars, c);
+        }
+    }
+    states.iter().map(|s| s.is_match()).collect()
+}
+
+// The states that a program is in after reading part of the text.
+struct States<'r> {
+    prog: &'r Program,
+    // The states that read a character or match, in no particular order.
+    list: Vec<(uint, Vec<uint>)>,
+    // Every state reached at the current position, including those of
+    // instructions that don't read anything, so that each one is only
+    // followed once.
+    seen: HashSet<(uint, Vec<uint>)>,
+}
+
+impl<'r> States<'r> {
+    fn new(prog: &'r Program) -> States<'r> {
+        States { prog: prog, list: vec!(), seen: HashSet::new() }
+    }
+
+    // Adds the start of the program, at the beginning of the text.
+    fn start(&mut self, chars: &CharReader) {
+        let mut counts = Vec::from_elem(self.prog.num_counters(), 0u);
+        self.add(chars, 0, counts.as_mut_slice());
+    }
+
+    // Moves every state past the character `c`, which `chars` has just
+    // read.
+    fn step(&mut self, chars: &CharReader, c: char) {
+        let list = mem::replace(&mut self.list, vec!());
+        self.seen.clear();
+        for &(pc, ref counts) in list.iter() {
+            if self.reads(pc, c) {
+                let mut counts = counts.clone();
+                self.add(chars, pc + 1, counts.as_mut_slice());
+            }
+        }
+    }
+
+    // Returns true if the instruction at `pc` reads the character `c`.
+    fn reads(&self, pc: uint, c: char) -> bool {
+        match *self.prog.insts.get(pc) {
+            OneChar(regc, flags) => {
+                regc == c
+                || (flags & FLAG_NOCASE > 0
+                    && regc.to_uppercase() == c.to_uppercase())
+            }
+            CharClass(ref ranges, flags) => {
+                let negate = flags & FLAG_NEGATED > 0;
+                let casei = flags & FLAG_NOCASE > 0;
+                let found = ranges.as_slice();
+                let found = found.bsearch(|&rc| class_cmp(casei, c, rc));
+                found.is_some() != negate
+            }
+            Any(flags) => flags & FLAG_DOTNL > 0 || c != '\n',
+            _ => false,
+        }
+    }
+
+    // Returns true if the program can match at the current position.
+    fn is_match(&self) -> bool {
+        self.list.iter().any(|&(pc, _)| {
+            match *self.prog.insts.get(pc) {
+                Match => true,
+                _ => false,
+            }
+        })
+    }
+
+    // Adds the state at `pc` with the counters given, following every
+    // instruction that doesn't read a character (like the VM does).
+    fn add(&mut self, chars: &CharReader, pc: uint, counts: &mut [uint]) {
+        if !self.seen.insert((pc, Vec::from_slice(counts))) {
+            return
+        }
+        match *self.prog.insts.get(pc) {
+            Match | OneChar(_, _) | CharClass(_, _) | Any(_) => {
+                self.list.push((pc, Vec::from_slice(counts)));
+            }
+            EmptyBegin(flags) => {
+                let multi = flags & FLAG_MULTI > 0;
+                if chars.is_begin() || (multi && chars.prev == Some('\n')) {
+                    self.add(chars, pc + 1, counts)
+                }
+            }
+            EmptyEnd(flags) => {
+                let ok =
+                    if flags & FLAG_FINAL_NEWLINE > 0 {
+                        chars.is_end_or_final_newline()
+                    } else {
+                        chars.is_end()
+                        || (flags & FLAG_MULTI > 0 && chars.cur == Some('\n'))
+                    };
+                if ok {
+                    self.add(chars, pc + 1, counts)
+                }
+            }
+            EmptyWordBoundary(flags) => {
+                let boundary =
+                    if flags & FLAG_UNICODE > 0 {
+                        chars.is_word_boundary()
+                    } else {
+                        chars.is_ascii_word_boundary()
+                    };
+                if boundary == !(flags & FLAG_NEGATED > 0) {
+                    self.add(chars, pc + 1, counts)
+                }
+            }
+            Save(_) => self.add(chars, pc + 1, counts),
+            Jump(to) => self.add(chars, to, counts),
+            Split(x, y) => {
+                self.add(chars, x, counts);
+                self.add(chars, y, counts);
+            }
+            CountedLoop(k, min, max, _, exit) => {
+                let n = counts[k];
+                if n < min {
+                    self.add(chars, pc + 1, counts)
+                } else if max == Some(n) {
+                    self.exit_loop(chars, k, exit, counts)
+                } else {
+                    self.add(chars, pc + 1, counts);
+                    self.exit_loop(chars, k, exit, counts);
+                }
+            }
+            CountedNext(k, limit, start) => {
+                let n = counts[k];
+                counts[k] = cmp::min(n + 1, limit);
+                self.add(chars, start, counts);
+                counts[k] = n;
+            }
+            // Programs with these have no language. (See `from_program`.)
+            OneBackref(_, _) | LookStart(_, _, _) | AtomicStart(_)
+            | LookEnd => unreachable!(),
+        }
+    }
+
+    // Leaves the counted loop with counter `k` for the instruction at `exit`,
+    // resetting the counter (like the VM does).
+    fn exit_loop(&mut self, chars: &CharReader, k: uint, exit: uint,
+                 counts: &mut [uint]) {
+        let n = counts[k];
+        counts[k] = 0;
+        self.add(chars, exit, counts);
+        counts[k] = n;
+    }
+}
@@ -75,8 +75,13 @@
 //! }
 //! ```
 //!
+//! Flags for the whole expression can also be given in a second string
+//! literal, so `regex!(r"^foo$", "im")` is the same as `regex!(r"(?im)^foo$")`.
+//! The flags are checked when your program is compiled.
+//!
 //! There are a few things worth mentioning about using the `regex!` macro.
-//! Firstly, the `regex!` macro *only* accepts string *literals*.
+//! Firstly, the `regex!` macro *only* accepts string *literals* (or macros
+//! that expand to one, like `concat!`).
 //! Secondly, the `regex` crate *must* be linked with the name `regex` since
 //! the generated code depends on finding symbols in the `regex` crate.
 //!
@@ -84,7 +89,11 @@
 //! size of your program's binary since it generates specialized Rust code.
 //! The extra size probably won't be significant for a small number of
 //! expressions, but 100+ calls to `regex!` will probably result in a
-//! noticeably bigger binary.
+//! noticeably bigger binary. If that's a problem, then the `regex_small!`
+//! macro (which is used the same way) checks and compiles an expression
+//! when your program is compiled too, but instead of generating code, it
+//! embeds the compiled expression as data. Searching with it is exactly as
+//! fast as with `Regex::new`.
 //!
 //! # Example: iterating over capture groups
 //!
@@ -201,18 +210,49 @@
 //! [a-z]       A character class matching any character in range a-z.
 //! \d          Perl character class ([0-9])
 //! \D          Negated Perl character class ([^0-9])
-//! [:alpha:]   ASCII character class ([A-Za-z])
-//! [:^alpha:]  Negated ASCII character class ([^A-Za-z])
+//! [[:alpha:]] ASCII character class ([A-Za-z])
+//! [[:^alpha:]] Negated ASCII character class ([^A-Za-z])
 //! \pN         One letter name Unicode character class
 //! \p{Greek}   Unicode character class (general category or script)
 //! \PN         Negated one letter name Unicode character class
 //! \P{Greek}   negated Unicode character class (general category or script)
+//! \p{Alphabetic} Unicode binary property (see below for the full list)
+//! \p{gc=Lu}    Unicode general category
+//! \p{sc=Greek} Unicode script (also \p{Script=Greek})
+//! \p{InGreek}  Unicode block (also \p{Block=Greek_and_Coptic})
+//! \p{scx:Greek} Unicode script extensions (includes characters shared by
+//!              several scripts, like U+0342 COMBINING GREEK PERISPOMENI)
 //! </pre>
 //!
+//! Unicode class names are matched loosely, so case, spaces, underscores and
+//! hyphens are ignored. General categories may also be given by their long
+//! names. For example, `\p{Ll}`, `\p{lowercase letter}` and
+//! `\p{Lowercase_Letter}` are all the same class. Property names (before
+//! `=` or `:`) may be `General_Category` (`gc`), `Script` (`sc`),
+//! `Script_Extensions` (`scx`) or `Block` (`blk`). Blocks are ranges of code
+//! points set aside for a script or purpose, and usually have characters
+//! that belong to other scripts (and unassigned code points) as well, so
+//! scripts are the better choice unless you really do mean a block.
+//!
+//! The binary properties that are supported are `Alphabetic`,
+//! `ASCII_Hex_Digit`, `Case_Ignorable`, `Cased`, `Dash`, `Diacritic`,
+//! `Hex_Digit`, `ID_Continue`, `ID_Start`, `Ideographic`, `Join_Control`,
+//! `Lowercase`, `Math`, `Quotation_Mark`, `Uppercase`, `White_Space`,
+//! `XID_Continue` and `XID_Start`.
+//!
 //! Any named character class may appear inside a bracketed `[...]` character
 //! class. For example, `[\p{Greek}\pN]` matches any Greek or numeral
 //! character.
 //!
+//! Inside a bracketed class, the sets of characters on either side of `&&`
+//! (intersection), `--` (subtraction) or `~~` (symmetric difference) can be
+//! combined. The right hand side of an operator may also be a nested
+//! bracketed class. Operators are applied from left to right and a leading
+//! `^` negates the result. For example, `[\p{Greek}--\p{Lu}]` matches any
+//! Greek character that isn't uppercase and `[\w&&[^_]]` matches any word
+//! character except for an underscore. (With the `i` flag, the operators are
+//! applied before case is ignored.)
+//!
 //! ## Composites
 //!
 //! <pre class="rust">
@@ -231,9 +271,11 @@
 //! x??       zero or one of x (ungreedy)
 //! x{n,m}    at least n and at most x (greedy)
 //! x{n,}     at least n x (greedy)
+//! x{,m}     at most m x (greedy)
 //! x{n}      exactly n x
 //! x{n,m}?   at least n and at most x (ungreedy)
 //! x{n,}?    at least n x (ungreedy)
+//! x{,m}?    at most m x (ungreedy)
 //! x{n}?     exactly n x
 //! </pre>
 //!
@@ -244,18 +286,26 @@
 //! $     the end of text (or end-of-line with multi-line mode)
 //! \A    only the beginning of text (even with multi-line mode enabled)
 //! \z    only the end of text (even with multi-line mode enabled)
+//! \Z    the end of text or just before a final \n (even with multi-line mode)
 //! \b    a Unicode word boundary (\w on one side and \W, \A, or \z on other)
 //! \B    not a Unicode word boundary
 //! </pre>
 //!
+//! Word boundaries use the same Unicode definition of a word character as
+//! `\w`, so `\b` works as expected on text that isn't ASCII, like French or
+//! Greek. When the `u` flag is cleared, they use the ASCII class
+//! `[0-9A-Za-z_]` instead, like `\w` does.
+//!
 //! ## Grouping and flags
 //!
 //! <pre class="rust">
 //! (exp)          numbered capture group (indexed by opening parenthesis)
 //! (?P&lt;name&gt;exp)  named (also numbered) capture group (allowed chars: [_0-9a-zA-Z])
+//! (?&lt;name&gt;exp)   named capture group (the same as (?P&lt;name&gt;exp))
 //! (?:exp)        non-capturing group
 //! (?flags)       set flags within current group
 //! (?flags:exp)   set flags for exp (non-capturing)
+//! (?#comment)    a comment, which is ignored (it ends at the first `)`)
 //! </pre>
 //!
 //! Flags are each a single character. For example, `(?x)` sets the flag `x`
@@ -263,15 +313,37 @@
 //! the same time: `(?xy)` sets both the `x` and `y` flags and `(?x-y)` sets
 //! the `x` flag and clears the `y` flag.
 //!
-//! All flags are by default disabled. They are:
+//! All flags except for `u` are by default disabled. They are:
 //!
 //! <pre class="rust">
 //! i     case insensitive
 //! m     multi-line mode: ^ and $ match begin/end of line
 //! s     allow . to match \n
 //! U     swap the meaning of x* and x*?
+//! x     ignore whitespace and allow line comments (starting with `#`)
+//! u     Unicode support for \d, \s and \w (enabled by default)
 //! </pre>
 //!
+//! In free-spacing mode (`x`), whitespace is ignored everywhere except in
+//! character classes, and everything from an unescaped `#` to the end of the
+//! line is a comment. Whitespace and `#` can still be matched by escaping
+//! them with a `\` (or by putting them in a character class):
+//!
+//! ```rust
+//! # #![feature(phase)]
+//! # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+//! # fn main() {
+//! let re = regex!(r"(?x)
+//!   (?P<y>\d{4}) # the year
+//!   -
+//!   (?P<m>\d{2}) # the month
+//!   \ [#]        # a space and a hash
+//! ");
+//! let cap = re.captures("2014-01 #").unwrap();
+//! assert_eq!(cap.name("m"), "01");
+//! # }
+//! ```
+//!
 //! Here's an example that matches case insensitively for only part of the
 //! expression:
 //!
@@ -292,17 +364,41 @@
 //!
 //! <pre class="rust">
 //! \*         literal *, works for any punctuation character: \.+*?()|[]{}^$
+//! \#         literal #, also works for whitespace (useful with the x flag)
 //! \a         bell (\x07)
 //! \f         form feed (\x0C)
 //! \t         horizontal tab
 //! \n         new line
 //! \r         carriage return
-//! \v         vertical tab (\x0B)
+//! \e         escape (\x1B)
+//! \cA        control character (\cA through \cZ are \x01 through \x1A)
 //! \123       octal character code (up to three digits)
 //! \x7F       hex character code (exactly two digits)
 //! \x{10FFFF} any hex character code corresponding to a valid UTF8 codepoint
+//! \u00E9     hex character code (exactly four digits)
+//! \U0001F600 hex character code (exactly eight digits)
+//! \Q...\E    every character between \Q and \E is matched literally
 //! </pre>
 //!
+//! An escape like `\1` is a backreference when backtracking is enabled (see
+//! below). Otherwise, it's an octal escape, unless a capture group with
+//! that number has been opened before it (or the digit is `8` or `9`), in
+//! which case it's an error, since it was almost certainly meant to be a
+//! backreference. A leading zero (`\01`) is always octal.
+//!
+//! If a `\Q` has no matching `\E`, then the rest of the expression is
+//! quoted. This makes it easy to embed arbitrary text in a pattern:
+//!
+//! ```rust
+//! # #![feature(phase)]
+//! # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+//! # fn main() {
+//! let re = regex!(r"^\Q1+1=2?\E$");
+//! assert!(re.is_match("1+1=2?"));
+//! assert!(!re.is_match("11=2"));
+//! # }
+//! ```
+//!
 //! ## Perl character classes (Unicode friendly)
 //!
 //! <pre class="rust">
@@ -312,10 +408,37 @@
 //! \S     not whitespace
 //! \w     word character ([0-9A-Za-z_] + \p{L})
 //! \W     not word character
+//! \h     horizontal whitespace ([\t ] + \p{Zs})
+//! \H     not horizontal whitespace
+//! \v     vertical whitespace ([\n\v\f\r] + \x85 + \p{Zl} + \p{Zp})
+//! \V     not vertical whitespace
 //! </pre>
 //!
+//! There is also `\R`, which matches any line break: `\r\n` or a single
+//! vertical whitespace character. It is the same as `(?:\r\n|\v)` and can't
+//! be used inside a bracketed class.
+//!
+//! (Note that `\v` is not a vertical tab, although a vertical tab is vertical
+//! whitespace. Use `\x0B` to match only a vertical tab.)
+//!
+//! When the `u` flag is cleared, these classes only match ASCII characters:
+//! `\d` is `[0-9]`, `\s` is `[\t\n\f\r ]`, `\w` is `[0-9A-Za-z_]`, `\h` is
+//! `[\t ]` and `\v` is `[\n\x0B\f\r]`. This can be done for part of an
+//! expression, as in `(?-u:\w+)`, or for the whole expression with
+//! `RegexBuilder`:
+//!
+//! ```rust
+//! # use regex::RegexBuilder;
+//! let re = RegexBuilder::new(r"\w+").unicode(false).build().unwrap();
+//! assert_eq!(re.find("δabc"), Some((2, 5)));
+//! ```
+//!
 //! ## ASCII character classes
 //!
+//! These are the POSIX classes, and like in other engines, they're written
+//! inside a bracketed character class, e.g., `[[:alpha:]]` or
+//! `[[:digit:][:punct:]_]`.
+//!
 //! <pre class="rust">
 //! [:alnum:]    alphanumeric ([0-9A-Za-z])
 //! [:alpha:]    alphabetic ([A-Za-z])
@@ -333,24 +456,84 @@
 //! [:xdigit:]   hex digit ([0-9A-Fa-f])
 //! </pre>
 //!
+//! For compatibility with older versions of this crate, a bare `[:alpha:]`
+//! outside of brackets is also accepted as the class itself. Other engines
+//! read it as a class of the characters `:`, `a`, `l`, `p` and `h` instead
+//! (and `grep` rejects it), so the bracketed form should be preferred.
+//!
+//! # Backtracking
+//!
+//! Some constructs can't be matched in linear time, so they aren't allowed
+//! by default. They can be enabled with `RegexBuilder::backtrack`, which
+//! runs any expression that uses them with a backtracking engine instead:
+//!
+//! <pre class="rust">
+//! \1          backreference: the text last matched by group 1 (up to \99)
+//! (?P=name)   backreference to a named group
+//! (?=exp)     lookahead: exp matches here (without consuming any text)
+//! (?!exp)     negative lookahead: exp doesn't match here
+//! (?<=exp)    lookbehind: exp matches text that ends here
+//! (?<!exp)    negative lookbehind: exp doesn't match text that ends here
+//! (?>exp)     atomic group: the first match of exp is never given up
+//! x*+ x++ x?+ possessive repetition: the same as (?>x*), (?>x+), (?>x?)
+//! x{n,m}+     possessive counted repetition: the same as (?>x{n,m})
+//! </pre>
+//!
+//! For example, this finds quoted text with a backreference and a password
+//! with a digit and a letter with lookaheads:
+//!
+//! ```rust
+//! # use regex::RegexBuilder;
+//! let re = RegexBuilder::new(r#"(?P<q>['"]).*?(?P=q)"#).backtrack(true)
+//!                                                     .build().unwrap();
+//! assert_eq!(re.find(r#"say "it's" now"#), Some((4, 10)));
+//!
+//! let re = RegexBuilder::new(r"^(?=.*\d)(?=.*[a-z])\w{8,}$")
+//!                      .backtrack(true).build().unwrap();
+//! assert!(re.is_match("hunter22"));
+//! assert!(!re.is_match("hunterhunter"));
+//! ```
+//!
+//! The expression in a lookbehind must match a bounded amount of text, so it
+//! can't contain `*`, `+` or backreferences. For example, `(?<!\\)"` finds
+//! a quote that isn't escaped with a backslash.
+//!
+//! A backreference to a group that hasn't matched never matches. Capture
+//! groups in a lookaround keep what they matched, unless the lookaround is
+//! negative. The work done by the backtracking engine is limited (see
+//! `RegexBuilder::backtrack_limit`). A search that exceeds the limit stops
+//! without deciding whether there's a match: `Regex::try_is_match`,
+//! `Regex::try_find` and `Regex::try_captures` return a `LimitExceeded`
+//! error, and the other search methods report no match (which may be
+//! wrong).
+//!
 //! # Untrusted input
 //!
 //! There are two factors to consider here: untrusted regular expressions and
 //! untrusted search text.
 //!
-//! Currently, there are no counter-measures in place to prevent a malicious
-//! user from writing an expression that may use a lot of resources. One such
-//! example is to repeat counted repetitions: `((a{100}){100}){100}` will try
-//! to repeat the `a` instruction `100^3` times. Essentially, this means it's
-//! very easy for an attacker to exhaust your system's memory if they are
-//! allowed to execute arbitrary regular expressions. A possible solution to
-//! this is to impose a hard limit on the size of a compiled expression, but it
-//! does not yet exist.
+//! Counted repetitions compile to a few loop instructions, but every thread
+//! running them keeps its own counters, so a search may have to track a
+//! thread for each repetition of an expression. To bound this, an expression
+//! can be repeated at most `100000` times in all, counting the repetitions
+//! it's nested in. For example, `a{100000}` is allowed, but
+//! `((a{100}){100}){100}` (which repeats `a` a million times) is not. This
+//! keeps the number of threads under `100000` times the number of
+//! instructions in the compiled expression.
+//!
+//! Other than that, there are no counter-measures in place to prevent a
+//! malicious user from writing an expression that may use a lot of
+//! resources. For example, a long expression (or a long alternation of
+//! Unicode classes) compiles to a large program. A possible solution to
+//! this is to impose a hard limit on the size of a compiled expression, but
+//! it does not yet exist.
 //!
 //! The story is a bit better with untrusted search text, since this crate's
 //! implementation provides `O(nm)` search where `n` is the number of
 //! characters in the search text and `m` is the number of instructions in a
-//! compiled expression.
+//! compiled expression. (Except for expressions that need backtracking,
+//! which can only be compiled when it is explicitly enabled. In a counted
+//! loop, each value of its counter counts as a separate instruction.)
 
 #![crate_id = "regex#0.11-pre"]
 #![crate_type = "rlib"]
@@ -365,31 +548,104 @@
 #![deny(missing_doc)]
 
 extern crate collections;
+extern crate rand;
+extern crate sync;
 #[cfg(test)]
 extern crate stdtest = "test";
 #[cfg(test)]
-extern crate rand;
+extern crate time;
 
 // During tests, this links with the `regex` crate so that the `regex!` macro
 // can be tested.
 #[cfg(test)]
 extern crate regex;
 
-pub use parse::Error;
-pub use re::{Regex, Captures, SubCaptures, SubCapturesPos};
-pub use re::{FindCaptures, FindMatches};
-pub use re::{Replacer, NoExpand, RegexSplits, RegexSplitsN};
-pub use re::{quote, is_match};
+pub use parse::{
+    Error, ErrorKind,
+    UnclosedParen, UnopenedParen, EmptyGroup, UnexpectedEof, UnexpectedChar,
+    RepeatNoExpression, DoubleRepeat, RepeatEmptyWidth, UnclosedRepetition,
+    MissingMinRepetition, RepetitionTooLarge, InvalidRepetitionRange,
+    InvalidInteger, InvalidRange, InvalidClassEscape, InvalidEscape,
+    InvalidControl, UnclosedBrace, InvalidOctal, InvalidHex, InvalidCodepoint,
+    MissingUnicodeClassName, UnknownUnicodeClass, UnclosedCaptureName,
+    EmptyCaptureName, InvalidCaptureIndex, InvalidCaptureName,
+    DuplicateCaptureName,
+    UnclosedBackrefName, UndefinedGroup, UndefinedGroupName,
+    UnboundedLookbehind, BacktrackRequired, DoubleFlagNegation,
+    EmptyFlagNegation, UnrecognizedFlag,
+};
+pub use re::{Regex, RegexBuilder, Captures, SubCaptures, SubCapturesPos};
+pub use re::LimitExceeded;
+pub use re::SubCapturesNamed;
+pub use re::{Feed, FeedState, FullMatch, PartialMatch, NoMatch};
+pub use vm::{MatchKind, Exists, Location, Submatches, Input};
+pub use re::{ProgramStats, Literals};
+pub use re::{Explanation, Engine, NfaEngine, BacktrackEngine, NativeEngine};
+pub use re::{StartScan, AnchoredScan, PrefixScan, PrefixSetScan, FullScan};
+pub use re::{FindCaptures, FindMatches, RFindMatches};
+pub use re::{Replacer, NoExpand, RegexSplits, RegexSplitsN, RegexSplitsPos};
+pub use re::{quote, quote_into, is_meta_character, is_match, replace_all_multi};
+pub use language::Language;
+pub use lint::{
+    lint, Lint, LintKind, AlwaysEmptyGroup, NestedRepetition, RedundantEscape,
+    SuspiciousRange, EmptyClass, BarePosixClass,
+};
 
+pub mod cached;
+pub mod conformance;
+pub mod gen;
+pub mod grep;
+pub mod parallel;
+pub mod pattern;
+pub mod scan;
+
+mod backtrack;
 mod compile;
+mod language;
+mod lint;
+mod literals;
 mod parse;
 mod re;
+mod simplify;
 mod vm;
 
 // FIXME(#13725) windows needs fixing.
 #[cfg(test, not(windows))]
 mod test;
 
+/// The `syntax` module provides access to the parser used by this crate.
+///
+/// It can be used by tools that want to inspect or manipulate a regular
+/// expression without compiling it. The abstract syntax given here is
+/// exactly what gets compiled by `Regex::new` and `regex!`.
+///
+/// # Example
+///
+/// ```rust
+/// use regex::syntax::{parse, Cat, Literal};
+///
+/// match *parse("ab").unwrap() {
+///     Cat(ref xs) => assert_eq!(xs.len(), 2),
+///     ref ast => fail!("unexpected AST: {}", ast),
+/// }
+/// ```
+pub mod syntax {
+    pub use parse::{
+        parse, parse_with_flags, parse_with_spans, parse_with_recovery,
+        tokenize, Error, Ast, Token, TokenKind,
+        LiteralToken, EscapeToken, ClassToken, RepeatToken, AnchorToken,
+        BackrefToken, GroupOpenToken, GroupCloseToken, FlagsToken, AltToken,
+        CommentToken, ErrorToken,
+        Nothing, Literal, Dot, Class, Begin, End, WordBoundary, Capture, Cat,
+        Alt, Rep, Backref, Lookahead, Lookbehind, Atomic,
+        Repeater, ZeroOne, ZeroMore, OneMore, Counted,
+        Greed, Greedy, Ungreedy,
+        Flags, FLAG_EMPTY, FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL,
+        FLAG_SWAP_GREED, FLAG_NEGATED, FLAG_IGNORE_SPACE, FLAG_UNICODE,
+        FLAG_FINAL_NEWLINE, FLAG_BACKTRACK,
+    };
+}
+
 /// The `program` module exists to support the `regex!` macro. Do not use.
 #[doc(hidden)]
 pub mod native {
@@ -409,18 +665,21 @@ pub mod native {
     // On the bright side, `rustdoc` lets us hide this from the public API
     // documentation.
     pub use compile::{
-        Program,
+        Program, LazyProgram,
         OneChar, CharClass, Any, Save, Jump, Split,
         Match, EmptyBegin, EmptyEnd, EmptyWordBoundary,
+        OneBackref, LookStart, AtomicStart, LookEnd,
+        CountedLoop, CountedNext,
     };
     pub use parse::{
-        FLAG_EMPTY, FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL,
-        FLAG_SWAP_GREED, FLAG_NEGATED,
+        Flags, FLAG_EMPTY, FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL,
+        FLAG_SWAP_GREED, FLAG_NEGATED, FLAG_UNICODE, FLAG_FINAL_NEWLINE,
     };
     pub use re::{Dynamic, Native};
     pub use vm::{
         MatchKind, Exists, Location, Submatches,
         StepState, StepMatchEarlyReturn, StepMatch, StepContinue,
-        CharReader, find_prefix,
+        CharReader, ScratchPool,
     };
+    pub use literals::{AhoCorasick, find_prefix};
 }
@@ -0,0 +1,336 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This module finds constructs in an expression that are valid but probably
+// not what was meant (for `lint`).
+//
+// Most of the checks work on the tokens of the expression (see
+// `parse::tokenize`), since they need to know where each construct is. The
+// rest work on the abstract syntax, and find where a construct is by its
+// position among the others of its kind: capture groups by their number,
+// and repetitions by their order, which is the same in the syntax (children
+// before their parents) as it is in the tokens.
+
+use compile::max_len;
+use parse;
+use parse::{
+    Ast, Flags, Capture, Cat, Alt, Rep, Lookahead, Lookbehind, Atomic, Class,
+    ZeroOne, ZeroMore, OneMore, Counted, Repeater,
+    FLAG_UNICODE, FLAG_BACKTRACK, FLAG_IGNORE_SPACE, FLAG_NEGATED,
+    Token, EscapeToken, ClassToken, RepeatToken, GroupOpenToken,
+    GroupCloseToken, FlagsToken,
+    combine_ranges, negate_ranges,
+};
+
+/// A construct in an expression that is probably a mistake, as found by
+/// `lint`.
+#[deriving(Show, Eq, Clone)]
+pub struct Lint {
+    /// What kind of mistake it is.
+    pub kind: LintKind,
+    /// The start and end byte indices of the construct in the expression.
+    pub span: (uint, uint),
+    /// A message describing the mistake.
+    pub msg: ~str,
+}
+
+/// The kinds of mistakes that `lint` finds.
+#[deriving(Show, Eq, Clone)]
+pub enum LintKind {
+    /// A group that can only match the empty string, like `()` or `(\b)`.
+    AlwaysEmptyGroup,
+    /// An unbounded repetition of an expression that has an unbounded
+    /// repetition itself, like `(a+)*`. These can take exponential time
+    /// when backtracking.
+    NestedRepetition,
+    /// A character that is escaped but doesn't need to be, like `[\.]`, or
+    /// `\#` without the `x` flag.
+    RedundantEscape,
+    /// A character class range between different kinds of characters, like
+    /// `[A-z]` (which includes `[`, `\`, `]`, `^`, `_` and a backtick).
+    SuspiciousRange,
+    /// A character class that can't match any character, like `[a&&b]`.
+    EmptyClass,
+    /// An ASCII class that isn't inside brackets, like `[:alpha:]`. Other
+    /// engines read it as a class of the characters in it.
+    BarePosixClass,
+}
+
+/// Returns the constructs in an expression that are probably mistakes, in
+/// the order they appear. If the expression can't be parsed, then an error
+/// is returned instead.
+///
+/// The expression is parsed as it is by a regex with backtracking enabled,
+/// so every construct that is valid with either engine is allowed.
+///
+/// # Example
+///
+/// ```rust
+/// use regex::{lint, LintKind, NestedRepetition, SuspiciousRange};
+///
+/// let lints = lint(r"([A-z]+)*").unwrap();
+/// let kinds: Vec<(LintKind, (uint, uint))> =
+///     lints.move_iter().map(|l| (l.kind, l.span)).collect();
+/// assert_eq!(kinds, vec![(SuspiciousRange, (2, 5)),
+///                        (NestedRepetition, (8, 9))]);
+/// ```
+pub fn lint(re: &str) -> Result<Vec<Lint>, parse::Error> {
+    let flags = FLAG_UNICODE | FLAG_BACKTRACK;
+    let (ast, spans) = try!(parse::parse_with_spans(re, flags));
+    let tokens = parse::tokenize(re, flags);
+    let mut lints = vec!();
+    lint_tokens(re, flags, tokens.as_slice(), &mut lints);
+
+    let mut caps = vec!();
+    always_empty_captures(&*ast, &mut caps);
+    for &i in caps.iter() {
+        lints.push(Lint {
+            kind: AlwaysEmptyGroup,
+            span: *spans.get(i - 1),
+            msg: ~"This group can only match the empty string.",
+        });
+    }
+
+    let mut nested = vec!();
+    nested_reps(&*ast, &mut nested);
+    let reps = tokens.iter().filter(|t| t.kind == RepeatToken);
+    for (&is_nested, t) in nested.iter().zip(reps) {
+        if is_nested {
+            lints.push(Lint {
+                kind: NestedRepetition,
+                span: t.span,
+                msg: ~"This repeats an expression that has an unbounded \
+                       repetition itself, which can be very slow when \
+                       backtracking.",
+            });
+        }
+    }
+    lints.sort_by(|l1, l2| l1.span.cmp(&l2.span));
+    Ok(lints)
+}
+
+// Finds the lints that can be found in the tokens: empty non-capturing
+// groups, redundant escapes and the mistakes in character classes.
+fn lint_tokens(re: &str, flags: Flags, tokens: &[Token],
+               lints: &mut Vec<Lint>) {
+    // The flags outside of each open group, and the flags where the token
+    // is.
+    let mut stack = vec!();
+    let mut flags = flags;
+    for (i, t) in tokens.iter().enumerate() {
+        let (start, end) = t.span;
+        let text = re.slice(start, end);
+        match t.kind {
+            GroupOpenToken(inner) => {
+                stack.push(flags);
+                flags = inner;
+                let closed = match tokens.get(i + 1) {
+                    Some(t) => t.kind == GroupCloseToken,
+                    None => false,
+                };
+                // Empty capture groups are found with the others.
+                if closed && text.starts_with("(?") && !is_named(text) {
+                    let (_, close) = tokens[i + 1].span;
+                    lints.push(Lint {
+                        kind: AlwaysEmptyGroup,
+                        span: (start, close),
+                        msg: ~"This group is empty.",
+                    });
+                }
+            }
+            GroupCloseToken => flags = stack.pop().unwrap_or(flags),
+            FlagsToken(after) => flags = after,
+            EscapeToken if flags & FLAG_IGNORE_SPACE == 0 => {
+                let c = text.char_at(1);
+                if c == '#' || c.is_whitespace() {
+                    lints.push(redundant_escape(start, end, c));
+                }
+            }
+            ClassToken if text.starts_with("[:") && text.ends_with(":]") => {
+                lints.push(Lint {
+                    kind: BarePosixClass,
+                    span: t.span,
+                    msg: format!("Write '[{}]' instead, as other engines \
+                                  only recognize ASCII classes inside \
+                                  brackets.", text),
+                });
+            }
+            ClassToken if text.starts_with("[") => {
+                lint_class(text, start, lints);
+                if class_is_empty(text, flags) {
+                    lints.push(Lint {
+                        kind: EmptyClass,
+                        span: t.span,
+                        msg: ~"This class can't match any character.",
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Returns true if the group opened by `text` is a named capture group.
+fn is_named(text: &str) -> bool {
+    text.starts_with("(?P<")
+    || (text.starts_with("(?<") && !text.starts_with("(?<=")
+        && !text.starts_with("(?<!"))
+}
+
+// Finds redundant escapes and suspicious ranges in the text of a bracketed
+// character class, which starts at `offset` in the expression.
+fn lint_class(text: &str, offset: uint, lints: &mut Vec<Lint>) {
+    let chars: Vec<(uint, char)> = text.char_indices().collect();
+    let mut i = 1;
+    while i < chars.len() {
+        let (pos, c) = *chars.get(i);
+        if c == '\\' && i + 1 < chars.len() {
+            let (_, c2) = *chars.get(i + 1);
+            match c2 {
+                '.' | '+' | '*' | '?' | '(' | ')' | '|' | '{' | '}' | '$' => {
+                    let start = offset + pos;
+                    lints.push(redundant_escape(start, start + 2, c2));
+                }
+                _ => {}
+            }
+            i += 2;
+            continue
+        }
+        if i + 2 < chars.len() && *chars.get(i + 1) == (pos + 1, '-') {
+            let (end, c2) = *chars.get(i + 2);
+            let end = offset + end + c2.len_utf8_bytes();
+            match (char_kind(c), char_kind(c2)) {
+                (Some(k1), Some(k2)) if k1 != k2 => {
+                    lints.push(Lint {
+                        kind: SuspiciousRange,
+                        span: (offset + pos, end),
+                        msg: format!("The range '{}-{}' includes more than \
+                                      digits or letters of one case.",
+                                     c, c2),
+                    });
+                }
+                _ => {}
+            }
+            i += 3;
+            continue
+        }
+        i += 1;
+    }
+}
+
+// Returns which kind of ASCII alphanumeric character `c` is: a digit, an
+// upper case letter or a lower case letter.
+fn char_kind(c: char) -> Option<uint> {
+    if c >= '0' && c <= '9' {
+        Some(0)
+    } else if c >= 'A' && c <= 'Z' {
+        Some(1)
+    } else if c >= 'a' && c <= 'z' {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn redundant_escape(start: uint, end: uint, c: char) -> Lint {
+    Lint {
+        kind: RedundantEscape,
+        span: (start, end),
+        msg: format!("'{}' doesn't need to be escaped here.", c),
+    }
+}
+
+// Returns true if the bracketed class in `text` can't match any character.
+fn class_is_empty(text: &str, flags: Flags) -> bool {
+    fn is_empty(ast: &Ast) -> bool {
+        match *ast {
+            Class(ref ranges, cflags) => {
+                let ranges = combine_ranges(ranges.clone());
+                if cflags & FLAG_NEGATED > 0 {
+                    negate_ranges(ranges.as_slice()).is_empty()
+                } else {
+                    ranges.is_empty()
+                }
+            }
+            Alt(ref x, ref y) => is_empty(&**x) && is_empty(&**y),
+            _ => false,
+        }
+    }
+    match parse::parse_with_flags(text, flags) {
+        Ok(ast) => is_empty(&*ast),
+        Err(_) => false,
+    }
+}
+
+// Finds the capture groups that can only match the empty string. (Other
+// empty groups are found in the tokens.)
+fn always_empty_captures(ast: &Ast, caps: &mut Vec<uint>) {
+    match *ast {
+        Capture(i, _, ref x) => {
+            if max_len(&**x) == Some(0) {
+                caps.push(i);
+            }
+            always_empty_captures(&**x, caps)
+        }
+        Lookahead(ref x, _) | Lookbehind(ref x, _) | Atomic(ref x)
+        | Rep(ref x, _, _) => always_empty_captures(&**x, caps),
+        Cat(ref xs) => {
+            for x in xs.iter() {
+                always_empty_captures(&**x, caps)
+            }
+        }
+        Alt(ref x, ref y) => {
+            always_empty_captures(&**x, caps);
+            always_empty_captures(&**y, caps)
+        }
+        _ => {}
+    }
+}
+
+// Adds whether each repetition in the expression is a nested unbounded
+// repetition, children before their parents. Returns true if the expression
+// has an unbounded repetition (outside of an atomic group).
+fn nested_reps(ast: &Ast, nested: &mut Vec<bool>) -> bool {
+    match *ast {
+        Rep(ref x, rep, _) => {
+            let inner = nested_reps(&**x, nested);
+            let unbounded = is_unbounded(rep);
+            nested.push(unbounded && inner);
+            inner || unbounded
+        }
+        Capture(_, _, ref x) | Lookahead(ref x, _) | Lookbehind(ref x, _) => {
+            nested_reps(&**x, nested)
+        }
+        // An atomic group never backtracks into its repetitions.
+        Atomic(ref x) => {
+            nested_reps(&**x, nested);
+            false
+        }
+        Cat(ref xs) => {
+            let mut any = false;
+            for x in xs.iter() {
+                any = nested_reps(&**x, nested) || any;
+            }
+            any
+        }
+        Alt(ref x, ref y) => {
+            let left = nested_reps(&**x, nested);
+            nested_reps(&**y, nested) || left
+        }
+        _ => false,
+    }
+}
+
+fn is_unbounded(rep: Repeater) -> bool {
+    match rep {
+        ZeroOne | Counted(_, Some(_)) => false,
+        ZeroMore | OneMore | Counted(_, None) => true,
+    }
+}
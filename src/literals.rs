@@ -0,0 +1,296 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This module contains the substring search routines used to skip ahead to
+// the literal prefix(es) of a regular expression. When a regex is known to
+// begin with one of a small set of literal strings, these are much faster
+// than feeding every character of the search text through the VM.
+
+use std::cmp;
+use std::mem;
+
+// Prefixes at least this long are searched for with Horspool's algorithm.
+// Shorter prefixes are found with `memchr`, which does better when the
+// needle is too short to skip over much of the haystack.
+static HORSPOOL_MIN_LEN: uint = 8;
+
+/// Returns the skip table used to search for `needle` with Horspool's
+/// algorithm. If `needle` is too short to benefit from Horspool's algorithm,
+/// then the table returned is empty.
+///
+/// For each byte `b`, the table contains the distance from the last
+/// occurrence of `b` in `needle` (excluding its final byte) to the end of
+/// `needle`. Bytes that don't occur get the length of `needle`.
+pub fn prefix_skip_table(needle: &[u8]) -> Vec<uint> {
+    let nlen = needle.len();
+    if nlen < HORSPOOL_MIN_LEN {
+        return vec!()
+    }
+    let mut skip = Vec::from_elem(256, nlen);
+    for (i, &b) in needle.slice_to(nlen - 1).iter().enumerate() {
+        *skip.get_mut(b as uint) = nlen - 1 - i;
+    }
+    skip
+}
+
+/// Returns the starting location of `needle` in `haystack`.
+/// If `needle` is not in `haystack`, then `None` is returned.
+///
+/// `skip` should be the table returned by `prefix_skip_table` for `needle`.
+/// If it's empty, candidate positions are found by using `memchr` to look
+/// for the first byte of `needle` and each candidate is then verified with
+/// a naive comparison. Otherwise, Horspool's algorithm is used.
+#[inline]
+pub fn find_prefix(needle: &[u8], skip: &[uint],
+                   haystack: &[u8]) -> Option<uint> {
+    let (hlen, nlen) = (haystack.len(), needle.len());
+    if nlen > hlen || nlen == 0 {
+        return None
+    }
+    if skip.len() > 0 {
+        return horspool(needle, skip, haystack)
+    }
+    let (first, rest) = (needle[0], needle.slice_from(1));
+    let mut hayi = 0u;
+    while hayi <= hlen - nlen {
+        match memchr(first, haystack.slice(hayi, hlen - nlen + 1)) {
+            None => break,
+            Some(i) => hayi += i,
+        }
+        if haystack.slice(hayi + 1, hayi + nlen) == rest {
+            return Some(hayi)
+        }
+        hayi += 1;
+    }
+    None
+}
+
+// Horspool's simplification of Boyer-Moore. The window is compared against
+// `needle` and then shifted by the skip distance of the byte at the end of
+// the window, which lets it jump over up to `needle.len()` bytes at a time.
+#[inline]
+fn horspool(needle: &[u8], skip: &[uint], haystack: &[u8]) -> Option<uint> {
+    let (hlen, nlen) = (haystack.len(), needle.len());
+    let (last, init) = (needle[nlen - 1], needle.slice_to(nlen - 1));
+    let mut hayi = 0u;
+    while hayi + nlen <= hlen {
+        let b = haystack[hayi + nlen - 1];
+        if b == last && haystack.slice(hayi, hayi + nlen - 1) == init {
+            return Some(hayi)
+        }
+        hayi += skip[b as uint];
+    }
+    None
+}
+
+/// Returns the index of the first occurrence of the byte `needle` in
+/// `haystack`. If `needle` is not in `haystack`, then `None` is returned.
+///
+/// The haystack is scanned a word (8 bytes) at a time, which is much faster
+/// than looking at each byte individually on long inputs.
+#[inline]
+pub fn memchr(needle: u8, haystack: &[u8]) -> Option<uint> {
+    static LO: u64 = 0x0101010101010101;
+    static HI: u64 = 0x8080808080808080;
+
+    let (len, ptr) = (haystack.len(), haystack.as_ptr());
+    let mut i = 0u;
+    // Look at one byte at a time until the rest of the haystack is aligned.
+    while i < len && (ptr as uint + i) % 8 != 0 {
+        if haystack[i] == needle {
+            return Some(i)
+        }
+        i += 1;
+    }
+    // XORing a word with a word full of `needle` bytes leaves a zero byte
+    // wherever `needle` was. The bit twiddling to detect a zero byte is
+    // described here:
+    // http://graphics.stanford.edu/~seander/bithacks.html#ZeroInWord
+    let repeated = (needle as u64) * LO;
+    while i + 8 <= len {
+        let word = unsafe { *(ptr.offset(i as int) as *u64) } ^ repeated;
+        if (word - LO) & !word & HI != 0 {
+            break
+        }
+        i += 8;
+    }
+    // Either we found a word containing `needle` or there are fewer than
+    // 8 bytes left. Either way, find the exact position one byte at a time.
+    while i < len {
+        if haystack[i] == needle {
+            return Some(i)
+        }
+        i += 1;
+    }
+    None
+}
+
+/// An Aho-Corasick automaton for finding the leftmost occurrence of any one
+/// of a set of literal strings.
+///
+/// This is used as a prefilter for expressions that must begin with one of
+/// several literals (e.g., `agggtaaa|tttaccct`). A single literal prefix is
+/// handled by `find_prefix` instead.
+///
+/// The automaton works on bytes, which is fine since both the literals and
+/// the search text are UTF-8. (A match can never start in the middle of a
+/// codepoint.)
+#[deriving(Clone)]
+pub struct AhoCorasick {
+    pats: Vec<~str>,
+    states: Vec<State>,
+    max_len: uint,
+}
+
+#[deriving(Clone)]
+struct State {
+    // Transitions out of this state, sorted by byte.
+    trans: Vec<(u8, uint)>,
+    // The state to fall back to when there is no transition for a byte.
+    fail: uint,
+    // The length of the longest literal that is a suffix of the bytes read
+    // to reach this state.
+    out: Option<uint>,
+}
+
+impl AhoCorasick {
+    /// Builds an automaton for the literals given. None of the literals may
+    /// be empty.
+    pub fn new(pats: Vec<~str>) -> AhoCorasick {
+        let mut ac = AhoCorasick {
+            pats: vec!(),
+            states: vec!(State::new()),
+            max_len: 0,
+        };
+        for pat in pats.move_iter() {
+            assert!(pat.len() > 0);
+            ac.add(pat);
+        }
+        ac.fill_failures();
+        ac
+    }
+
+    /// Returns the literals in this automaton.
+    pub fn patterns<'r>(&'r self) -> &'r [~str] {
+        self.pats.as_slice()
+    }
+
+    /// Returns the starting location of the leftmost occurrence of any of
+    /// the literals in `haystack`.
+    pub fn find(&self, haystack: &[u8]) -> Option<uint> {
+        let mut s = 0;
+        let mut best: Option<uint> = None;
+        for (i, &b) in haystack.iter().enumerate() {
+            s = self.next_state(s, b);
+            match self.states.get(s).out {
+                None => {}
+                Some(len) => {
+                    let start = i + 1 - len;
+                    best = Some(best.map_or(start, |b| cmp::min(b, start)));
+                }
+            }
+            // An occurrence found later can't start before `best` once we've
+            // read `max_len` bytes past it.
+            match best {
+                Some(start) if i + 2 >= start + self.max_len => return best,
+                _ => {}
+            }
+        }
+        best
+    }
+
+    /// Returns the approximate number of bytes that the automaton uses on
+    /// the heap.
+    pub fn heap_size(&self) -> uint {
+        let pats = self.pats.iter().fold(
+            self.pats.capacity() * mem::size_of::<~str>(),
+            |n, pat| n + pat.len());
+        let states = self.states.iter().fold(
+            self.states.capacity() * mem::size_of::<State>(),
+            |n, s| n + s.trans.capacity() * mem::size_of::<(u8, uint)>());
+        pats + states
+    }
+
+    fn add(&mut self, pat: ~str) {
+        let mut s = 0;
+        for &b in pat.as_bytes().iter() {
+            s = match self.states.get(s).goto(b) {
+                Some(next) => next,
+                None => {
+                    let next = self.states.len();
+                    self.states.push(State::new());
+                    self.states.get_mut(s).add_trans(b, next);
+                    next
+                }
+            };
+        }
+        self.states.get_mut(s).out = Some(pat.len());
+        self.max_len = cmp::max(self.max_len, pat.len());
+        self.pats.push(pat);
+    }
+
+    // Computes failure transitions with a breadth first traversal of the
+    // trie. The failure state of `s` is the longest proper suffix of `s` that
+    // is also in the trie, which is always closer to the root.
+    fn fill_failures(&mut self) {
+        let mut queue: Vec<uint> = vec!();
+        for &(_, s) in self.states.get(0).trans.iter() {
+            queue.push(s);
+        }
+        let mut qi = 0;
+        while qi < queue.len() {
+            let s = *queue.get(qi);
+            qi += 1;
+            let trans = self.states.get(s).trans.clone();
+            for &(b, next) in trans.iter() {
+                queue.push(next);
+                let mut f = self.states.get(s).fail;
+                while f != 0 && self.states.get(f).goto(b).is_none() {
+                    f = self.states.get(f).fail;
+                }
+                let fail = self.states.get(f).goto(b).unwrap_or(0);
+                let out = self.states.get(next).out
+                                         .or(self.states.get(fail).out);
+                let st = self.states.get_mut(next);
+                st.fail = fail;
+                st.out = out;
+            }
+        }
+    }
+
+    #[inline]
+    fn next_state(&self, mut s: uint, b: u8) -> uint {
+        loop {
+            match self.states.get(s).goto(b) {
+                Some(next) => return next,
+                None if s == 0 => return 0,
+                None => s = self.states.get(s).fail,
+            }
+        }
+    }
+}
+
+impl State {
+    fn new() -> State {
+        State { trans: vec!(), fail: 0, out: None }
+    }
+
+    #[inline]
+    fn goto(&self, b: u8) -> Option<uint> {
+        self.trans.as_slice().bsearch(|&(b2, _)| b2.cmp(&b))
+                             .map(|i| self.trans.get(i).val1())
+    }
+
+    fn add_trans(&mut self, b: u8, next: uint) {
+        let i = self.trans.iter().position(|&(b2, _)| b2 > b)
+                                 .unwrap_or(self.trans.len());
+        self.trans.insert(i, (b, next));
+    }
+}
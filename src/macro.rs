@@ -31,15 +31,19 @@ use syntax::ext::base::{
     NormalTT, BasicMacroExpander,
 };
 use syntax::parse;
+use syntax::parse::parser::Parser;
 use syntax::parse::token;
 use syntax::print::pprust;
 
 use regex::Regex;
+use regex::scan::Scanner;
 use regex::native::{
     OneChar, CharClass, Any, Save, Jump, Split,
     Match, EmptyBegin, EmptyEnd, EmptyWordBoundary,
-    Program, Dynamic, Native,
-    FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED,
+    OneBackref, LookStart, AtomicStart, LookEnd, CountedLoop, CountedNext,
+    Program, Dynamic, Native, Flags,
+    FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED, FLAG_UNICODE,
+    FLAG_FINAL_NEWLINE,
 };
 
 /// For the `regex!` syntax extension. Do not use.
@@ -47,7 +51,11 @@ use regex::native::{
 #[doc(hidden)]
 pub fn macro_registrar(register: |ast::Name, SyntaxExtension|) {
     let expander = ~BasicMacroExpander { expander: native, span: None };
-    register(token::intern("regex"), NormalTT(expander, None))
+    register(token::intern("regex"), NormalTT(expander, None));
+    let expander = ~BasicMacroExpander { expander: small, span: None };
+    register(token::intern("regex_small"), NormalTT(expander, None));
+    let expander = ~BasicMacroExpander { expander: lexer, span: None };
+    register(token::intern("lexer"), NormalTT(expander, None))
 }
 
 /// Generates specialized code for the Pike VM for a particular regular
@@ -74,16 +82,98 @@ pub fn macro_registrar(register: |ast::Name, SyntaxExtension|) {
 /// strategy is identical and vm.rs has comments and will be easier to follow.
 fn native(cx: &mut ExtCtxt, sp: codemap::Span, tts: &[ast::TokenTree])
          -> ~MacResult {
-    let regex = match parse(cx, tts) {
+    match compile(cx, sp, tts) {
+        None => DummyResult::any(sp),
+        // The generated code has no way to keep counters for counted loops,
+        // so programs with them are embedded as data instead.
+        Some(mut gen) => {
+            if gen.prog.num_counters() > 0 {
+                MacExpr::new(gen.data())
+            } else {
+                MacExpr::new(gen.code())
+            }
+        }
+    }
+}
+
+/// Embeds the compiled program for a particular regular expression as data,
+/// which is run by the same code as a regex compiled with `Regex::new`.
+///
+/// This is slower than the code generated by `regex!`, but it's much
+/// smaller. (The expression is still checked when the program using it is
+/// compiled.)
+fn small(cx: &mut ExtCtxt, sp: codemap::Span, tts: &[ast::TokenTree])
+        -> ~MacResult {
+    match compile(cx, sp, tts) {
+        None => DummyResult::any(sp),
+        Some(gen) => MacExpr::new(gen.data()),
+    }
+}
+
+/// Generates a `Lexer` for the `pattern => token` pairs given. The patterns
+/// are compiled to a single anchored program (as `Scanner::new` does), which
+/// is embedded as data.
+fn lexer(cx: &mut ExtCtxt, sp: codemap::Span, tts: &[ast::TokenTree])
+        -> ~MacResult {
+    let rules = match parse_rules(cx, tts) {
+        None => return DummyResult::any(sp),
+        Some(rules) => rules,
+    };
+    // Check each pattern on its own, so that errors point into it.
+    for &(ref pat, pat_sp, _) in rules.iter() {
+        match Regex::new(pat.as_slice()) {
+            Ok(_) => {}
+            Err(err) => {
+                cx.span_err(error_span(cx, pat_sp, 0, err.pos), err.to_str());
+                return DummyResult::any(sp)
+            }
+        }
+    }
+    let pats: Vec<&str> =
+        rules.iter().map(|&(ref pat, _, _)| pat.as_slice()).collect();
+    let scanner = Scanner::new(pats.as_slice()).unwrap();
+    let gen = NfaGen {
+        cx: &*cx, sp: sp, prog: scanner.prog,
+        names: ~[], original: ~"",
+    };
+    let prog = gen.prog_expr(&gen.prog);
+    let pats = gen.vec_expr(pats.as_slice(), |cx, &pat| quote_expr!(cx, ~$pat));
+    let toks = gen.vec_expr(rules.as_slice(), |cx, &(_, _, ref tok)| {
+        quote_expr!(cx, $tok)
+    });
+    MacExpr::new(quote_expr!(gen.cx, ::regex::scan::Lexer {
+        scanner: ::regex::scan::Scanner {
+            patterns: ~$pats,
+            prog: $prog,
+            scratch: ::regex::native::ScratchPool::new(),
+        },
+        tokens: ~$toks,
+    }))
+}
+
+/// Parses and compiles the expression given to a regex macro.
+/// If that fails, then an error is logged with cx.span_err and None is
+/// returned.
+fn compile<'a>(cx: &'a mut ExtCtxt, sp: codemap::Span,
+               tts: &[ast::TokenTree]) -> Option<NfaGen<'a>> {
+    let (regex, lit_sp, flags) = match parse(cx, tts) {
         Some(r) => r,
         // error is logged in 'parse' with cx.span_err
-        None => return DummyResult::any(sp),
+        None => return None,
     };
+    // Flags given separately apply to the whole expression, just like flags
+    // at the very beginning of it.
+    let (regex, skip) =
+        if flags.len() == 0 {
+            (regex, 0)
+        } else {
+            (format!("(?{}){}", flags, regex), flags.char_len() + 3)
+        };
     let re = match Regex::new(regex.to_owned()) {
         Ok(re) => re,
         Err(err) => {
-            cx.span_err(sp, err.to_str());
-            return DummyResult::any(sp)
+            cx.span_err(error_span(cx, lit_sp, skip, err.pos), err.to_str());
+            return None
         }
     };
     let prog = match re.p {
@@ -91,11 +181,10 @@ fn native(cx: &mut ExtCtxt, sp: codemap::Span, tts: &[ast::TokenTree])
         Native(_) => unreachable!(),
     };
 
-    let mut gen = NfaGen {
+    Some(NfaGen {
         cx: &*cx, sp: sp, prog: prog,
         names: re.names.clone(), original: re.original.clone(),
-    };
-    MacExpr::new(gen.code())
+    })
 }
 
 struct NfaGen<'a> {
@@ -130,6 +219,8 @@ impl<'a> NfaGen<'a> {
                                            |cx| quote_expr!(cx, None));
         let prefix_bytes = self.vec_expr(self.prog.prefix.as_slice().as_bytes(),
                                          |cx, b| quote_expr!(cx, $b));
+        let prefix_skip = self.vec_expr(self.prog.prefix_skip.as_slice(),
+                                        |cx, n| quote_expr!(cx, $n));
         let check_prefix = self.check_prefix();
         let step_insts = self.step_insts();
         let add_insts = self.add_insts();
@@ -166,6 +257,7 @@ fn exec<'t>(which: ::regex::native::MatchKind, input: &'t str,
         fn run(&mut self, start: uint, end: uint) -> Vec<Option<uint>> {
             let mut matched = false;
             let prefix_bytes: &[u8] = &$prefix_bytes;
+            let prefix_skip: &[uint] = &$prefix_skip;
             let mut clist = &mut Threads::new(self.which);
             let mut nlist = &mut Threads::new(self.which);
 
@@ -311,10 +403,165 @@ fn exec<'t>(which: ::regex::native::MatchKind, input: &'t str,
     original: ~$regex,
     names: ~$cap_names,
     p: ::regex::native::Native(exec),
+    scratch: ::regex::native::ScratchPool::new(),
+    reverse: ::regex::native::LazyProgram::new(),
+    full: ::regex::native::LazyProgram::new(),
 }
         })
     }
 
+    // Generates a `Regex` whose program is given as data rather than as
+    // specialized code. The program is run by the VM in the `regex` crate.
+    fn data(&self) -> @ast::Expr {
+        let regex = self.original.as_slice();
+        let cap_names = self.vec_expr(self.names,
+            |cx, name| match name {
+                &Some(ref name) => {
+                    let name = name.as_slice();
+                    quote_expr!(cx, Some(~$name))
+                }
+                &None => quote_expr!(cx, None),
+            }
+        );
+        let prog = self.prog_expr(&self.prog);
+        quote_expr!(self.cx, ::regex::Regex {
+            original: ~$regex,
+            names: ~$cap_names,
+            p: ::regex::native::Dynamic($prog),
+            scratch: ::regex::native::ScratchPool::new(),
+            reverse: ::regex::native::LazyProgram::new(),
+            full: ::regex::native::LazyProgram::new(),
+        })
+    }
+
+    // Converts a compiled program to an expression that builds it. (The
+    // literal prefix automaton is built again from its literals.)
+    fn prog_expr(&self, prog: &Program) -> @ast::Expr {
+        let insts = self.vec_expr(prog.insts.as_slice(), |cx, inst| {
+            // Flags are given as integers.
+            let f = |flags: Flags| {
+                let flags = flags as uint;
+                quote_expr!(cx, $flags as ::regex::native::Flags)
+            };
+            match *inst {
+                Match => quote_expr!(cx, ::regex::native::Match),
+                OneChar(c, flags) => {
+                    let flags = f(flags);
+                    quote_expr!(cx, ::regex::native::OneChar($c, $flags))
+                }
+                CharClass(ref ranges, flags) => {
+                    let flags = f(flags);
+                    let ranges = self.vec_expr(ranges.as_slice(),
+                        |cx, &(s, e)| quote_expr!(cx, ($s, $e)));
+                    quote_expr!(cx, ::regex::native::CharClass(
+                        ::std::vec::Vec::from_slice(&$ranges), $flags))
+                }
+                Any(flags) => {
+                    let flags = f(flags);
+                    quote_expr!(cx, ::regex::native::Any($flags))
+                }
+                EmptyBegin(flags) => {
+                    let flags = f(flags);
+                    quote_expr!(cx, ::regex::native::EmptyBegin($flags))
+                }
+                EmptyEnd(flags) => {
+                    let flags = f(flags);
+                    quote_expr!(cx, ::regex::native::EmptyEnd($flags))
+                }
+                EmptyWordBoundary(flags) => {
+                    let flags = f(flags);
+                    quote_expr!(cx,
+                        ::regex::native::EmptyWordBoundary($flags))
+                }
+                Save(slot) => quote_expr!(cx, ::regex::native::Save($slot)),
+                OneBackref(cap, flags) => {
+                    let flags = f(flags);
+                    quote_expr!(cx, ::regex::native::OneBackref($cap, $flags))
+                }
+                LookStart(flags, behind, next) => {
+                    let flags = f(flags);
+                    quote_expr!(cx,
+                        ::regex::native::LookStart($flags, $behind, $next))
+                }
+                AtomicStart(next) =>
+                    quote_expr!(cx, ::regex::native::AtomicStart($next)),
+                LookEnd => quote_expr!(cx, ::regex::native::LookEnd),
+                Jump(to) => quote_expr!(cx, ::regex::native::Jump($to)),
+                Split(x, y) =>
+                    quote_expr!(cx, ::regex::native::Split($x, $y)),
+                CountedLoop(k, min, max, greedy, exit) => {
+                    let max = match max {
+                        None => quote_expr!(cx, None),
+                        Some(max) => quote_expr!(cx, Some($max)),
+                    };
+                    quote_expr!(cx, ::regex::native::CountedLoop(
+                        $k, $min, $max, $greedy, $exit))
+                }
+                CountedNext(k, limit, start) => quote_expr!(cx,
+                    ::regex::native::CountedNext($k, $limit, $start)),
+            }
+        });
+        let prefix = prog.prefix.as_slice();
+        let prefix_skip = self.vec_expr(prog.prefix_skip.as_slice(),
+                                        |cx, &n| quote_expr!(cx, $n));
+        let prefix_set = match prog.prefix_set {
+            None => quote_expr!(self.cx, None),
+            Some(ref ac) => {
+                let lits = self.vec_expr(ac.patterns(), |cx, lit| {
+                    let lit = lit.as_slice();
+                    quote_expr!(cx, ~$lit)
+                });
+                quote_expr!(self.cx, Some(::regex::native::AhoCorasick::new(
+                    ::std::vec::Vec::from_slice(&$lits))))
+            }
+        };
+        let anchored_end = prog.anchored_end;
+        let anchored_start = prog.anchored_start;
+        let suffix = prog.suffix.as_slice();
+        let reverse = match prog.reverse {
+            None => quote_expr!(self.cx, None),
+            Some(ref rev) => {
+                let rev = self.prog_expr(&**rev);
+                quote_expr!(self.cx, Some(~$rev))
+            }
+        };
+        let inner = prog.inner.as_slice();
+        let inner_skip = self.vec_expr(prog.inner_skip.as_slice(),
+                                       |cx, &n| quote_expr!(cx, $n));
+        let inner_offset = match prog.inner_offset {
+            None => quote_expr!(self.cx, None),
+            Some(n) => quote_expr!(self.cx, Some($n)),
+        };
+        let min_len = prog.min_len;
+        let max_len = match prog.max_len {
+            None => quote_expr!(self.cx, None),
+            Some(n) => quote_expr!(self.cx, Some($n)),
+        };
+        let backtrack = prog.backtrack;
+        let backtrack_limit = prog.backtrack_limit;
+        let alternates = prog.alternates;
+        let flags = prog.flags as uint;
+        quote_expr!(self.cx, ::regex::native::Program {
+            insts: ::std::vec::Vec::from_slice(&$insts),
+            prefix: ~$prefix,
+            prefix_skip: ::std::vec::Vec::from_slice(&$prefix_skip),
+            prefix_set: $prefix_set,
+            anchored_end: $anchored_end,
+            anchored_start: $anchored_start,
+            suffix: ~$suffix,
+            reverse: $reverse,
+            inner: ~$inner,
+            inner_skip: ::std::vec::Vec::from_slice(&$inner_skip),
+            inner_offset: $inner_offset,
+            min_len: $min_len,
+            max_len: $max_len,
+            backtrack: $backtrack,
+            backtrack_limit: $backtrack_limit,
+            alternates: $alternates,
+            flags: $flags as ::regex::native::Flags,
+        })
+    }
+
     // Generates code for the `add` method, which is responsible for adding
     // zero-width states to the next queue of states to visit.
     fn add_insts(&self) -> @ast::Expr {
@@ -340,7 +587,10 @@ fn exec<'t>(which: ::regex::native::MatchKind, input: &'t str,
                 EmptyEnd(flags) => {
                     let nl = '\n';
                     let cond =
-                        if flags & FLAG_MULTI > 0 {
+                        if flags & FLAG_FINAL_NEWLINE > 0 {
+                            quote_expr!(self.cx,
+                                self.chars.is_end_or_final_newline())
+                        } else if flags & FLAG_MULTI > 0 {
                             quote_expr!(self.cx,
                                 self.chars.is_end()
                                 || self.chars.cur == Some($nl)
@@ -354,11 +604,18 @@ fn exec<'t>(which: ::regex::native::MatchKind, input: &'t str,
                     })
                 }
                 EmptyWordBoundary(flags) => {
+                    let boundary =
+                        if flags & FLAG_UNICODE > 0 {
+                            quote_expr!(self.cx, self.chars.is_word_boundary())
+                        } else {
+                            quote_expr!(self.cx,
+                                        self.chars.is_ascii_word_boundary())
+                        };
                     let cond =
                         if flags & FLAG_NEGATED > 0 {
-                            quote_expr!(self.cx, !self.chars.is_word_boundary())
+                            quote_expr!(self.cx, !$boundary)
                         } else {
-                            quote_expr!(self.cx, self.chars.is_word_boundary())
+                            boundary
                         };
                     quote_expr!(self.cx, {
                         nlist.add_empty($pc);
@@ -546,7 +803,7 @@ fn exec<'t>(which: ::regex::native::MatchKind, input: &'t str,
             quote_expr!(self.cx,
                 if clist.size == 0 {
                     let haystack = self.input.as_bytes().slice_from(self.ic);
-                    match find_prefix(prefix_bytes, haystack) {
+                    match find_prefix(prefix_bytes, prefix_skip, haystack) {
                         None => break,
                         Some(i) => {
                             self.ic += i;
@@ -651,21 +908,95 @@ impl ToTokens for bool {
     }
 }
 
-/// Looks for a single string literal and returns it.
+/// Looks for a string literal, optionally followed by a string literal of
+/// flags, and returns both along with the span of the first. (The flags are
+/// empty if there aren't any.)
 /// Otherwise, logs an error with cx.span_err and returns None.
-fn parse(cx: &mut ExtCtxt, tts: &[ast::TokenTree]) -> Option<~str> {
+fn parse(cx: &mut ExtCtxt, tts: &[ast::TokenTree])
+        -> Option<(~str, codemap::Span, ~str)> {
     let mut parser = parse::new_parser_from_tts(cx.parse_sess(), cx.cfg(),
                                                 Vec::from_slice(tts));
-    let entry = cx.expand_expr(parser.parse_expr());
-    let regex = match entry.node {
+    let (regex, sp) = match parse_str(cx, &mut parser) {
+        None => return None,
+        Some(regex) => regex,
+    };
+    let mut flags = ~"";
+    if parser.eat(&token::COMMA) {
+        let span = parser.span;
+        flags = match parse_str(cx, &mut parser) {
+            None => return None,
+            Some((flags, _)) => flags,
+        };
+        for c in flags.chars() {
+            if !"imsUxu".contains_char(c) {
+                cx.span_err(span, format!(
+                    "unknown flag `{}` (expected one of i, m, s, U, x or u)",
+                    c));
+                return None
+            }
+        }
+    }
+    if !parser.eat(&token::EOF) {
+        cx.span_err(parser.span,
+                    "expected a string literal and optional flags only");
+        return None;
+    }
+    Some((regex, sp, flags))
+}
+
+/// Looks for `pattern => token` pairs separated by commas (with an optional
+/// trailing comma), where each pattern is a string literal and each token is
+/// any expression. The span of each pattern is returned along with it.
+/// Otherwise, logs an error with cx.span_err and returns None.
+fn parse_rules(cx: &mut ExtCtxt, tts: &[ast::TokenTree])
+              -> Option<Vec<(~str, codemap::Span, @ast::Expr)>> {
+    let mut parser = parse::new_parser_from_tts(cx.parse_sess(), cx.cfg(),
+                                                Vec::from_slice(tts));
+    let mut rules = vec!();
+    while !parser.eat(&token::EOF) {
+        let (pat, sp) = match parse_str(cx, &mut parser) {
+            None => return None,
+            Some(pat) => pat,
+        };
+        if !parser.eat(&token::FAT_ARROW) {
+            cx.span_err(parser.span, "expected `=>` after a pattern");
+            return None
+        }
+        let tok = parser.parse_expr();
+        rules.push((pat, sp, tok));
+        if !parser.eat(&token::COMMA) && parser.token != token::EOF {
+            cx.span_err(parser.span, "expected `,` after a token");
+            return None
+        }
+    }
+    if rules.len() == 0 {
+        cx.span_err(parser.span, "expected at least one `pattern => token`");
+        return None
+    }
+    Some(rules)
+}
+
+// Parses the next expression and expands it, which must result in a string
+// literal (possibly in parentheses). So macros like `concat!` can be used.
+// The string and the span of the literal are returned.
+fn parse_str(cx: &mut ExtCtxt, parser: &mut Parser)
+            -> Option<(~str, codemap::Span)> {
+    let mut entry = cx.expand_expr(parser.parse_expr());
+    loop {
+        match entry.node {
+            ast::ExprParen(e) => entry = e,
+            _ => break,
+        }
+    }
+    match entry.node {
         ast::ExprLit(lit) => {
             match lit.node {
-                ast::LitStr(ref s, _) => s.to_str(),
+                ast::LitStr(ref s, _) => Some((s.to_str(), entry.span)),
                 _ => {
                     cx.span_err(entry.span, format!(
                         "expected string literal but got `{}`",
                         pprust::lit_to_str(lit)));
-                    return None
+                    None
                 }
             }
         }
@@ -673,12 +1004,50 @@ fn parse(cx: &mut ExtCtxt, tts: &[ast::TokenTree]) -> Option<~str> {
             cx.span_err(entry.span, format!(
                 "expected string literal but got `{}`",
                 pprust::expr_to_str(entry)));
-            return None
+            None
         }
+    }
+}
+
+/// Returns the span of the character at `pos` (a character index into the
+/// expression) in the string literal at `sp`. The first `skip` characters of
+/// the expression don't come from the literal. If the character can't be
+/// found in the source (e.g., because the literal has escapes), then `sp` is
+/// returned.
+fn error_span(cx: &ExtCtxt, sp: codemap::Span, skip: uint, pos: uint)
+             -> codemap::Span {
+    let snippet = match cx.codemap().span_to_snippet(sp) {
+        None => return sp,
+        Some(snippet) => snippet,
     };
-    if !parser.eat(&token::EOF) {
-        cx.span_err(parser.span, "only one string literal allowed");
-        return None;
+    // Only raw strings and strings without escapes have exactly the same
+    // characters in the source as in the literal.
+    let start =
+        if snippet.starts_with("r\"") || snippet.starts_with("r#") {
+            // A raw string's prefix is `r`, then any number of `#`, then `"`.
+            let hashes = snippet.slice_from(1).chars()
+                                .take_while(|&c| c == '#').count();
+            if !snippet.slice_from(1 + hashes).starts_with("\"") {
+                return sp
+            }
+            hashes + 2
+        } else if snippet.starts_with("\"") && !snippet.contains_char('\\') {
+            1
+        } else {
+            return sp
+        };
+    if pos < skip {
+        return sp
+    }
+    let found = snippet.slice_from(start).char_indices().nth(pos - skip);
+    let (offset, c) = match found {
+        None => return sp,
+        Some(found) => found,
+    };
+    let lo = sp.lo + codemap::BytePos((start + offset) as u32);
+    codemap::Span {
+        lo: lo,
+        hi: lo + codemap::BytePos(c.len_utf8_bytes() as u32),
+        expn_info: sp.expn_info,
     }
-    Some(regex)
 }
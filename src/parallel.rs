@@ -0,0 +1,216 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Searching large texts in parallel.
+//!
+//! `find_all` splits the text into parts, searches each part in a task of
+//! its own, and puts the matches back together. The result is exactly what
+//! `Regex::find_iter` returns for the whole text.
+//!
+//! Each task searches from the start of its part, and keeps the matches that
+//! start in it (even if they end in a later part). But a match found
+//! earlier may end past the start of a part, so the matches a task found
+//! aren't necessarily the ones a search of the whole text would find. When
+//! putting them together, the text is searched again from where the matches
+//! so far end, until the search reaches a point where one of the tasks was
+//! searching too. Since a search only depends on where it starts, what that
+//! task found from there on is used as is. Usually this means one extra
+//! search at each part boundary.
+//!
+//! # Example
+//!
+//! ```rust
+//! extern crate regex;
+//! extern crate sync;
+//! use regex::Regex;
+//! use regex::parallel;
+//! use sync::Arc;
+//!
+//! # fn main() {
+//! let re = Arc::new(Regex::new(r"[ag]{2}[ct]").unwrap());
+//! let text = Arc::new(~"agtcaggtcagtaggac");
+//! let found = parallel::find_all(&re, &text, 4);
+//! let all: Vec<(uint, uint)> = re.find_iter(text.as_slice()).collect();
+//! assert_eq!(found, all);
+//! # }
+//! ```
+
+use collections::HashMap;
+use std::cmp;
+use std::comm::channel;
+use std::task;
+use sync::Arc;
+
+use re::Regex;
+use vm::Location;
+
+/// Returns the start and end byte indices of every successive
+/// non-overlapping match of `re` in `text`, like `Regex::find_iter`. The text
+/// is split into (at most) `parts` parts, which are searched in separate
+/// tasks.
+///
+/// Splitting the text only pays off if it's large (megabytes, say).
+pub fn find_all(re: &Arc<Regex>, text: &Arc<~str>, parts: uint)
+               -> Vec<(uint, uint)> {
+    let starts = split_points(text.as_slice(), parts);
+    let (tx, rx) = channel();
+    for (i, &start) in starts.iter().enumerate() {
+        // Empty matches at the very end belong to the last part.
+        let stop =
+            if i + 1 < starts.len() {
+                *starts.get(i + 1)
+            } else {
+                text.len() + 1
+            };
+        let (re, text, tx) = (re.clone(), text.clone(), tx.clone());
+        task::spawn(proc() {
+            tx.send((i, search_part(&*re, text.as_slice(), start, stop)));
+        });
+    }
+    let mut parts = Vec::from_fn(starts.len(), |_| None);
+    for _ in range(0, starts.len()) {
+        let (i, part) = rx.recv();
+        *parts.get_mut(i) = Some(part);
+    }
+    let parts: Vec<Part> = parts.move_iter().map(|p| p.unwrap()).collect();
+    join(&**re, text.as_slice(), starts.as_slice(), parts.as_slice())
+}
+
+// The matches found by searching one part of the text, and whether there
+// were no more matches in the text after them.
+type Part = (Vec<(uint, uint)>, bool);
+
+// Returns where each part of the text starts. Each one is on a character
+// boundary, and there's always at least one part.
+fn split_points(text: &str, parts: uint) -> Vec<uint> {
+    let parts = cmp::max(parts, 1);
+    let mut starts = vec!(0u);
+    for i in range(1, parts) {
+        let mut s = text.len() * i / parts;
+        while !text.is_char_boundary(s) {
+            s += 1;
+        }
+        if s > *starts.last().unwrap() && s < text.len() {
+            starts.push(s);
+        }
+    }
+    starts
+}
+
+// Searches `text` from `start` for the matches that start before `stop`.
+fn search_part(re: &Regex, text: &str, start: uint, stop: uint) -> Part {
+    let mut matches = Matches::new(re, text, start, None);
+    let mut found = vec!();
+    loop {
+        match matches.next() {
+            None => return (found, true),
+            Some((s, _)) if s >= stop => return (found, false),
+            Some(m) => found.push(m),
+        }
+    }
+}
+
+// Puts the matches found in each part together. (See the module
+// documentation.)
+fn join(re: &Regex, text: &str, starts: &[uint], parts: &[Part])
+       -> Vec<(uint, uint)> {
+    // Each point where a task was searching from, and the part and number
+    // of matches in it that preceded it. A point is where the search starts
+    // and whether a match just ended there (which matters to an empty match
+    // found there).
+    let mut points = HashMap::new();
+    for (i, &(ref found, _)) in parts.iter().enumerate() {
+        points.insert((starts[i], false), (i, 0u));
+        for (k, &(_, e)) in found.iter().enumerate() {
+            points.insert((e, true), (i, k + 1));
+        }
+    }
+    let mut matches = Matches::new(re, text, 0, None);
+    let mut all = vec!();
+    loop {
+        let point = (matches.last_end,
+                     matches.last_match == Some(matches.last_end));
+        match points.find(&point) {
+            None => {}
+            Some(&(i, k)) => {
+                let (ref found, done) = parts[i];
+                let rest = found.slice_from(k);
+                all.push_all(rest);
+                match rest.last() {
+                    None => {}
+                    Some(&(_, e)) => {
+                        matches.last_end = e;
+                        matches.last_match = Some(e);
+                    }
+                }
+                if done {
+                    break
+                }
+            }
+        }
+        // The next match starts after the part that was just used (or the
+        // search isn't at a point where any task was).
+        match matches.next() {
+            None => break,
+            Some(m) => all.push(m),
+        }
+    }
+    all
+}
+
+// Matches yields successive matches like `FindMatches`, except it can start
+// from any point.
+struct Matches<'r, 't> {
+    re: &'r Regex,
+    text: &'t str,
+    last_end: uint,
+    last_match: Option<uint>,
+}
+
+impl<'r, 't> Matches<'r, 't> {
+    fn new(re: &'r Regex, text: &'t str, last_end: uint,
+           last_match: Option<uint>) -> Matches<'r, 't> {
+        Matches {
+            re: re,
+            text: text,
+            last_end: last_end,
+            last_match: last_match,
+        }
+    }
+}
+
+impl<'r, 't> Iterator<(uint, uint)> for Matches<'r, 't> {
+    fn next(&mut self) -> Option<(uint, uint)> {
+        loop {
+            if self.last_end > self.text.len() {
+                return None
+            }
+            let locs = self.re.exec(Location, self.text,
+                                    self.last_end, self.text.len());
+            let (s, e) = match (*locs.get(0), *locs.get(1)) {
+                (Some(s), Some(e)) => (s, e),
+                _ => return None,
+            };
+            // Don't accept empty matches immediately following a match.
+            if s == e && Some(self.last_end) == self.last_match {
+                self.last_end =
+                    if self.last_end >= self.text.len() {
+                        self.last_end + 1
+                    } else {
+                        self.text.char_range_at(self.last_end).next
+                    };
+                continue
+            }
+            self.last_end = e;
+            self.last_match = Some(e);
+            return Some((s, e))
+        }
+    }
+}
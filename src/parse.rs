@@ -11,17 +11,25 @@
 use std::char;
 use std::cmp;
 use std::fmt;
-use std::iter;
+use std::mem;
 use std::num;
 use std::str;
-
-/// Static data containing Unicode ranges for general categories and scripts.
-use self::unicode::{UNICODE_CLASSES, PERLD, PERLS, PERLW};
+use std::uint;
+use compile::max_len;
+
+/// Static data containing Unicode ranges for general categories, scripts and
+/// blocks.
+use self::unicode::{
+    UNICODE_CLASSES, SCRIPT_EXTENSIONS, UNICODE_BLOCKS, PERLD, PERLS, PERLW,
+};
 #[allow(visible_private_types)]
 pub mod unicode;
 
-/// The maximum number of repetitions allowed with the `{n,m}` syntax.
-static MAX_REPEAT: uint = 1000;
+/// The maximum number of repetitions allowed with the `{n,m}` syntax. An
+/// expression in nested counted repetitions is limited to this many
+/// repetitions in all (e.g., `(a{1000}){1000}` repeats `a` a million times),
+/// since a search may have to track a thread for each of them.
+static MAX_REPEAT: uint = 100000;
 
 /// Error corresponds to something that can go wrong while parsing
 /// a regular expression.
@@ -31,10 +39,133 @@ static MAX_REPEAT: uint = 1000;
 pub struct Error {
     /// The *approximate* character index of where the error occurred.
     pub pos: uint,
+    /// The start and end byte indices of the part of the expression that
+    /// caused the error. When a construct can't be pinned down, this is
+    /// the character at `pos` (or an empty span at the end of the
+    /// expression).
+    pub span: (uint, uint),
+    /// The kind of error, which can be used to tell errors apart without
+    /// looking at the message.
+    pub kind: ErrorKind,
     /// A message describing the error.
     pub msg: ~str,
 }
 
+/// The kinds of errors that can happen while parsing a regular expression.
+/// Some of them carry the part of the expression that caused the error.
+#[deriving(Clone, Eq, Show)]
+pub enum ErrorKind {
+    /// A group was opened but never closed.
+    UnclosedParen,
+    /// A `)` doesn't close any group.
+    UnopenedParen,
+    /// An alternate is empty, like `a||b`.
+    EmptyGroup,
+    /// The expression ended where more was expected.
+    UnexpectedEof,
+    /// The first character was expected, but the second was found instead.
+    UnexpectedChar(char, char),
+    /// A repetition operator doesn't follow anything that can be repeated.
+    RepeatNoExpression,
+    /// Two repetition operators follow each other, like `a**`.
+    DoubleRepeat,
+    /// An empty width assertion is repeated, like `^*`.
+    RepeatEmptyWidth,
+    /// A counted repetition has no closing `}`.
+    UnclosedRepetition,
+    /// A counted repetition gives neither a minimum nor a maximum, like
+    /// `{,}`.
+    MissingMinRepetition,
+    /// A counted repetition (the first number) is larger than the limit
+    /// (the second number). The count of a repetition nested in others is
+    /// multiplied by theirs.
+    RepetitionTooLarge(uint, uint),
+    /// The maximum of a counted repetition (the second number) is smaller
+    /// than its minimum (the first number).
+    InvalidRepetitionRange(uint, uint),
+    /// The text given isn't a valid unsigned integer.
+    InvalidInteger(~str),
+    /// The end of a character class range comes before its start.
+    InvalidRange(char, char),
+    /// An escape sequence that can't be used inside a character class,
+    /// like `\b`.
+    InvalidClassEscape,
+    /// An escape sequence isn't recognized, like `\q`.
+    InvalidEscape(char),
+    /// A control escape isn't followed by a letter, like `\c1`.
+    InvalidControl(char),
+    /// The `{` of an escape sequence (like `\x{...}`) isn't closed.
+    UnclosedBrace,
+    /// The text given isn't a valid octal number.
+    InvalidOctal(~str),
+    /// The text given isn't a valid hexadecimal number.
+    InvalidHex(~str),
+    /// The number given isn't a Unicode scalar value.
+    InvalidCodepoint(u32),
+    /// `\p` or `\P` isn't followed by a class name.
+    MissingUnicodeClassName,
+    /// There is no Unicode class with the name given.
+    UnknownUnicodeClass(~str),
+    /// A capture group name has no closing `>`.
+    UnclosedCaptureName,
+    /// A capture group name is empty.
+    EmptyCaptureName,
+    /// A capture group in abstract syntax (that wasn't parsed) doesn't have
+    /// the index that parsing would give it, which is one more than the
+    /// number of groups that open before it.
+    InvalidCaptureIndex(uint),
+    /// A capture group name has characters other than underscores, letters
+    /// and digits.
+    InvalidCaptureName(~str),
+    /// Two capture groups have the same name.
+    DuplicateCaptureName(~str),
+    /// A backreference by name has no closing `)`.
+    UnclosedBackrefName,
+    /// A backreference refers to a group number that hasn't been opened.
+    UndefinedGroup(uint),
+    /// A backreference refers to a group name that hasn't been opened.
+    UndefinedGroupName(~str),
+    /// A lookbehind can match an unbounded amount of text.
+    UnboundedLookbehind,
+    /// A backreference, lookaround or atomic group is used without enabling
+    /// backtracking.
+    BacktrackRequired,
+    /// A flag group has two `-` characters, like `(?-i-s)`.
+    DoubleFlagNegation,
+    /// A `-` in a flag group isn't followed by a flag, like `(?i-)`.
+    EmptyFlagNegation,
+    /// A flag isn't recognized.
+    UnrecognizedFlag(char),
+}
+
+impl Error {
+    /// Returns the expression given (which should be the one that caused
+    /// the error) followed by a line that marks `span` with a caret, e.g.,
+    ///
+    /// ```text
+    /// a{100001}
+    ///  ^~~~~~~~
+    /// ```
+    ///
+    /// The caret line is only aligned if every character in the expression
+    /// takes up one column.
+    pub fn caret(&self, re: &str) -> ~str {
+        let (start, end) = self.span;
+        let start = cmp::min(start, re.len());
+        let end = cmp::max(start, cmp::min(end, re.len()));
+        let mut buf = StrBuf::from_str(re);
+        buf.push_char('\n');
+        for _ in range(0, re.slice_to(start).char_len()) {
+            buf.push_char(' ');
+        }
+        buf.push_char('^');
+        for _ in range(1, re.slice(start, end).char_len()) {
+            buf.push_char('~');
+        }
+        buf.into_owned()
+    }
+}
+
 impl fmt::Show for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f.buf, "Regex syntax error near position {}: {}",
@@ -50,37 +181,408 @@ impl fmt::Show for Error {
 ///
 /// Note that this representation prevents one from reproducing the regex as
 /// it was typed. (But it could be used to reproduce an equivalent regex.)
-#[deriving(Show, Clone)]
+///
+/// Flags that can't affect an expression's meaning are not always dropped,
+/// so two equivalent expressions may have flags that differ.
+#[deriving(Show)]
 pub enum Ast {
+    /// Matches the empty string.
     Nothing,
+    /// A single character. The only flag that matters is `FLAG_NOCASE`.
     Literal(char, Flags),
+    /// Any character. `\n` is only included when `FLAG_DOTNL` is set.
     Dot(Flags),
+    /// A set of character ranges, sorted and non-overlapping. The flags
+    /// that matter are `FLAG_NOCASE` and `FLAG_NEGATED`.
     Class(Vec<(char, char)>, Flags),
+    /// The beginning of the text (or of a line, with `FLAG_MULTI`).
     Begin(Flags),
+    /// The end of the text (or of a line, with `FLAG_MULTI`, or before a
+    /// newline at the end of the text, with `FLAG_FINAL_NEWLINE`).
     End(Flags),
+    /// A word boundary (or not a word boundary, with `FLAG_NEGATED`). Word
+    /// characters are those of the Unicode `\w` with `FLAG_UNICODE`, and
+    /// only `[0-9A-Za-z_]` without it.
     WordBoundary(Flags),
+    /// A capture group with its index and an optional name.
     Capture(uint, Option<~str>, ~Ast),
+    /// A backreference to a capture group by its index. It matches the same
+    /// text that the group last matched. The only flag that matters is
+    /// `FLAG_NOCASE`.
+    Backref(uint, Flags),
+    /// A lookahead assertion, which matches if the expression matches at
+    /// the current position (or doesn't, with `FLAG_NEGATED`) and consumes
+    /// no characters.
+    Lookahead(~Ast, Flags),
+    /// A lookbehind assertion, which matches if the expression matches text
+    /// that ends at the current position (or doesn't, with `FLAG_NEGATED`).
+    /// The expression can only match a bounded amount of text.
+    Lookbehind(~Ast, Flags),
+    /// An atomic group. Once the expression has matched, the match is never
+    /// changed to make the rest of the expression match.
+    Atomic(~Ast),
+    /// A concatenation of expressions.
     // Represent concatenation as a flat vector to avoid blowing the
     // stack in the compiler.
     Cat(Vec<~Ast>),
+    /// An alternation of two expressions. The first one is preferred.
     Alt(~Ast, ~Ast),
+    /// A repetition of an expression.
     Rep(~Ast, Repeater, Greed),
 }
 
+impl Ast {
+    /// Returns a regular expression that parses to this abstract syntax.
+    ///
+    /// The expression returned usually won't look like the one that was
+    /// originally parsed. For example, flags are given explicitly with a
+    /// group around every expression they apply to. But parsing it is
+    /// guaranteed to produce the same abstract syntax for any `Ast` that
+    /// was produced by `parse`, and equivalent abstract syntax for any other
+    /// `Ast` that passes `check`. (An expression with backreferences must be
+    /// parsed with `FLAG_BACKTRACK` set.)
+    pub fn to_pattern(&self) -> ~str {
+        let mut buf = StrBuf::new();
+        self.write_pattern(&mut buf);
+        buf.into_owned()
+    }
+
+    fn write_pattern(&self, buf: &mut StrBuf) {
+        match *self {
+            Nothing => {}
+            Literal(_, flags) | Dot(flags) | Class(_, flags)
+            | Begin(flags) | End(flags) if group_flags(flags).len() > 0 => {
+                buf.push_str(format!("(?{}:", group_flags(flags)));
+                self.write_atom(buf);
+                buf.push_char(')');
+            }
+            Literal(_, _) | Dot(_) | Class(_, _) | Begin(_) | End(_)
+            | WordBoundary(_) => self.write_atom(buf),
+            // The group keeps a digit that follows from being read as part
+            // of the group index.
+            Backref(cap, flags) =>
+                buf.push_str(format!("(?{}:\\\\{})", group_flags(flags), cap)),
+            Lookahead(ref x, flags) | Lookbehind(ref x, flags) => {
+                buf.push_str("(?");
+                match *self {
+                    Lookbehind(_, _) => buf.push_char('<'),
+                    _ => {}
+                }
+                if flags & FLAG_NEGATED > 0 {
+                    buf.push_char('!');
+                } else {
+                    buf.push_char('=');
+                }
+                x.write_pattern(buf);
+                buf.push_char(')');
+            }
+            Atomic(ref x) => {
+                buf.push_str("(?>");
+                x.write_pattern(buf);
+                buf.push_char(')');
+            }
+            Capture(_, ref name, ref x) => {
+                match *name {
+                    None => buf.push_char('('),
+                    Some(ref name) => buf.push_str(format!("(?P<{}>", name)),
+                }
+                x.write_pattern(buf);
+                buf.push_char(')');
+            }
+            Cat(ref xs) => {
+                for x in xs.iter() {
+                    match **x {
+                        Alt(_, _) => x.write_group(buf),
+                        _ => x.write_pattern(buf),
+                    }
+                }
+            }
+            // An empty alternate has to be written as a group, since `a|`
+            // doesn't parse.
+            Alt(ref x, ref y) => {
+                match **x {
+                    Alt(_, _) => x.write_group(buf),
+                    _ if x.is_written_empty() => x.write_group(buf),
+                    _ => x.write_pattern(buf),
+                }
+                buf.push_char('|');
+                if y.is_written_empty() {
+                    y.write_group(buf);
+                } else {
+                    y.write_pattern(buf);
+                }
+            }
+            Rep(ref x, rep, greed) => {
+                match **x {
+                    Nothing | Cat(_) | Alt(_, _) | Rep(_, _, _) =>
+                        x.write_group(buf),
+                    _ => x.write_pattern(buf),
+                }
+                match rep {
+                    ZeroOne => buf.push_char('?'),
+                    ZeroMore => buf.push_char('*'),
+                    OneMore => buf.push_char('+'),
+                    Counted(min, None) =>
+                        buf.push_str(format!("\\{{},\\}", min)),
+                    Counted(min, Some(max)) if min == max =>
+                        buf.push_str(format!("\\{{}\\}", min)),
+                    Counted(min, Some(max)) =>
+                        buf.push_str(format!("\\{{},{}\\}", min, max)),
+                }
+                if !greed.is_greedy() {
+                    buf.push_char('?');
+                }
+            }
+        }
+    }
+
+    // Returns true if the expression is written as the empty string.
+    fn is_written_empty(&self) -> bool {
+        match *self {
+            Nothing => true,
+            Cat(ref xs) => xs.iter().all(|x| x.is_written_empty()),
+            _ => false,
+        }
+    }
+
+    // Writes the expression inside a non-capturing group.
+    fn write_group(&self, buf: &mut StrBuf) {
+        buf.push_str("(?:");
+        self.write_pattern(buf);
+        buf.push_char(')');
+    }
+
+    // Writes a single character expression, ignoring any flags that can
+    // only be set with a group.
+    fn write_atom(&self, buf: &mut StrBuf) {
+        match *self {
+            Literal(c, flags) => {
+                if is_punct(c) {
+                    buf.push_char('\\');
+                    buf.push_char(c);
+                } else if flags == FLAG_EMPTY && c.is_control() {
+                    push_hex(buf, c);
+                } else {
+                    buf.push_char(c);
+                }
+            }
+            Dot(_) => buf.push_char('.'),
+            Class(ref ranges, flags) => {
+                buf.push_char('[');
+                if flags & FLAG_NEGATED > 0 {
+                    buf.push_char('^');
+                }
+                // A ']' can only be written as the first character of a
+                // class, so pull it out of whichever range contains it.
+                // (The parser will merge the pieces back together.)
+                let mut pieces = vec!();
+                for &(s, e) in ranges.iter() {
+                    if s <= ']' && ']' <= e {
+                        buf.push_char(']');
+                        if s < ']' { pieces.push((s, '\\')) }
+                        if e > ']' { pieces.push(('^', e)) }
+                    } else {
+                        pieces.push((s, e));
+                    }
+                }
+                // An empty class can't be written directly, but subtracting
+                // a set from itself parses to one.
+                if ranges.len() == 0 {
+                    buf.push_str("a--a");
+                }
+                for &(s, e) in pieces.iter() {
+                    push_class_char(buf, s);
+                    if s != e {
+                        buf.push_char('-');
+                        push_class_char(buf, e);
+                    }
+                }
+                buf.push_char(']');
+            }
+            Begin(_) => buf.push_char('^'),
+            End(flags) if flags & FLAG_FINAL_NEWLINE > 0 => {
+                buf.push_str("\\Z")
+            }
+            End(_) => buf.push_char('$'),
+            WordBoundary(flags) => {
+                let b = if flags & FLAG_NEGATED > 0 { "\\B" } else { "\\b" };
+                if flags & FLAG_UNICODE > 0 {
+                    buf.push_str(b)
+                } else {
+                    buf.push_str(format!("(?-u:{})", b))
+                }
+            }
+            _ => fail!("BUG: {} is not a single character expression", self),
+        }
+    }
+}
+
+// A derived `clone` would recurse, which overflows the stack on deeply
+// nested expressions.
+impl Clone for Ast {
+    fn clone(&self) -> Ast {
+        *fold(self, |ast, mut xs: Vec<~Ast>| {
+            ~match *ast {
+                Nothing => Nothing,
+                Literal(c, flags) => Literal(c, flags),
+                Dot(flags) => Dot(flags),
+                Class(ref ranges, flags) => Class(ranges.clone(), flags),
+                Begin(flags) => Begin(flags),
+                End(flags) => End(flags),
+                WordBoundary(flags) => WordBoundary(flags),
+                Capture(cap, ref name, _) =>
+                    Capture(cap, name.clone(), xs.pop().unwrap()),
+                Backref(cap, flags) => Backref(cap, flags),
+                Lookahead(_, flags) => Lookahead(xs.pop().unwrap(), flags),
+                Lookbehind(_, flags) => Lookbehind(xs.pop().unwrap(), flags),
+                Atomic(_) => Atomic(xs.pop().unwrap()),
+                Cat(_) => Cat(xs),
+                Alt(_, _) => {
+                    let y = xs.pop().unwrap();
+                    Alt(xs.pop().unwrap(), y)
+                }
+                Rep(_, rep, greed) => Rep(xs.pop().unwrap(), rep, greed),
+            }
+        })
+    }
+}
+
+// Returns the sub-expressions of an expression, in order.
+fn children<'a>(ast: &'a Ast) -> Vec<&'a Ast> {
+    match *ast {
+        Capture(_, _, ref x) | Lookahead(ref x, _) | Lookbehind(ref x, _)
+        | Atomic(ref x) | Rep(ref x, _, _) => vec!(&**x),
+        Cat(ref xs) => xs.iter().map(|x| &**x).collect(),
+        Alt(ref x, ref y) => vec!(&**x, &**y),
+        _ => vec!(),
+    }
+}
+
+// Takes the sub-expressions out of an expression (in order), leaving
+// `Nothing` in their place.
+fn take_children(ast: &mut Ast) -> Vec<~Ast> {
+    match *ast {
+        Capture(_, _, ref mut x) | Lookahead(ref mut x, _)
+        | Lookbehind(ref mut x, _) | Atomic(ref mut x) | Rep(ref mut x, _, _) =>
+            vec!(mem::replace(x, ~Nothing)),
+        Cat(ref mut xs) => mem::replace(xs, vec!()),
+        Alt(ref mut x, ref mut y) =>
+            vec!(mem::replace(x, ~Nothing), mem::replace(y, ~Nothing)),
+        _ => vec!(),
+    }
+}
+
+// Puts back the sub-expressions taken out by `take_children`.
+fn put_children(ast: &mut Ast, mut xs: Vec<~Ast>) {
+    match *ast {
+        Capture(_, _, ref mut x) | Lookahead(ref mut x, _)
+        | Lookbehind(ref mut x, _) | Atomic(ref mut x) | Rep(ref mut x, _, _) =>
+            *x = xs.pop().unwrap(),
+        Cat(ref mut ys) => *ys = xs,
+        Alt(ref mut x, ref mut y) => {
+            *y = xs.pop().unwrap();
+            *x = xs.pop().unwrap();
+        }
+        _ => {}
+    }
+}
+
+// Pops the last `n` values off of `vals`, in the order they were pushed.
+fn pop_values<T>(vals: &mut Vec<T>, n: uint) -> Vec<T> {
+    let mut xs = Vec::with_capacity(n);
+    for _ in range(0, n) {
+        xs.push(vals.pop().unwrap());
+    }
+    xs.reverse();
+    xs
+}
+
+/// Computes a value for the expression given from the values of its
+/// sub-expressions, which are computed the same way. `f` is given an
+/// expression and the values of its sub-expressions (in order).
+///
+/// Expressions can be nested arbitrarily deep, so this keeps a stack of the
+/// expressions left to visit instead of recursing. Every analysis of an
+/// expression that runs before matching should use it (or `map`).
+pub fn fold<'a, T>(ast: &'a Ast, f: |&'a Ast, Vec<T>| -> T) -> T {
+    // Each expression is visited twice: once to push its sub-expressions,
+    // and again (with the number of them) once their values are computed.
+    let mut stack = vec!((ast, None));
+    let mut vals = vec!();
+    loop {
+        match stack.pop() {
+            None => return vals.pop().unwrap(),
+            Some((ast, None)) => {
+                let xs = children(ast);
+                stack.push((ast, Some(xs.len())));
+                for &x in xs.iter().rev() {
+                    stack.push((x, None));
+                }
+            }
+            Some((ast, Some(n))) => {
+                let xs = pop_values(&mut vals, n);
+                vals.push(f(ast, xs));
+            }
+        }
+    }
+}
+
+/// Rebuilds the expression given from the bottom up. Each sub-expression is
+/// rebuilt first, and then the expression with the rebuilt sub-expressions
+/// in it is given to `f`, which returns the expression that replaces it.
+///
+/// Like `fold`, this doesn't recurse.
+pub fn map(ast: ~Ast, f: |~Ast| -> ~Ast) -> ~Ast {
+    // Each expression is visited twice: once to take its sub-expressions out
+    // of it, and again (with the number of them) to put them back once
+    // they've been rebuilt.
+    let mut stack = vec!((ast, None));
+    let mut done = vec!();
+    loop {
+        match stack.pop() {
+            None => return done.pop().unwrap(),
+            Some((mut ast, None)) => {
+                let xs = take_children(&mut *ast);
+                stack.push((ast, Some(xs.len())));
+                for x in xs.move_iter().rev() {
+                    stack.push((x, None));
+                }
+            }
+            Some((mut ast, Some(n))) => {
+                let xs = pop_values(&mut done, n);
+                put_children(&mut *ast, xs);
+                done.push(f(ast));
+            }
+        }
+    }
+}
+
+/// The kinds of repetition.
 #[deriving(Show, Eq, Clone)]
 pub enum Repeater {
+    /// `?`
     ZeroOne,
+    /// `*`
     ZeroMore,
+    /// `+`
     OneMore,
+    /// `{n}`, `{n,}` or `{n,m}`, with the minimum and (if there is one)
+    /// maximum number of repetitions.
+    Counted(uint, Option<uint>),
 }
 
+/// Whether a repetition matches as much as possible or as little as
+/// possible.
 #[deriving(Show, Clone)]
 pub enum Greed {
+    /// Prefers to match more, e.g., `a*`.
     Greedy,
+    /// Prefers to match less, e.g., `a*?`.
     Ungreedy,
 }
 
 impl Greed {
+    /// Returns true if and only if this is `Greedy`.
     pub fn is_greedy(&self) -> bool {
         match *self {
             Greedy => true,
@@ -97,6 +599,47 @@ impl Greed {
     }
 }
 
+/// A token of an expression, as returned by `tokenize`.
+#[deriving(Show, Eq, Clone)]
+pub struct Token {
+    /// What kind of construct the token is.
+    pub kind: TokenKind,
+    /// The start and end byte indices of the token in the expression.
+    pub span: (uint, uint),
+}
+
+/// The kinds of tokens in an expression.
+#[deriving(Show, Eq, Clone)]
+pub enum TokenKind {
+    /// A literal character, like `a`.
+    LiteralToken,
+    /// An escape sequence, like `\n`, `\*`, `\d`, `\pL` or `\Q...\E`.
+    EscapeToken,
+    /// A bracketed character class, like `[a-z]`, or `.`.
+    ClassToken,
+    /// A repetition operator along with its `?` or `+` suffix, like `*` or
+    /// `{2,5}?`.
+    RepeatToken,
+    /// An empty width assertion, like `^`, `$`, `\A` or `\b`.
+    AnchorToken,
+    /// A backreference, like `\1` or `(?P=name)`.
+    BackrefToken,
+    /// The opening of a group, like `(`, `(?P<name>`, `(?i:` or `(?=`, with
+    /// the flags that are set inside of it.
+    GroupOpenToken(Flags),
+    /// The `)` that closes a group.
+    GroupCloseToken,
+    /// A group that only sets flags, like `(?i)`, with the flags that are set
+    /// after it.
+    FlagsToken(Flags),
+    /// A `|` between alternates.
+    AltToken,
+    /// A comment, like `(?#...)` (or `# ...` with the `x` flag).
+    CommentToken,
+    /// A construct that couldn't be parsed.
+    ErrorToken,
+}
+
 /// BuildAst is a regrettable type that represents intermediate state for
 /// constructing an abstract syntax tree. Its central purpose is to facilitate
 /// parsing groups and alternations while also maintaining a stack of flag
@@ -105,27 +648,30 @@ impl Greed {
 enum BuildAst {
     Ast(~Ast),
     Paren(Flags, uint, ~str), // '('
+    LookParen(Flags, Flags, bool), // '(?=', '(?!', '(?<=' or '(?<!'
+    AtomicParen(Flags), // '(?>'
     Bar, // '|'
 }
 
 impl BuildAst {
     fn paren(&self) -> bool {
         match *self {
-            Paren(_, _, _) => true,
+            Paren(_, _, _) | LookParen(_, _, _) | AtomicParen(_) => true,
             _ => false,
         }
     }
 
     fn flags(&self) -> Flags {
         match *self {
-            Paren(flags, _, _) => flags,
+            Paren(flags, _, _) | LookParen(flags, _, _)
+            | AtomicParen(flags) => flags,
             _ => fail!("Cannot get flags from {}", self),
         }
     }
 
     fn capture(&self) -> Option<uint> {
         match *self {
-            Paren(_, 0, _) => None,
+            Paren(_, 0, _) | LookParen(_, _, _) | AtomicParen(_) => None,
             Paren(_, c, _) => Some(c),
             _ => fail!("Cannot get capture group from {}", self),
         }
@@ -133,7 +679,7 @@ impl BuildAst {
 
     fn capture_name(&self) -> Option<~str> {
         match *self {
-            Paren(_, 0, _) => None,
+            Paren(_, 0, _) | LookParen(_, _, _) | AtomicParen(_) => None,
             Paren(_, _, ref name) => {
                 if name.len() == 0 {
                     None
@@ -145,6 +691,22 @@ impl BuildAst {
         }
     }
 
+    // Returns the flags of the lookaround this opens, if any, and whether
+    // it's a lookbehind.
+    fn lookaround(&self) -> Option<(Flags, bool)> {
+        match *self {
+            LookParen(_, flags, behind) => Some((flags, behind)),
+            _ => None,
+        }
+    }
+
+    fn atomic(&self) -> bool {
+        match *self {
+            AtomicParen(_) => true,
+            _ => false,
+        }
+    }
+
     fn bar(&self) -> bool {
         match *self {
             Bar => true,
@@ -162,14 +724,35 @@ impl BuildAst {
 
 /// Flags represents all options that can be twiddled by a user in an
 /// expression.
-pub type Flags = u8;
-
-pub static FLAG_EMPTY:      u8 = 0;
-pub static FLAG_NOCASE:     u8 = 1 << 0; // i
-pub static FLAG_MULTI:      u8 = 1 << 1; // m
-pub static FLAG_DOTNL:      u8 = 1 << 2; // s
-pub static FLAG_SWAP_GREED: u8 = 1 << 3; // U
-pub static FLAG_NEGATED:    u8 = 1 << 4; // char class or not word boundary
+pub type Flags = u16;
+
+/// No flags are set.
+pub static FLAG_EMPTY:      u16 = 0;
+/// Case insensitive matching. (`i`)
+pub static FLAG_NOCASE:     u16 = 1 << 0; // i
+/// `^` and `$` match at the beginning and end of lines. (`m`)
+pub static FLAG_MULTI:      u16 = 1 << 1; // m
+/// `.` matches `\n`. (`s`)
+pub static FLAG_DOTNL:      u16 = 1 << 2; // s
+/// The meaning of `x*` and `x*?` are swapped. (`U`)
+pub static FLAG_SWAP_GREED: u16 = 1 << 3; // U
+/// A negated character class or not a word boundary.
+pub static FLAG_NEGATED:    u16 = 1 << 4; // char class or not word boundary
+/// Whitespace is ignored and `#` starts a comment. (`x`) This only affects
+/// parsing, so it never appears in an `Ast`.
+pub static FLAG_IGNORE_SPACE: u16 = 1 << 5; // x
+/// `\d`, `\s`, `\w` and `\b` use their Unicode definitions instead of
+/// ASCII. (`u`) This is set by default. It only appears in an `Ast` on a
+/// `WordBoundary`, since the others are parsed to different classes.
+pub static FLAG_UNICODE:    u16 = 1 << 6; // u
+/// `End` also matches just before a newline that ends the text. This can
+/// only be set with `\Z`.
+pub static FLAG_FINAL_NEWLINE: u16 = 1 << 7; // \Z
+/// Backreferences like `\1` and `(?P=name)`, lookaround assertions and
+/// atomic groups are allowed, which means the expression may have to be run
+/// by a backtracking engine. This only affects parsing and can only be set
+/// with `RegexBuilder::backtrack`.
+pub static FLAG_BACKTRACK: u16 = 1 << 8;
 
 struct Parser<'a> {
     // The input, parsed only as a sequence of UTF8 code points.
@@ -184,76 +767,328 @@ struct Parser<'a> {
     // Incremented each time an opening left paren is seen (assuming it is
     // opening a capture group).
     caps: uint,
-    // A set of all capture group names used only to detect duplicates.
-    names: Vec<~str>,
+    // The names of all capture groups seen so far along with their indices.
+    // Used to detect duplicates and to resolve backreferences by name.
+    names: Vec<(~str, uint)>,
+    // Where each capture group starts and ends (as character indices). The
+    // end is zero until the group is closed.
+    spans: Vec<(uint, uint)>,
+    // The errors found so far, when parsing goes on after an error (see
+    // parse_with_recovery).
+    errors: Option<Vec<Error>>,
+    // The tokens found so far, when they're wanted (see tokenize).
+    tokens: Option<Vec<Token>>,
 }
 
+/// Parses a regular expression into its abstract syntax. If the expression
+/// isn't valid, then an error is returned.
 pub fn parse(s: &str) -> Result<~Ast, Error> {
-    Parser {
-        chars: s.chars().collect(),
-        chari: 0,
-        stack: vec!(),
-        flags: FLAG_EMPTY,
-        caps: 0,
-        names: vec!(),
-    }.parse()
+    parse_with_flags(s, FLAG_UNICODE)
 }
 
-impl<'a> Parser<'a> {
-    fn parse(&mut self) -> Result<~Ast, Error> {
-        loop {
-            let c = self.cur();
-            match c {
-                '?' | '*' | '+' => try!(self.push_repeater(c)),
-                '\\' => {
-                    let ast = try!(self.parse_escape());
-                    self.push(ast)
+/// Parses a regular expression like `parse`, but with the given flags set
+/// at the start of the expression instead of only `FLAG_UNICODE`.
+pub fn parse_with_flags(s: &str, flags: Flags) -> Result<~Ast, Error> {
+    Parser::new(s, flags).parse()
+}
+
+/// Parses a regular expression like `parse_with_flags`, and also returns
+/// where each capture group is in the expression. The span of group `i` is
+/// at index `i - 1`, and it goes from the group's opening parenthesis to
+/// just past its closing one (as byte indices).
+///
+/// # Example
+///
+/// ```rust
+/// use regex::syntax::{parse_with_spans, FLAG_UNICODE};
+///
+/// let (_, spans) = parse_with_spans(r"a(b(?P<c>c))", FLAG_UNICODE).unwrap();
+/// assert_eq!(spans, vec![(1, 12), (3, 11)]);
+/// ```
+pub fn parse_with_spans(s: &str, flags: Flags)
+                       -> Result<(~Ast, Vec<(uint, uint)>), Error> {
+    let mut p = Parser::new(s, flags);
+    let ast = try!(p.parse());
+    let spans = p.spans.iter().map(|&(start, end)| {
+        (p.byte_offset(start), p.byte_offset(end))
+    }).collect();
+    Ok((ast, spans))
+}
+
+/// Parses a regular expression like `parse_with_flags`, but doesn't stop at
+/// the first error. Each construct that causes an error is skipped, and
+/// parsing goes on after it, so every error in the expression is returned
+/// (in the order they were found).
+///
+/// An error can hide others in the construct it's in, and sometimes skipping
+/// a construct leads to an error that wouldn't otherwise happen (e.g., a `)`
+/// after a group that couldn't be opened). So only the first error is
+/// guaranteed to be the same as the one `parse_with_flags` returns.
+///
+/// # Example
+///
+/// ```rust
+/// use regex::syntax::{parse_with_recovery, FLAG_UNICODE};
+///
+/// let errs = parse_with_recovery(r"a**|[z-a]|\q", FLAG_UNICODE).unwrap_err();
+/// let spans: Vec<(uint, uint)> = errs.iter().map(|e| e.span).collect();
+/// assert_eq!(spans, vec![(1, 2), (7, 8), (10, 12)]);
+/// ```
+pub fn parse_with_recovery(s: &str, flags: Flags)
+                          -> Result<~Ast, Vec<Error>> {
+    let mut p = Parser::new(s, flags);
+    p.errors = Some(vec!());
+    let result = p.parse();
+    let mut errors = p.errors.take().unwrap();
+    match result {
+        Ok(ast) => if errors.is_empty() { return Ok(ast) },
+        Err(err) => errors.push(err),
+    }
+    Err(errors)
+}
+
+/// Splits an expression into tokens (with the given flags set at the start
+/// of the expression), as it's parsed. This is meant for syntax highlighting,
+/// so the tokens follow the same rules as parsing (e.g., flags like `x` are
+/// taken into account).
+///
+/// Every construct that can't be parsed is an `ErrorToken`, and tokenizing
+/// goes on after it (like `parse_with_recovery`). Whitespace that's ignored
+/// with the `x` flag isn't part of any token.
+///
+/// # Example
+///
+/// ```rust
+/// use regex::syntax::{tokenize, TokenKind, FLAG_UNICODE};
+/// use regex::syntax::{GroupOpenToken, LiteralToken, GroupCloseToken};
+/// use regex::syntax::{RepeatToken, EscapeToken};
+///
+/// let kinds: Vec<(TokenKind, (uint, uint))> =
+///     tokenize(r"(a)+\d", FLAG_UNICODE).iter().map(|t| {
+///         (t.kind.clone(), t.span)
+///     }).collect();
+/// assert_eq!(kinds, vec![
+///     (GroupOpenToken(FLAG_UNICODE), (0, 1)), (LiteralToken, (1, 2)),
+///     (GroupCloseToken, (2, 3)), (RepeatToken, (3, 4)),
+///     (EscapeToken, (4, 6)),
+/// ]);
+/// ```
+pub fn tokenize(s: &str, flags: Flags) -> Vec<Token> {
+    if s.len() == 0 {
+        return vec!()
+    }
+    let mut p = Parser::new(s, flags);
+    p.errors = Some(vec!());
+    p.tokens = Some(vec!());
+    // Errors are already marked by tokens, except for unclosed groups (and
+    // the like) at the end, which don't spoil the tokens.
+    let _ = p.parse();
+    p.tokens.take().unwrap()
+}
+
+/// Checks abstract syntax that wasn't parsed from an expression for the
+/// errors that parsing would have caught:
+///
+/// * capture groups that aren't numbered in the order they open, starting
+///   at `1`,
+/// * invalid or duplicate capture names,
+/// * backreferences to groups that haven't been opened,
+/// * lookbehinds that can match an unbounded amount of text,
+/// * repetitions of empty width assertions,
+/// * character class ranges whose end comes before their start, and
+/// * repetitions that are too large (counting the repetitions they're
+///   nested in) or have a maximum smaller than their minimum.
+///
+/// There's no expression for an error to point to, so its position and
+/// span are always zero.
+pub fn check(ast: &Ast) -> Result<(), Error> {
+    let (mut caps, mut names) = (0, vec!());
+    // The expressions left to check, with the next one last. They're checked
+    // in the order that they appear, so that capture groups are counted in
+    // the order that they open.
+    let mut stack = vec!(ast);
+    loop {
+        match stack.pop() {
+            None => return Ok(()),
+            Some(ast) => {
+                try!(check_one(ast, &mut caps, &mut names));
+                for &x in children(ast).iter().rev() {
+                    stack.push(x);
                 }
-                '{' => try!(self.parse_counted()),
-                '[' => match self.try_parse_ascii() {
-                    None => try!(self.parse_class()),
-                    Some(class) => self.push(class),
-                },
-                '(' => {
-                    if self.peek_is(1, '?') {
-                        try!(self.expect('?'))
-                        try!(self.parse_group_opts())
-                    } else {
-                        self.caps += 1;
-                        self.stack.push(Paren(self.flags, self.caps, ~""))
+            }
+        }
+    }
+}
+
+// Checks an expression (but not its sub-expressions), given the number of
+// capture groups that open before it and their names.
+fn check_one(ast: &Ast, caps: &mut uint, names: &mut Vec<~str>)
+            -> Result<(), Error> {
+    match *ast {
+        Capture(cap, ref name, _) => {
+            *caps += 1;
+            if cap != *caps {
+                return check_err(InvalidCaptureIndex(cap),
+                    format!("Capture group {} should be numbered {}, as \
+                             {} groups open before it.",
+                            cap, *caps, *caps - 1))
+            }
+            match *name {
+                None => {}
+                Some(ref name) => {
+                    if name.len() == 0 {
+                        return check_err(EmptyCaptureName,
+                            "Capture names must have at least 1 character.")
+                    }
+                    if !name.chars().all(is_valid_cap) {
+                        return check_err(InvalidCaptureName(name.clone()),
+                            "Capture names can only have underscores, \
+                             letters and digits.")
+                    }
+                    if names.contains(name) {
+                        return check_err(DuplicateCaptureName(name.clone()),
+                            format!("Duplicate capture group name '{}'.",
+                                    name))
                     }
+                    names.push(name.clone());
                 }
-                ')' => {
-                    let catfrom = try!(
-                        self.pos_last(false, |x| x.paren() || x.bar()));
-                    try!(self.concat(catfrom));
-
-                    let altfrom = try!(self.pos_last(false, |x| x.paren()));
-                    // Before we smush the alternates together and pop off the
-                    // left paren, let's grab the old flags and see if we
-                    // need a capture.
-                    let (cap, cap_name, oldflags) = {
-                        let paren = self.stack.get(altfrom-1);
-                        (paren.capture(), paren.capture_name(), paren.flags())
-                    };
-                    try!(self.alternate(altfrom));
-                    self.flags = oldflags;
-
-                    // If this was a capture, pop what we just pushed in
-                    // alternate and make it a capture.
-                    if cap.is_some() {
-                        let ast = try!(self.pop_ast());
-                        self.push(~Capture(cap.unwrap(), cap_name, ast));
+            }
+            Ok(())
+        }
+        Backref(cap, _) if cap == 0 || cap > *caps => {
+            check_err(UndefinedGroup(cap),
+                      format!("Backreference to undefined group {}.", cap))
+        }
+        Class(ref ranges, _) => {
+            for &(start, end) in ranges.iter() {
+                if start > end {
+                    return check_err(InvalidRange(start, end), format!(
+                        "Invalid character class range '{}-{}'",
+                        start, end))
+                }
+            }
+            Ok(())
+        }
+        Rep(ref x, rep, _) => {
+            match **x {
+                Begin(_) | End(_) | WordBoundary(_) =>
+                    return check_err(RepeatEmptyWidth,
+                        "Repeat arguments cannot be empty width \
+                         assertions."),
+                _ => {}
+            }
+            match rep {
+                Counted(min, max) => {
+                    let m = max.unwrap_or(min);
+                    let n = repeat_count(ast);
+                    if n > MAX_REPEAT {
+                        return check_err(RepetitionTooLarge(n, MAX_REPEAT),
+                            format!("{} exceeds maximum allowed repetitions \
+                                     ({})", n, MAX_REPEAT))
+                    }
+                    if m < min {
+                        return check_err(InvalidRepetitionRange(min, m),
+                            format!("Max repetitions ({}) cannot be smaller \
+                                     than min repetitions ({}).", m, min))
                     }
                 }
-                '|' => {
-                    let catfrom = try!(
-                        self.pos_last(true, |x| x.paren() || x.bar()));
-                    try!(self.concat(catfrom));
+                _ => {}
+            }
+            Ok(())
+        }
+        Lookbehind(ref x, _) => {
+            if max_len(&**x).is_none() {
+                return check_err(UnboundedLookbehind,
+                    "Lookbehind assertions must match a bounded amount of \
+                     text.")
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+// Returns the most times that any part of an expression is repeated by the
+// counted repetitions in it (which is `1` if there are none). A counted
+// repetition with no maximum counts as its minimum, since a thread only
+// needs to count that far.
+fn repeat_count(ast: &Ast) -> uint {
+    fold(ast, |ast, counts: Vec<uint>| {
+        let n = counts.iter().map(|&n| n).max().unwrap_or(1);
+        match *ast {
+            Rep(_, Counted(min, max), _) => {
+                let m = cmp::max(cmp::max(min, max.unwrap_or(min)), 1);
+                if n > uint::MAX / m { uint::MAX } else { n * m }
+            }
+            _ => n,
+        }
+    })
+}
+
+fn check_err<T>(kind: ErrorKind, msg: &str) -> Result<T, Error> {
+    Err(Error { pos: 0, span: (0, 0), kind: kind, msg: msg.to_owned() })
+}
+
+/// Sorts and merges the ranges of every character class in the abstract
+/// syntax, as parsing does. (None of the ranges can be reversed.)
+pub fn combine_classes(ast: ~Ast) -> ~Ast {
+    map(ast, |ast| {
+        match ast {
+            ~Class(ranges, flags) => ~Class(combine_ranges(ranges), flags),
+            ast => ast,
+        }
+    })
+}
+
+/// Returns true if the abstract syntax has constructs that only the
+/// backtracking engine can run (which only parse with `FLAG_BACKTRACK`).
+pub fn needs_backtrack(ast: &Ast) -> bool {
+    fold(ast, |ast, xs: Vec<bool>| {
+        match *ast {
+            Backref(_, _) | Lookahead(_, _) | Lookbehind(_, _)
+            | Atomic(_) => true,
+            _ => xs.iter().any(|&x| x),
+        }
+    })
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &str, flags: Flags) -> Parser {
+        Parser {
+            chars: s.chars().collect(),
+            chari: 0,
+            stack: vec!(),
+            flags: flags,
+            caps: 0,
+            names: vec!(),
+            spans: vec!(),
+            errors: None,
+            tokens: None,
+        }
+    }
 
-                    self.stack.push(Bar);
+    fn parse(&mut self) -> Result<~Ast, Error> {
+        loop {
+            let c = self.cur();
+            let (start, depth) = (self.chari, self.stack.len());
+            let kind = match self.parse_item(c) {
+                Ok(()) if self.tokens.is_none() => None,
+                Ok(()) => self.token_kind(c, start, depth),
+                Err(err) => {
+                    try!(self.recover(c, err));
+                    Some(ErrorToken)
+                }
+            };
+            match (self.tokens.is_some(), kind) {
+                (true, Some(kind)) => {
+                    let span = (self.byte_offset(start),
+                                self.byte_offset(self.chari + 1));
+                    self.tokens.get_mut_ref().push(Token {
+                        kind: kind,
+                        span: span,
+                    });
                 }
-                _ => try!(self.push_literal(c)),
+                _ => {}
             }
             if !self.next_char() {
                 break
@@ -263,7 +1098,7 @@ impl<'a> Parser<'a> {
         // Try to improve error handling. At this point, there should be
         // no remaining open parens.
         if self.stack.iter().any(|x| x.paren()) {
-            return self.err("Unclosed parenthesis.")
+            return self.err(UnclosedParen, "Unclosed parenthesis.")
         }
         let catfrom = try!(self.pos_last(true, |x| x.bar()));
         try!(self.concat(catfrom));
@@ -273,19 +1108,188 @@ impl<'a> Parser<'a> {
         self.pop_ast()
     }
 
+    // Parses the construct that starts at the current character `c`.
+    fn parse_item(&mut self, c: char) -> Result<(), Error> {
+        match c {
+            '?' | '*' | '+' => try!(self.push_repeater(c)),
+            '\\' if self.peek_is(1, 'Q') => self.parse_quoted(),
+            '\\' => {
+                let ast = try!(self.parse_escape());
+                self.push(ast)
+            }
+            '{' => try!(self.parse_counted()),
+            // A bare '[:alpha:]' is accepted for compatibility, but
+            // other engines only recognize it inside brackets.
+            '[' => match self.try_parse_ascii() {
+                None => try!(self.parse_class()),
+                Some(class) => self.push(class),
+            },
+            '(' => {
+                let (open, caps) = (self.chari, self.caps);
+                if self.peek_is(1, '?') {
+                    try!(self.expect('?'))
+                    try!(self.parse_group_opts())
+                } else {
+                    self.caps += 1;
+                    self.stack.push(Paren(self.flags, self.caps, ~""))
+                }
+                if self.caps > caps {
+                    self.spans.push((open, 0));
+                }
+            }
+            ')' => {
+                let catfrom = try!(
+                    self.pos_last(false, |x| x.paren() || x.bar()));
+                // An empty group, like '()', matches the empty string.
+                // (An empty alternate is still an error.)
+                if catfrom == self.stack.len()
+                   && self.stack.get(catfrom - 1).paren() {
+                    self.push(~Nothing);
+                }
+                try!(self.concat(catfrom));
+
+                let altfrom = try!(self.pos_last(false, |x| x.paren()));
+                // Before we smush the alternates together and pop off the
+                // left paren, let's grab the old flags and see if we
+                // need a capture.
+                let (cap, cap_name, oldflags, look, atomic) = {
+                    let paren = self.stack.get(altfrom-1);
+                    (paren.capture(), paren.capture_name(), paren.flags(),
+                     paren.lookaround(), paren.atomic())
+                };
+                try!(self.alternate(altfrom));
+                self.flags = oldflags;
+
+                // If this was a capture, pop what we just pushed in
+                // alternate and make it a capture.
+                if cap.is_some() {
+                    let ast = try!(self.pop_ast());
+                    self.push(~Capture(cap.unwrap(), cap_name, ast));
+                    let i = cap.unwrap() - 1;
+                    let (start, _) = *self.spans.get(i);
+                    *self.spans.get_mut(i) = (start, self.chari + 1);
+                }
+                // Likewise for a lookaround.
+                match look {
+                    None => {}
+                    Some((flags, false)) => {
+                        let ast = try!(self.pop_ast());
+                        self.push(~Lookahead(ast, flags));
+                    }
+                    Some((flags, true)) => {
+                        let ast = try!(self.pop_ast());
+                        if max_len(&*ast).is_none() {
+                            return self.err(UnboundedLookbehind,
+                                "Lookbehind assertions must match a \
+                                 bounded amount of text.")
+                        }
+                        self.push(~Lookbehind(ast, flags));
+                    }
+                }
+                if atomic {
+                    let ast = try!(self.pop_ast());
+                    self.push(~Atomic(ast));
+                }
+            }
+            '|' => {
+                let catfrom = try!(
+                    self.pos_last(true, |x| x.paren() || x.bar()));
+                try!(self.concat(catfrom));
+
+                self.stack.push(Bar);
+            }
+            c if self.ignore_space() && c.is_whitespace() => {}
+            '#' if self.ignore_space() => self.skip_comment(),
+            _ => try!(self.push_literal(c)),
+        }
+        Ok(())
+    }
+
+    // Returns the kind of token that parse_item just parsed, starting at
+    // `start` (with character `c`) when the stack had `depth` elements.
+    // Whitespace that is ignored isn't a token.
+    fn token_kind(&self, c: char, start: uint, depth: uint)
+                 -> Option<TokenKind> {
+        // What was pushed on to the stack, if anything.
+        let pushed =
+            if self.stack.len() > depth { self.stack.last() } else { None };
+        let (opened, backref) = match pushed {
+            Some(&Ast(ref ast)) => {
+                match **ast {
+                    Backref(_, _) => (false, true),
+                    _ => (false, false),
+                }
+            }
+            Some(x) => (x.paren(), false),
+            None => (false, false),
+        };
+        let kind = match c {
+            '?' | '*' | '+' | '{' => RepeatToken,
+            '\\' if backref => BackrefToken,
+            '\\' => {
+                match *self.chars.get(start + 1) {
+                    'A' | 'z' | 'Z' | 'b' | 'B' => AnchorToken,
+                    _ => EscapeToken,
+                }
+            }
+            '[' | '.' => ClassToken,
+            '^' | '$' => AnchorToken,
+            '(' if opened => GroupOpenToken(self.flags),
+            '(' if backref => BackrefToken,
+            '(' if *self.chars.get(start + 2) == '#' => CommentToken,
+            '(' => FlagsToken(self.flags),
+            ')' => GroupCloseToken,
+            '|' => AltToken,
+            c if self.ignore_space() && c.is_whitespace() => return None,
+            '#' if self.ignore_space() => CommentToken,
+            _ => LiteralToken,
+        };
+        Some(kind)
+    }
+
+    // Handles an error from parse_item. Outside of recovery mode, the error
+    // is returned. Otherwise, it's recorded, and whatever construct caused
+    // it is replaced with an empty expression so that parsing can go on.
+    fn recover(&mut self, c: char, err: Error) -> Result<(), Error> {
+        let kind = err.kind.clone();
+        match self.errors {
+            None => return Err(err),
+            Some(ref mut errors) => errors.push(err),
+        }
+        match c {
+            // The group is still opened, so that its ')' closes it.
+            '(' => self.stack.push(Paren(self.flags, 0, ~"")),
+            // The group that couldn't be closed is dropped. (An unbounded
+            // lookbehind has been closed already.)
+            ')' if kind != UnboundedLookbehind
+                   && self.stack.iter().any(|x| x.paren()) => {
+                while !self.stack.last().unwrap().paren() {
+                    self.stack.pop();
+                }
+                self.flags = self.stack.pop().unwrap().flags();
+                self.push(~Nothing)
+            }
+            _ => self.push(~Nothing),
+        }
+        Ok(())
+    }
+
     fn noteof(&mut self, expected: &str) -> Result<(), Error> {
         match self.next_char() {
             true => Ok(()),
-            false => self.err(format!("Expected {} but got EOF.", expected)),
+            false => self.err(UnexpectedEof,
+                              format!("Expected {} but got EOF.", expected)),
         }
     }
 
     fn expect(&mut self, expected: char) -> Result<(), Error> {
         match self.next_char() {
             true if self.cur() == expected => Ok(()),
-            true => self.err(format!("Expected '{}' but got '{}'.",
+            true => self.err(UnexpectedChar(expected, self.cur()),
+                             format!("Expected '{}' but got '{}'.",
                                      expected, self.cur())),
-            false => self.err(format!("Expected '{}' but got EOF.", expected)),
+            false => self.err(UnexpectedEof,
+                              format!("Expected '{}' but got EOF.", expected)),
         }
     }
 
@@ -307,7 +1311,7 @@ impl<'a> Parser<'a> {
 
     fn push_repeater(&mut self, c: char) -> Result<(), Error> {
         if self.stack.len() == 0 {
-            return self.err(
+            return self.err(RepeatNoExpression,
                 "A repeat operator must be preceded by a valid expression.")
         }
         let rep: Repeater = match c {
@@ -316,41 +1320,67 @@ impl<'a> Parser<'a> {
         };
 
         match self.peek(1) {
+            // A '+' makes the repetition possessive.
+            Some('+') if self.flags & FLAG_BACKTRACK > 0 => {}
             Some('*') | Some('+') =>
-                return self.err(
+                return self.err(DoubleRepeat,
                     "Double repeat operators are not supported."),
             _ => {},
         }
         let ast = try!(self.pop_ast());
         match ast {
             ~Begin(_) | ~End(_) | ~WordBoundary(_) =>
-                return self.err(
+                return self.err(RepeatEmptyWidth,
                     "Repeat arguments cannot be empty width assertions."),
             _ => {}
         }
-        let greed = try!(self.get_next_greedy());
-        self.push(~Rep(ast, rep, greed));
+        if self.get_next_possessive() {
+            self.push(~Atomic(~Rep(ast, rep, Greedy)));
+        } else {
+            let greed = try!(self.get_next_greedy());
+            self.push(~Rep(ast, rep, greed));
+        }
         Ok(())
     }
 
     fn push_literal(&mut self, c: char) -> Result<(), Error> {
+        let flags = self.ast_flags();
         match c {
             '.' => {
-                self.push(~Dot(self.flags))
+                self.push(~Dot(flags))
             }
             '^' => {
-                self.push(~Begin(self.flags))
+                self.push(~Begin(flags))
             }
             '$' => {
-                self.push(~End(self.flags))
+                self.push(~End(flags))
             }
             _ => {
-                self.push(~Literal(c, self.flags))
+                self.push(~Literal(c, flags))
             }
         }
         Ok(())
     }
 
+    // Returns the current flags without the ones that only affect parsing.
+    fn ast_flags(&self) -> Flags {
+        self.flags & !(FLAG_IGNORE_SPACE | FLAG_UNICODE | FLAG_BACKTRACK)
+    }
+
+    fn ignore_space(&self) -> bool {
+        self.flags & FLAG_IGNORE_SPACE > 0
+    }
+
+    // Skips a comment in free-spacing mode.
+    // Assumes that '#' is the current character.
+    // When done, the parser will be at the character before the next new
+    // line (or at the last character).
+    fn skip_comment(&mut self) {
+        while self.peek(1).map_or(false, |c| c != '\n') {
+            self.chari += 1;
+        }
+    }
+
     // Parses all forms of character classes.
     // Assumes that '[' is the current character.
     fn parse_class(&mut self) -> Result<(), Error> {
@@ -363,6 +1393,10 @@ impl<'a> Parser<'a> {
             };
         let mut ranges: Vec<(char, char)> = vec!();
         let mut alts: Vec<~Ast> = vec!();
+        // When a set operator (&&, -- or ~~) is used, this holds the set of
+        // everything to the left of the most recent operator along with
+        // that operator.
+        let mut setop: Option<(Vec<(char, char)>, char)> = None;
 
         if self.peek_is(1, ']') {
             try!(self.expect(']'))
@@ -375,11 +1409,33 @@ impl<'a> Parser<'a> {
         loop {
             try!(self.noteof("a closing ']' or a non-empty character class)"))
             let mut c = self.cur();
+            // An escaped ']' doesn't close the class.
+            let mut escaped = false;
             match c {
+                '&' | '-' | '~' if self.peek_is(1, c)
+                                   && !self.peek_is(2, ']')
+                                   && (ranges.len() > 0 || alts.len() > 0) => {
+                    let rhs = class_set(mem::replace(&mut ranges, vec!()),
+                                        mem::replace(&mut alts, vec!()));
+                    let lhs = match setop.take() {
+                        None => rhs,
+                        Some((lhs, op)) => apply_set_op(op, lhs, rhs),
+                    };
+                    setop = Some((lhs, c));
+                    try!(self.expect(c))
+                    // A nested class may only appear as the right hand
+                    // operand of a set operator.
+                    if self.peek_is(1, '[') && !self.peek_is(2, ':') {
+                        try!(self.expect('['))
+                        try!(self.parse_class())
+                        alts.push(try!(self.pop_ast()));
+                    }
+                    continue
+                }
                 '[' =>
                     match self.try_parse_ascii() {
                         Some(~Class(asciis, flags)) => {
-                            alts.push(~Class(asciis, flags ^ negated));
+                            alts.push(~Class(asciis, flags));
                             continue
                         }
                         Some(ast) =>
@@ -390,23 +1446,51 @@ impl<'a> Parser<'a> {
                 '\\' => {
                     match try!(self.parse_escape()) {
                         ~Class(asciis, flags) => {
-                            alts.push(~Class(asciis, flags ^ negated));
+                            alts.push(~Class(asciis, flags));
                             continue
                         }
-                        ~Literal(c2, _) => c = c2, // process below
+                        ~Literal(c2, _) => { // process below
+                            c = c2;
+                            escaped = true;
+                        }
                         ~Begin(_) | ~End(_) | ~WordBoundary(_) =>
-                            return self.err(
-                                "\\A, \\z, \\b and \\B are not valid escape \
-                                 sequences inside a character class."),
+                            return self.err(InvalidClassEscape,
+                                "\\A, \\z, \\Z, \\b and \\B are not valid \
+                                 escape sequences inside a character class."),
+                        ~Alt(_, _) =>
+                            return self.err(InvalidClassEscape,
+                                "\\R is not a valid escape sequence inside \
+                                 a character class."),
+                        ~Backref(_, _) =>
+                            return self.err(InvalidClassEscape,
+                                "Backreferences are not valid inside a \
+                                 character class."),
                         ast => fail!("Unexpected AST item '{}'", ast),
                     }
                 }
                 _ => {},
             }
             match c {
-                ']' => {
+                ']' if !escaped => {
+                    let flags = negated | (self.flags & FLAG_NOCASE);
+                    match setop {
+                        Some((lhs, op)) => {
+                            let rhs = class_set(ranges, alts);
+                            let set = apply_set_op(op, lhs, rhs);
+                            self.push(~Class(set, flags));
+                            return Ok(())
+                        }
+                        None => {}
+                    }
+                    // Without set operators, the negation is applied to
+                    // each named class individually.
+                    let mut alts: Vec<~Ast> = alts.move_iter().map(|alt| {
+                        match alt {
+                            ~Class(asciis, fs) => ~Class(asciis, fs ^ negated),
+                            alt => alt,
+                        }
+                    }).collect();
                     if ranges.len() > 0 {
-                        let flags = negated | (self.flags & FLAG_NOCASE);
                         let mut ast = ~Class(combine_ranges(ranges), flags);
                         for alt in alts.move_iter() {
                             ast = ~Alt(alt, ast)
@@ -422,15 +1506,20 @@ impl<'a> Parser<'a> {
                     return Ok(())
                 }
                 c => {
-                    if self.peek_is(1, '-') && !self.peek_is(2, ']') {
+                    // A '-' that starts a '--' set operator doesn't form a
+                    // range.
+                    let setop_next = self.peek_is(2, '-')
+                                     && !self.peek_is(3, ']');
+                    if self.peek_is(1, '-') && !self.peek_is(2, ']')
+                       && !setop_next {
                         try!(self.expect('-'))
                         try!(self.noteof("not a ']'"))
-                        let c2 = self.cur();
+                        let c2 = try!(self.parse_class_range_end());
                         if c2 < c {
-                            return self.err(format!(
+                            return self.err(InvalidRange(c, c2), format!(
                                 "Invalid character class range '{}-{}'", c, c2))
                         }
-                        ranges.push((c, self.cur()))
+                        ranges.push((c, c2))
                     } else {
                         ranges.push((c, c))
                     }
@@ -439,6 +1528,22 @@ impl<'a> Parser<'a> {
         }
     }
 
+    // Parses the end of a character class range, which is either a single
+    // character or an escape sequence for one, like \x1F.
+    // Assumes that the end is the current character.
+    // After return, parser will point at the last character of the end.
+    fn parse_class_range_end(&mut self) -> Result<char, Error> {
+        if self.cur() != '\\' {
+            return Ok(self.cur())
+        }
+        match try!(self.parse_escape()) {
+            ~Literal(c, _) => Ok(c),
+            _ => self.err(InvalidClassEscape,
+                          "Only an escape sequence for a single character \
+                           can end a character class range."),
+        }
+    }
+
     // Tries to parse an ASCII character class of the form [:name:].
     // If successful, returns an AST character class corresponding to name
     // and moves the parser to the final ']' character.
@@ -489,12 +1594,15 @@ impl<'a> Parser<'a> {
         let closer =
             match self.pos('}') {
                 Some(i) => i,
-                None => return self.err(format!(
+                None => return self.err_span(start, self.chars.len(),
+                                             UnclosedRepetition, format!(
                     "No closing brace for counted repetition starting at \
                      position {}.", start)),
             };
         self.chari = closer;
-        let greed = try!(self.get_next_greedy());
+        let possessive = self.get_next_possessive();
+        let greed =
+            if possessive { Greedy } else { try!(self.get_next_greedy()) };
         let inner = str::from_chars(
             self.chars.as_slice().slice(start + 1, closer));
 
@@ -506,11 +1614,13 @@ impl<'a> Parser<'a> {
         } else {
             let pieces: Vec<&str> = inner.splitn(',', 1).collect();
             let (smin, smax) = (*pieces.get(0), *pieces.get(1));
-            if smin.len() == 0 {
-                return self.err("Max repetitions cannot be specified \
-                                    without min repetitions.")
+            if smin.len() == 0 && smax.len() == 0 {
+                return self.err_span(start, closer + 1, MissingMinRepetition,
+                                     "Counted repetitions must specify min \
+                                      or max repetitions.")
             }
-            min = try!(self.parse_uint(smin));
+            // A missing minimum, as in '{,5}', is zero.
+            min = if smin.len() == 0 { 0 } else { try!(self.parse_uint(smin)) };
             max =
                 if smax.len() == 0 {
                     None
@@ -521,50 +1631,42 @@ impl<'a> Parser<'a> {
 
         // Do some bounds checking and make sure max >= min.
         if min > MAX_REPEAT {
-            return self.err(format!(
+            return self.err_span(start, closer + 1,
+                                 RepetitionTooLarge(min, MAX_REPEAT), format!(
                 "{} exceeds maximum allowed repetitions ({})",
                 min, MAX_REPEAT));
         }
         if max.is_some() {
             let m = max.unwrap();
             if m > MAX_REPEAT {
-                return self.err(format!(
+                return self.err_span(start, closer + 1,
+                                     RepetitionTooLarge(m, MAX_REPEAT), format!(
                     "{} exceeds maximum allowed repetitions ({})",
                     m, MAX_REPEAT));
             }
             if m < min {
-                return self.err(format!(
+                return self.err_span(start, closer + 1,
+                                     InvalidRepetitionRange(min, m), format!(
                     "Max repetitions ({}) cannot be smaller than min \
                      repetitions ({}).", m, min));
             }
         }
 
-        // Now manipulate the AST be repeating elements.
-        if max.is_none() {
-            // Require N copies of what's on the stack and then repeat it.
-            let ast = try!(self.pop_ast());
-            for _ in iter::range(0, min) {
-                self.push(ast.clone())
-            }
-            self.push(~Rep(ast, ZeroMore, greed));
+        // The repetition is kept as is. Small ones are expanded into copies
+        // of the expression when it's simplified. (See `simplify.rs`.)
+        let ast = try!(self.pop_ast());
+        let rep = ~Rep(ast, Counted(min, max), greed);
+        let n = repeat_count(&*rep);
+        if n > MAX_REPEAT {
+            return self.err_span(start, closer + 1,
+                                 RepetitionTooLarge(n, MAX_REPEAT), format!(
+                "Nested repetitions repeat an expression {} times, which \
+                 exceeds the maximum allowed ({})", n, MAX_REPEAT));
+        }
+        if possessive {
+            self.push(~Atomic(rep));
         } else {
-            // Require N copies of what's on the stack and then repeat it
-            // up to M times optionally.
-            let ast = try!(self.pop_ast());
-            for _ in iter::range(0, min) {
-                self.push(ast.clone())
-            }
-            if max.is_some() {
-                for _ in iter::range(min, max.unwrap()) {
-                    self.push(~Rep(ast.clone(), ZeroOne, greed))
-                }
-            }
-            // It's possible that we popped something off the stack but
-            // never put anything back on it. To keep things simple, add
-            // a no-op expression.
-            if min == 0 && (max.is_none() || max == Some(0)) {
-                self.push(~Nothing)
-            }
+            self.push(rep);
         }
         Ok(())
     }
@@ -575,7 +1677,7 @@ impl<'a> Parser<'a> {
         try!(self.noteof("an escape sequence following a '\\'"))
 
         let c = self.cur();
-        if is_punct(c) {
+        if is_escapable(c) {
             return Ok(~Literal(c, FLAG_EMPTY))
         }
         match c {
@@ -584,24 +1686,93 @@ impl<'a> Parser<'a> {
             't' => Ok(~Literal('\t', FLAG_EMPTY)),
             'n' => Ok(~Literal('\n', FLAG_EMPTY)),
             'r' => Ok(~Literal('\r', FLAG_EMPTY)),
-            'v' => Ok(~Literal('\x0B', FLAG_EMPTY)),
+            'e' => Ok(~Literal('\x1B', FLAG_EMPTY)),
+            'c' => Ok(try!(self.parse_control())),
             'A' => Ok(~Begin(FLAG_EMPTY)),
             'z' => Ok(~End(FLAG_EMPTY)),
-            'b' => Ok(~WordBoundary(FLAG_EMPTY)),
-            'B' => Ok(~WordBoundary(FLAG_NEGATED)),
+            'Z' => Ok(~End(FLAG_FINAL_NEWLINE)),
+            'b' => Ok(~WordBoundary(self.flags & FLAG_UNICODE)),
+            'B' => Ok(~WordBoundary(FLAG_NEGATED | self.flags & FLAG_UNICODE)),
+            'R' => Ok(self.linebreak()),
+            // Without backtracking, a digit that can only be a
+            // backreference is reported as one (which needs backtracking)
+            // instead of being read as an octal escape.
+            '1'|'2'|'3'|'4'|'5'|'6'|'7'|'8'|'9'
+                    if self.flags & FLAG_BACKTRACK > 0
+                       || c >= '8'
+                       || c.to_digit(10).unwrap() <= self.caps =>
+                Ok(try!(self.parse_backref_num())),
             '0'|'1'|'2'|'3'|'4'|'5'|'6'|'7' => Ok(try!(self.parse_octal())),
             'x' => Ok(try!(self.parse_hex())),
+            'u' => Ok(try!(self.parse_hex_fixed(4))),
+            'U' => Ok(try!(self.parse_hex_fixed(8))),
             'p' | 'P' => Ok(try!(self.parse_unicode_name())),
-            'd' | 'D' | 's' | 'S' | 'w' | 'W' => {
-                let ranges = perl_unicode_class(c);
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' | 'h' | 'H' | 'v' | 'V' => {
+                let ranges =
+                    if self.flags & FLAG_UNICODE > 0 {
+                        perl_unicode_class(c)
+                    } else {
+                        perl_ascii_class(c)
+                    };
                 let mut flags = self.flags & FLAG_NOCASE;
                 if c.is_uppercase() { flags |= FLAG_NEGATED }
                 Ok(~Class(ranges, flags))
             }
-            _ => self.err(format!("Invalid escape sequence '\\\\{}'", c)),
+            _ => self.err_span(self.chari - 1, self.chari + 1,
+                               InvalidEscape(c),
+                               format!("Invalid escape sequence '\\\\{}'", c)),
         }
     }
 
+    // Parses a control escape, where \cA through \cZ are \x01 through
+    // \x1A. Letters are case insensitive.
+    // Assumes that \c has been read, where 'c' is the current character.
+    // After return, parser will point at the letter.
+    fn parse_control(&mut self) -> Result<~Ast, Error> {
+        try!(self.noteof("a letter following '\\c'"))
+        let c = self.cur();
+        if !((c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z')) {
+            return self.err_span(self.chari - 2, self.chari + 1,
+                                 InvalidControl(c), format!(
+                "Invalid control escape sequence '\\\\c{}'", c))
+        }
+        Ok(~Literal((c as u8 & 0x1F) as char, FLAG_EMPTY))
+    }
+
+    // Returns the expression for \R, which matches any line break. A \r\n
+    // is preferred over a lone \r.
+    fn linebreak(&self) -> ~Ast {
+        let crlf = ~Cat(vec!(~Literal('\r', FLAG_EMPTY),
+                             ~Literal('\n', FLAG_EMPTY)));
+        let ranges =
+            if self.flags & FLAG_UNICODE > 0 {
+                perl_unicode_class('v')
+            } else {
+                perl_ascii_class('v')
+            };
+        ~Alt(crlf, ~Class(ranges, FLAG_EMPTY))
+    }
+
+    // Parses a quoted span of the form \Q...\E, where every character
+    // between the delimiters is pushed as a literal. If there is no closing
+    // \E, then the span extends to the end of the expression.
+    // Assumes that '\' is the current character and that 'Q' follows it.
+    fn parse_quoted(&mut self) {
+        let flags = self.ast_flags();
+        self.chari += 1;
+        while self.next_char() {
+            if self.cur() == '\\' && self.peek_is(1, 'E') {
+                self.chari += 1;
+                return
+            }
+            let c = self.cur();
+            self.push(~Literal(c, flags))
+        }
+        // Leave the cursor on the last character so that the main loop
+        // sees the end of the expression.
+        self.chari -= 1;
+    }
+
     // Parses a unicode character class name, either of the form \pF where
     // F is a one letter unicode class name or of the form \p{name} where
     // name is the unicode class name.
@@ -609,30 +1780,58 @@ impl<'a> Parser<'a> {
     // character).
     fn parse_unicode_name(&mut self) -> Result<~Ast, Error> {
         let negated = if self.cur() == 'P' { FLAG_NEGATED } else { FLAG_EMPTY };
+        let start = self.chari - 1;
         let mut name: ~str;
         if self.peek_is(1, '{') {
             try!(self.expect('{'))
             let closer =
                 match self.pos('}') {
                     Some(i) => i,
-                    None => return self.err(format!(
+                    None => return self.err_span(start, self.chars.len(),
+                                                 UnclosedBrace, format!(
                         "Missing '\\}' for unclosed '\\{' at position {}",
                         self.chari)),
                 };
             if closer - self.chari + 1 == 0 {
-                return self.err("No Unicode class name found.")
+                return self.err_span(start, self.chari + 2,
+                                     MissingUnicodeClassName,
+                                     "No Unicode class name found.")
             }
             name = self.slice(self.chari + 1, closer);
             self.chari = closer;
         } else {
             if self.chari + 1 >= self.chars.len() {
-                return self.err("No single letter Unicode class name found.")
+                return self.err_span(start, self.chari + 1,
+                                     MissingUnicodeClassName,
+                                     "No single letter Unicode class name \
+                                      found.")
             }
             name = self.slice(self.chari + 1, self.chari + 2);
             self.chari += 1;
         }
-        match find_class(UNICODE_CLASSES, name) {
-            None => return self.err(format!(
+        // A property may be named along with its value, as in 'gc=Lu' or
+        // 'Script=Greek'. Blocks may also be named with 'In', as in
+        // 'InGreek', if no other class has that name.
+        let found =
+            match name.find(|c: char| c == '=' || c == ':') {
+                Some(i) => find_unicode_property(name.slice_to(i),
+                                                 name.slice_from(i + 1)),
+                None => {
+                    let loose = loose_name(name);
+                    match find_unicode_class(UNICODE_CLASSES,
+                                             name.as_slice()) {
+                        Some(ranges) => Some(ranges),
+                        None if loose.starts_with("in") => {
+                            find_block(loose.slice_from(2))
+                        }
+                        None => None,
+                    }
+                }
+            };
+        match found {
+            None => return self.err_span(start, self.chari + 1,
+                                         UnknownUnicodeClass(name.clone()),
+                                         format!(
                 "Could not find Unicode class '{}'", name)),
             Some(ranges) => {
                 Ok(~Class(ranges, negated | (self.flags & FLAG_NOCASE)))
@@ -640,6 +1839,82 @@ impl<'a> Parser<'a> {
         }
     }
 
+    // Parses a numbered backreference of one or two digits. The second
+    // digit is only used if there are enough capture groups for it.
+    // Assumes that \n has been read, where n is the first digit.
+    fn parse_backref_num(&mut self) -> Result<~Ast, Error> {
+        let mut n = self.cur().to_digit(10).unwrap();
+        match self.peek(1).and_then(|c| c.to_digit(10)) {
+            Some(d) if n * 10 + d <= self.caps => {
+                n = n * 10 + d;
+                self.chari += 1;
+            }
+            _ => {}
+        }
+        self.backref(n)
+    }
+
+    // Parses a backreference by name of the form (?P=name).
+    // Assumes that '(?P=' has been consumed and that the current character
+    // is '='.
+    // When done, parser will be at the closing ')' character.
+    fn parse_named_backref(&mut self) -> Result<(), Error> {
+        let closer =
+            match self.pos(')') {
+                Some(i) => i,
+                None => return self.err(UnclosedBackrefName,
+                    "Backreference name must end with ')'."),
+            };
+        let name = self.slice(self.chari + 1, closer);
+        let cap = match self.names.iter().find(|&&(ref n, _)| *n == name) {
+            Some(&(_, cap)) => cap,
+            None => return self.err_span(self.chari + 1, closer,
+                                         UndefinedGroupName(name.clone()),
+                                         format!(
+                "Backreference to undefined group name '{}'.", name)),
+        };
+        self.chari = closer;
+        let ast = try!(self.backref(cap));
+        self.push(ast);
+        Ok(())
+    }
+
+    // Returns a backreference to the capture group given, if backtracking
+    // is enabled and the group has been opened.
+    fn backref(&self, cap: uint) -> Result<~Ast, Error> {
+        try!(self.check_backtrack("Backreferences"))
+        if cap == 0 || cap > self.caps {
+            return self.err(UndefinedGroup(cap), format!(
+                "Backreference to undefined group {}.", cap))
+        }
+        Ok(~Backref(cap, self.flags & FLAG_NOCASE))
+    }
+
+    // Parses the start of a lookahead or lookbehind, which is closed like
+    // any other group.
+    // Assumes that '(?' (or '(?<' for a lookbehind) has been consumed and
+    // that the current character is '?' (or '<').
+    // When done, parser will be at the '=' or '!' character.
+    fn parse_lookaround(&mut self, behind: bool) -> Result<(), Error> {
+        try!(self.check_backtrack("Lookaround assertions"))
+        self.chari += 1;
+        let negated =
+            if self.cur() == '!' { FLAG_NEGATED } else { FLAG_EMPTY };
+        self.stack.push(LookParen(self.flags, negated, behind));
+        Ok(())
+    }
+
+    // Returns an error if backtracking isn't enabled. `what` names the
+    // construct that needs it.
+    fn check_backtrack(&self, what: &str) -> Result<(), Error> {
+        if self.flags & FLAG_BACKTRACK == 0 {
+            return self.err(BacktrackRequired, format!(
+                "{} are only allowed when backtracking is enabled with \
+                 RegexBuilder::backtrack.", what))
+        }
+        Ok(())
+    }
+
     // Parses an octal number, up to 3 digits.
     // Assumes that \n has been read, where n is the first digit.
     fn parse_octal(&mut self) -> Result<~Ast, Error> {
@@ -657,7 +1932,7 @@ impl<'a> Parser<'a> {
         let s = self.slice(start, end);
         match num::from_str_radix::<u32>(s, 8) {
             Some(n) => Ok(~Literal(try!(self.char_from_u32(n)), FLAG_EMPTY)),
-            None => self.err(format!(
+            None => self.err(InvalidOctal(s.to_owned()), format!(
                 "Could not parse '{}' as octal number.", s)),
         }
     }
@@ -672,7 +1947,8 @@ impl<'a> Parser<'a> {
         let start = self.chari + 2;
         let closer =
             match self.pos('}') {
-                None => return self.err(format!(
+                None => return self.err_span(start - 3, self.chars.len(),
+                                             UnclosedBrace, format!(
                     "Missing '\\}' for unclosed '\\{' at position {}", start)),
                 Some(i) => i,
             };
@@ -691,44 +1967,84 @@ impl<'a> Parser<'a> {
         self.parse_hex_digits(self.slice(start, end))
     }
 
+    // Parses a hex number of exactly `digits` digits, as in \u00E9 or
+    // \U0001F600.
+    // Assumes that \u or \U has been read, where 'u' or 'U' is the current
+    // character.
+    // After return, parser will point at the last digit.
+    fn parse_hex_fixed(&mut self, digits: uint) -> Result<~Ast, Error> {
+        let (start, end) = (self.chari + 1, self.chari + 1 + digits);
+        if end > self.chars.len() {
+            let bad = self.slice(start - 2, self.chars.len());
+            return self.err_span(start - 2, self.chars.len(), UnexpectedEof,
+                                 format!("Expected {} hex digits in escape \
+                                          sequence '{}'", digits, bad))
+        }
+        self.chari = end - 1;
+        self.parse_hex_digits(self.slice(start, end))
+    }
+
     // Parses `s` as a hexadecimal number.
     fn parse_hex_digits(&self, s: &str) -> Result<~Ast, Error> {
         match num::from_str_radix::<u32>(s, 16) {
             Some(n) => Ok(~Literal(try!(self.char_from_u32(n)), FLAG_EMPTY)),
-            None => self.err(format!(
+            None => self.err(InvalidHex(s.to_owned()), format!(
                 "Could not parse '{}' as hex number.", s)),
         }
     }
 
     // Parses a named capture.
-    // Assumes that '(?P<' has been consumed and that the current character
-    // is '<'.
+    // Assumes that '(?P<' or '(?<' has been consumed and that the current
+    // character is '<'.
     // When done, parser will be at the closing '>' character.
     fn parse_named_capture(&mut self) -> Result<(), Error> {
         try!(self.noteof("a capture name"))
         let closer =
             match self.pos('>') {
                 Some(i) => i,
-                None => return self.err("Capture name must end with '>'."),
+                None => return self.err_span(self.chari, self.chars.len(),
+                                             UnclosedCaptureName,
+                                             "Capture name must end with '>'."),
             };
         if closer - self.chari == 0 {
-            return self.err("Capture names must have at least 1 character.")
+            return self.err(EmptyCaptureName,
+                            "Capture names must have at least 1 character.")
         }
         let name = self.slice(self.chari, closer);
         if !name.chars().all(is_valid_cap) {
-            return self.err(
+            return self.err_span(self.chari, closer,
+                InvalidCaptureName(name.clone()),
                 "Capture names can only have underscores, letters and digits.")
         }
-        if self.names.contains(&name) {
-            return self.err(format!("Duplicate capture group name '{}'.", name))
+        if self.names.iter().any(|&(ref n, _)| *n == name) {
+            return self.err_span(self.chari, closer,
+                DuplicateCaptureName(name.clone()),
+                format!("Duplicate capture group name '{}'.", name))
         }
-        self.names.push(name.clone());
         self.chari = closer;
         self.caps += 1;
+        self.names.push((name.clone(), self.caps));
         self.stack.push(Paren(self.flags, self.caps, name));
         Ok(())
     }
 
+    // Skips a comment group, i.e., `(?#comment)`. The comment ends at the
+    // first ')' and can't contain one.
+    // Assumes that '(?' has been consumed and that the current character is
+    // '?'.
+    // When done, parser will be at the closing ')' character.
+    fn parse_comment(&mut self) -> Result<(), Error> {
+        let start = self.chari - 1;
+        match self.pos(')') {
+            Some(closer) => {
+                self.chari = closer;
+                Ok(())
+            }
+            None => self.err_span(start, self.chars.len(), UnclosedParen,
+                                  "Comment group must end with ')'."),
+        }
+    }
+
     // Parses non-capture groups and options.
     // Assumes that '(?' has already been consumed and '?' is the current
     // character.
@@ -737,35 +2053,72 @@ impl<'a> Parser<'a> {
             try!(self.expect('P')) try!(self.expect('<'))
             return self.parse_named_capture()
         }
+        // The same as (?P<name>exp), as spelled by .NET, Java and PCRE.
+        if self.peek_is(1, '<') && !self.peek_is(2, '=')
+           && !self.peek_is(2, '!') {
+            try!(self.expect('<'))
+            return self.parse_named_capture()
+        }
+        if self.peek_is(1, '#') {
+            return self.parse_comment()
+        }
+        if self.peek_is(1, 'P') && self.peek_is(2, '=') {
+            try!(self.expect('P')) try!(self.expect('='))
+            return self.parse_named_backref()
+        }
+        if self.peek_is(1, '=') || self.peek_is(1, '!') {
+            return self.parse_lookaround(false)
+        }
+        if self.peek_is(1, '<') {
+            try!(self.expect('<'))
+            return self.parse_lookaround(true)
+        }
+        if self.peek_is(1, '>') {
+            try!(self.check_backtrack("Atomic groups"))
+            try!(self.expect('>'))
+            self.stack.push(AtomicParen(self.flags));
+            return Ok(())
+        }
         let start = self.chari;
         let mut flags = self.flags;
         let mut sign = 1;
         let mut saw_flag = false;
         loop {
             try!(self.noteof("expected non-empty set of flags or closing ')'"))
+            let flag = match self.cur() {
+                'i' => FLAG_NOCASE,
+                'm' => FLAG_MULTI,
+                's' => FLAG_DOTNL,
+                'U' => FLAG_SWAP_GREED,
+                'x' => FLAG_IGNORE_SPACE,
+                'u' => FLAG_UNICODE,
+                _ => FLAG_EMPTY,
+            };
             match self.cur() {
-                'i' => { flags = flags | FLAG_NOCASE;     saw_flag = true},
-                'm' => { flags = flags | FLAG_MULTI;      saw_flag = true},
-                's' => { flags = flags | FLAG_DOTNL;      saw_flag = true},
-                'U' => { flags = flags | FLAG_SWAP_GREED; saw_flag = true},
+                _ if flag != FLAG_EMPTY => {
+                    if sign < 0 {
+                        flags = flags & !flag;
+                    } else {
+                        flags = flags | flag;
+                    }
+                    saw_flag = true;
+                }
                 '-' => {
                     if sign < 0 {
-                        return self.err(format!(
+                        return self.err_span(start - 1, self.chari + 1,
+                                             DoubleFlagNegation, format!(
                             "Cannot negate flags twice in '{}'.",
                             self.slice(start, self.chari + 1)))
                     }
                     sign = -1;
                     saw_flag = false;
-                    flags = flags ^ flags;
                 }
                 ':' | ')' => {
-                    if sign < 0 {
-                        if !saw_flag {
-                            return self.err(format!(
-                                "A valid flag does not follow negation in '{}'",
-                                self.slice(start, self.chari + 1)))
-                        }
-                        flags = flags ^ flags;
+                    if sign < 0 && !saw_flag {
+                        return self.err_span(start - 1, self.chari + 1,
+                                             EmptyFlagNegation, format!(
+                            "A valid flag does not follow negation in '{}'",
+                            self.slice(start, self.chari + 1)))
                     }
                     if self.cur() == ':' {
                         // Save the old flags with the opening paren.
@@ -774,7 +2127,7 @@ impl<'a> Parser<'a> {
                     self.flags = flags;
                     return Ok(())
                 }
-                _ => return self.err(format!(
+                _ => return self.err(UnrecognizedFlag(self.cur()), format!(
                     "Unrecognized flag '{}'.", self.cur())),
             }
         }
@@ -791,6 +2144,18 @@ impl<'a> Parser<'a> {
         }.swap(self.flags & FLAG_SWAP_GREED > 0))
     }
 
+    // Peeks at the next character and returns whether it makes the preceding
+    // repetition possessive (which is only possible when backtracking is
+    // enabled). If it does, then the next character is consumed.
+    fn get_next_possessive(&mut self) -> bool {
+        if self.flags & FLAG_BACKTRACK > 0 && self.peek_is(1, '+') {
+            self.chari += 1;
+            true
+        } else {
+            false
+        }
+    }
+
     // Searches the stack (starting at the top) until it finds an expression
     // for which `pred` returns true. The index of that expression in the
     // stack is returned.
@@ -807,7 +2172,8 @@ impl<'a> Parser<'a> {
                 if allow_start {
                     self.stack.len()
                 } else {
-                    return self.err("No matching opening parenthesis.")
+                    return self.err(UnopenedParen,
+                                    "No matching opening parenthesis.")
                 }
             }
         };
@@ -851,7 +2217,8 @@ impl<'a> Parser<'a> {
     fn build_from(&mut self, from: uint, mk: |~Ast, ~Ast| -> Ast)
                  -> Result<~Ast, Error> {
         if from >= self.stack.len() {
-            return self.err("Empty group or alternate not allowed.")
+            return self.err(EmptyGroup,
+                            "Empty group or alternate not allowed.")
         }
 
         let mut combined = try!(self.pop_ast());
@@ -869,7 +2236,7 @@ impl<'a> Parser<'a> {
     fn parse_uint(&self, s: &str) -> Result<uint, Error> {
         match from_str::<uint>(s) {
             Some(i) => Ok(i),
-            None => self.err(format!(
+            None => self.err(InvalidInteger(s.to_owned()), format!(
                 "Expected an unsigned integer but got '{}'.", s)),
         }
     }
@@ -877,7 +2244,7 @@ impl<'a> Parser<'a> {
     fn char_from_u32(&self, n: u32) -> Result<char, Error> {
         match char::from_u32(n) {
             Some(c) => Ok(c),
-            None => self.err(format!(
+            None => self.err(InvalidCodepoint(n), format!(
                 "Could not decode '{}' to unicode character.", n)),
         }
     }
@@ -887,13 +2254,29 @@ impl<'a> Parser<'a> {
             .skip(self.chari).position(|&c2| c2 == c).map(|i| self.chari + i)
     }
 
-    fn err<T>(&self, msg: &str) -> Result<T, Error> {
+    fn err<T>(&self, kind: ErrorKind, msg: &str) -> Result<T, Error> {
+        self.err_span(self.chari, self.chari + 1, kind, msg)
+    }
+
+    // Returns an error for the characters from `start` up to (but not
+    // including) `end`.
+    fn err_span<T>(&self, start: uint, end: uint, kind: ErrorKind, msg: &str)
+                  -> Result<T, Error> {
         Err(Error {
             pos: self.chari,
+            span: (self.byte_offset(start), self.byte_offset(end)),
+            kind: kind,
             msg: msg.to_owned(),
         })
     }
 
+    // Returns the byte index in the expression of the character at index
+    // `i` (or the length of the expression, if `i` is past the end).
+    fn byte_offset(&self, i: uint) -> uint {
+        let i = cmp::min(i, self.chars.len());
+        self.chars.slice_to(i).iter().fold(0, |n, c| n + c.len_utf8_bytes())
+    }
+
     fn peek(&self, offset: uint) -> Option<char> {
         if self.chari + offset >= self.chars.len() {
             return None
@@ -917,44 +2300,144 @@ impl<'a> Parser<'a> {
 // Given an unordered collection of character ranges, combine_ranges returns
 // an ordered sequence of character ranges where no two ranges overlap. They
 // are ordered from least to greatest (using start position).
-fn combine_ranges(unordered: Vec<(char, char)>) -> Vec<(char, char)> {
-    // Returns true iff the two character classes overlap or share a boundary.
-    // e.g., ('a', 'g') and ('h', 'm') would return true.
-    fn should_merge((a, b): (char, char), (x, y): (char, char)) -> bool {
-        cmp::max(a, x) as u32 <= cmp::min(b, y) as u32 + 1
-    }
+pub fn combine_ranges(unordered: Vec<(char, char)>) -> Vec<(char, char)> {
+    let mut unordered = unordered;
+    unordered.sort();
 
-    // This is currently O(n^2), but I think with sufficient cleverness,
-    // it can be reduced to O(n) **if necessary**.
     let mut ordered: Vec<(char, char)> = Vec::with_capacity(unordered.len());
     for (us, ue) in unordered.move_iter() {
-        let (mut us, mut ue) = (us, ue);
         assert!(us <= ue);
-        let mut which: Option<uint> = None;
-        for (i, &(os, oe)) in ordered.iter().enumerate() {
-            if should_merge((us, ue), (os, oe)) {
-                us = cmp::min(us, os);
-                ue = cmp::max(ue, oe);
-                which = Some(i);
-                break
-            }
-        }
-        match which {
-            None => ordered.push((us, ue)),
-            Some(i) => *ordered.get_mut(i) = (us, ue),
+        // Since the ranges are sorted, a range can only overlap or share a
+        // boundary with the last one added. e.g., ('a', 'g') and ('h', 'm')
+        // are merged.
+        let merge = match ordered.last() {
+            Some(&(_, oe)) => us as u32 <= oe as u32 + 1,
+            None => false,
+        };
+        if merge {
+            let (os, oe) = ordered.pop().unwrap();
+            ordered.push((os, cmp::max(oe, ue)));
+        } else {
+            ordered.push((us, ue));
         }
     }
-    ordered.sort();
     ordered
 }
 
-// Constructs a Unicode friendly Perl character class from \d, \s or \w
-// (or any of their negated forms). Note that this does not handle negation.
+// Returns every character that isn't in the given ranges, which must be
+// ordered and non-overlapping (as returned by combine_ranges).
+pub fn negate_ranges(ranges: &[(char, char)]) -> Vec<(char, char)> {
+    let mut negated = vec!();
+    let mut next = Some('\x00');
+    for &(s, e) in ranges.iter() {
+        match next {
+            Some(n) if n < s => negated.push((n, char_before(s))),
+            _ => {}
+        }
+        next = char_after(e);
+    }
+    match next {
+        Some(n) => negated.push((n, char::MAX)),
+        None => {}
+    }
+    negated
+}
+
+// The characters immediately before and after a character, skipping over
+// the surrogate code points (which aren't valid characters).
+fn char_before(c: char) -> char {
+    match c {
+        '\uE000' => '\uD7FF',
+        c => char::from_u32(c as u32 - 1).unwrap(),
+    }
+}
+
+fn char_after(c: char) -> Option<char> {
+    match c {
+        '\uD7FF' => Some('\uE000'),
+        c if c == char::MAX => None,
+        c => char::from_u32(c as u32 + 1),
+    }
+}
+
+fn union_ranges(xs: Vec<(char, char)>, ys: Vec<(char, char)>)
+               -> Vec<(char, char)> {
+    let mut xs = xs;
+    xs.push_all_move(ys);
+    combine_ranges(xs)
+}
+
+fn intersect_ranges(xs: Vec<(char, char)>, ys: Vec<(char, char)>)
+                   -> Vec<(char, char)> {
+    let union = union_ranges(negate_ranges(xs.as_slice()),
+                             negate_ranges(ys.as_slice()));
+    negate_ranges(union.as_slice())
+}
+
+fn subtract_ranges(xs: Vec<(char, char)>, ys: Vec<(char, char)>)
+                  -> Vec<(char, char)> {
+    intersect_ranges(xs, negate_ranges(ys.as_slice()))
+}
+
+// Applies one of the character class set operators: '&' for intersection
+// (&&), '-' for subtraction (--) or '~' for symmetric difference (~~).
+fn apply_set_op(op: char, lhs: Vec<(char, char)>, rhs: Vec<(char, char)>)
+               -> Vec<(char, char)> {
+    match op {
+        '&' => intersect_ranges(lhs, rhs),
+        '-' => subtract_ranges(lhs, rhs),
+        '~' => union_ranges(subtract_ranges(lhs.clone(), rhs.clone()),
+                            subtract_ranges(rhs, lhs)),
+        _ => unreachable!(),
+    }
+}
+
+// Returns the set of characters matched by the pieces of a character class
+// that has been parsed so far: plain ranges and any named or nested classes.
+// Negation is resolved, so the result never needs to be negated.
+fn class_set(ranges: Vec<(char, char)>, alts: Vec<~Ast>) -> Vec<(char, char)> {
+    fn ast_set(ast: &Ast) -> Vec<(char, char)> {
+        match *ast {
+            Class(ref ranges, flags) => {
+                let ranges = combine_ranges(ranges.clone());
+                if flags & FLAG_NEGATED > 0 {
+                    negate_ranges(ranges.as_slice())
+                } else {
+                    ranges
+                }
+            }
+            Alt(ref x, ref y) => union_ranges(ast_set(&**x), ast_set(&**y)),
+            ref ast => fail!("Expected Class AST but got '{}'", ast),
+        }
+    }
+    alts.iter().fold(combine_ranges(ranges), |set, alt| {
+        union_ranges(set, ast_set(&**alt))
+    })
+}
+
+// Constructs a Unicode friendly Perl character class from \d, \s, \w, \h
+// or \v (or any of their negated forms). Note that this does not handle
+// negation.
 fn perl_unicode_class(which: char) -> Vec<(char, char)> {
     match which.to_lowercase() {
         'd' => Vec::from_slice(PERLD),
         's' => Vec::from_slice(PERLS),
         'w' => Vec::from_slice(PERLW),
+        'h' => Vec::from_slice(PERLH),
+        'v' => Vec::from_slice(PERLV),
+        _ => unreachable!(),
+    }
+}
+
+// Like perl_unicode_class, but only with ASCII characters (when the `u` flag
+// is cleared).
+fn perl_ascii_class(which: char) -> Vec<(char, char)> {
+    match which.to_lowercase() {
+        'd' => vec!(('0', '9')),
+        's' => vec!(('\t', '\n'), ('\x0C', '\r'), (' ', ' ')),
+        'w' => vec!(('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')),
+        'h' => vec!(('\t', '\t'), (' ', ' ')),
+        'v' => vec!(('\n', '\r')),
         _ => unreachable!(),
     }
 }
@@ -970,6 +2453,35 @@ fn concat_flatten(x: ~Ast, y: ~Ast) -> Ast {
     }
 }
 
+// Writes a character at either end of a range in a character class,
+// escaping the ones that would be read as something else there (like the
+// `--` set operator).
+fn push_class_char(buf: &mut StrBuf, c: char) {
+    match c {
+        '\\' | '[' | '^' | '-' | ':' => push_hex(buf, c),
+        c if c.is_control() => push_hex(buf, c),
+        c => buf.push_char(c),
+    }
+}
+
+// Writes a character as a hex escape sequence, e.g., `\x{2D}`.
+fn push_hex(buf: &mut StrBuf, c: char) {
+    buf.push_str(format!("\\x\\{{:X}\\}", c as u32));
+}
+
+// Returns the letters for the flags given that can be set in a group, e.g.,
+// `(?im:...)`.
+fn group_flags(flags: Flags) -> ~str {
+    let mut letters = StrBuf::new();
+    for &(flag, c) in [(FLAG_NOCASE, 'i'), (FLAG_MULTI, 'm'),
+                       (FLAG_DOTNL, 's'), (FLAG_SWAP_GREED, 'U')].iter() {
+        if flags & flag > 0 {
+            letters.push_char(c);
+        }
+    }
+    letters.into_owned()
+}
+
 pub fn is_punct(c: char) -> bool {
     match c {
         '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' |
@@ -978,6 +2490,14 @@ pub fn is_punct(c: char) -> bool {
     }
 }
 
+/// Returns true if `c` is parsed as itself when it's escaped with `\\`.
+/// These are the punctuation characters with special meaning, and also
+/// whitespace and `#` (which have special meaning in free-spacing mode) and
+/// `-` (so that it can end a class range).
+pub fn is_escapable(c: char) -> bool {
+    is_punct(c) || c == '#' || c == '-' || c.is_whitespace()
+}
+
 fn is_valid_cap(c: char) -> bool {
     c == '_' || (c >= '0' && c <= '9')
     || (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z')
@@ -990,9 +2510,130 @@ fn find_class(classes: NamedClasses, name: &str) -> Option<Vec<(char, char)>> {
     }
 }
 
+// Finds a Unicode class by name. Unlike find_class, names are matched
+// loosely (ignoring case, spaces, underscores and hyphens) and general
+// categories may be given by their long names, e.g., 'Lowercase_Letter'.
+fn find_unicode_class(classes: NamedClasses, name: &str)
+                     -> Option<Vec<(char, char)>> {
+    match find_class(classes, name) {
+        Some(ranges) => return Some(ranges),
+        None => {}
+    }
+    let mut name = loose_name(name);
+    let alias = GENERAL_CATEGORY_ALIASES.iter()
+                                        .find(|&&(n, _)| n == name.as_slice());
+    match alias {
+        Some(&(_, short)) => name = loose_name(short),
+        None => {}
+    }
+    classes.iter()
+           .find(|&&(n, _)| loose_name(n) == name)
+           .map(|&(_, ranges)| Vec::from_slice(ranges))
+}
+
+// Finds the class for the value of a Unicode property, e.g., 'Lu' for
+// 'General_Category' or 'Greek' for 'Script'. Scripts are looked up by
+// their script extensions with 'Script_Extensions' (or 'scx'), which
+// includes characters that are shared by several scripts.
+fn find_unicode_property(property: &str, value: &str)
+                        -> Option<Vec<(char, char)>> {
+    match loose_name(property).as_slice() {
+        "generalcategory" | "gc" => {
+            let value = loose_name(value);
+            GENERAL_CATEGORY_ALIASES.iter()
+                .find(|&&(long, short)| {
+                    long == value.as_slice() || loose_name(short) == value
+                })
+                .and_then(|&(_, short)| find_class(UNICODE_CLASSES, short))
+        }
+        "script" | "sc" => {
+            // Every script has script extensions, but no other class does.
+            match find_unicode_class(SCRIPT_EXTENSIONS, value) {
+                None => None,
+                Some(_) => find_unicode_class(UNICODE_CLASSES, value),
+            }
+        }
+        "scriptextensions" | "scx" => {
+            find_unicode_class(SCRIPT_EXTENSIONS, value)
+        }
+        "block" | "blk" => find_block(value),
+        _ => None,
+    }
+}
+
+// Finds a Unicode block by name, e.g., 'Greek_and_Coptic'. Names are matched
+// loosely, and a few blocks may also be given by the names they had in older
+// versions of Unicode.
+fn find_block(name: &str) -> Option<Vec<(char, char)>> {
+    let mut name = loose_name(name);
+    let alias = BLOCK_ALIASES.iter().find(|&&(n, _)| n == name.as_slice());
+    match alias {
+        Some(&(_, block)) => name = loose_name(block),
+        None => {}
+    }
+    UNICODE_BLOCKS.iter()
+                  .find(|&&(n, _)| loose_name(n) == name)
+                  .map(|&(_, ranges)| Vec::from_slice(ranges))
+}
+
+fn loose_name(name: &str) -> ~str {
+    name.chars()
+        .filter(|&c| c != ' ' && c != '_' && c != '-')
+        .map(|c| c.to_lowercase())
+        .collect()
+}
+
 type Class = &'static [(char, char)];
 type NamedClasses = &'static [(&'static str, Class)];
 
+// Horizontal whitespace (\h): tab and the space separators.
+static PERLH: Class = &[
+    ('\t', '\t'), (' ', ' '), ('\xA0', '\xA0'), ('\u1680', '\u1680'),
+    ('\u2000', '\u200A'), ('\u202F', '\u202F'), ('\u205F', '\u205F'),
+    ('\u3000', '\u3000'),
+];
+
+// Vertical whitespace (\v): line feed through carriage return, next line
+// and the line and paragraph separators.
+static PERLV: Class = &[
+    ('\n', '\r'), ('\x85', '\x85'), ('\u2028', '\u2029'),
+];
+
+// The long names (and other aliases) of general categories, as given in
+// PropertyValueAliases.txt. Names are already loose (see loose_name).
+static GENERAL_CATEGORY_ALIASES: &'static [(&'static str, &'static str)] = &[
+    ("casedletter", "LC"), ("closepunctuation", "Pe"),
+    ("cntrl", "Cc"), ("combiningmark", "M"),
+    ("connectorpunctuation", "Pc"), ("control", "Cc"),
+    ("currencysymbol", "Sc"), ("dashpunctuation", "Pd"),
+    ("decimalnumber", "Nd"), ("digit", "Nd"),
+    ("enclosingmark", "Me"), ("finalpunctuation", "Pf"),
+    ("format", "Cf"), ("initialpunctuation", "Pi"),
+    ("letter", "L"), ("letternumber", "Nl"),
+    ("lineseparator", "Zl"), ("lowercaseletter", "Ll"),
+    ("mark", "M"), ("mathsymbol", "Sm"),
+    ("modifierletter", "Lm"), ("modifiersymbol", "Sk"),
+    ("nonspacingmark", "Mn"), ("number", "N"),
+    ("openpunctuation", "Ps"), ("other", "C"),
+    ("otherletter", "Lo"), ("othernumber", "No"),
+    ("otherpunctuation", "Po"), ("othersymbol", "So"),
+    ("paragraphseparator", "Zp"), ("privateuse", "Co"),
+    ("punct", "P"), ("punctuation", "P"),
+    ("separator", "Z"), ("spaceseparator", "Zs"),
+    ("spacingmark", "Mc"), ("symbol", "S"),
+    ("titlecaseletter", "Lt"), ("uppercaseletter", "Lu"),
+];
+
+// The older names of blocks that were renamed, as given in
+// PropertyValueAliases.txt. Names are already loose (see loose_name).
+static BLOCK_ALIASES: &'static [(&'static str, &'static str)] = &[
+    ("combiningmarksforsymbols", "Combining_Diacritical_Marks_for_Symbols"),
+    ("cyrillicsupplementary", "Cyrillic_Supplement"),
+    ("greek", "Greek_and_Coptic"),
+    ("latin1", "Latin-1_Supplement"),
+    ("privateuse", "Private_Use_Area"),
+];
+
 static ASCII_CLASSES: NamedClasses = &[
     // Classes must be in alphabetical order so that bsearch works.
     // [:alnum:]      alphanumeric (== [0-9A-Za-z])
@@ -0,0 +1,270 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Building regular expressions out of parts, without writing them as
+//! strings.
+//!
+//! Each function here returns a `Pattern`, which can be combined with others
+//! and then compiled with `Pattern::compile`. The abstract syntax is built
+//! directly, so nothing has to be quoted, and combining parts never changes
+//! what they match. (For example, an alternation that is concatenated with
+//! something else is grouped, and a literal string that is repeated is
+//! repeated as a whole.)
+//!
+//! Capture groups are numbered in the order they appear in the final
+//! pattern, starting at `1`, as if it had been written as a string.
+//!
+//! # Example
+//!
+//! ```rust
+//! use regex::pattern::{literal, class, concat, alternate};
+//!
+//! let digits = class([('0', '9')]).one_or_more();
+//! let version = concat(vec!(
+//!     alternate(vec!(literal("v"), literal("version "))),
+//!     digits.clone().named("major"),
+//!     literal("."),
+//!     digits.named("minor"),
+//! ));
+//! let re = version.compile().unwrap();
+//! let caps = re.captures("regex version 0.1").unwrap();
+//! assert_eq!(caps.name("major"), "0");
+//! assert_eq!(caps.name("minor"), "1");
+//! ```
+
+use parse;
+use parse::{
+    Ast, Nothing, Literal, Dot, Class, Begin, End, WordBoundary, Capture, Cat,
+    Alt, Rep, ZeroOne, ZeroMore, OneMore, Counted, Greedy, Ungreedy,
+    FLAG_EMPTY, FLAG_NOCASE, FLAG_NEGATED, FLAG_UNICODE, combine_ranges,
+};
+use re;
+use re::Regex;
+
+/// A part of a regular expression, built with the functions in this
+/// module.
+#[deriving(Clone, Show)]
+pub struct Pattern {
+    // Capture groups are numbered when the pattern is compiled, so they're
+    // all zero here.
+    ast: ~Ast,
+}
+
+/// Returns a pattern that matches the string given.
+pub fn literal(s: &str) -> Pattern {
+    let mut lits: Vec<~Ast> =
+        s.chars().map(|c| ~Literal(c, FLAG_EMPTY)).collect();
+    let ast = match lits.len() {
+        0 => ~Nothing,
+        1 => lits.pop().unwrap(),
+        _ => ~Cat(lits),
+    };
+    Pattern { ast: ast }
+}
+
+/// Returns a pattern that matches any character in one of the ranges given.
+/// Each range includes both of its ends (in either order).
+pub fn class(ranges: &[(char, char)]) -> Pattern {
+    Pattern { ast: ~Class(class_ranges(ranges), FLAG_EMPTY) }
+}
+
+/// Returns a pattern that matches any character that isn't in one of the
+/// ranges given.
+pub fn not_class(ranges: &[(char, char)]) -> Pattern {
+    Pattern { ast: ~Class(class_ranges(ranges), FLAG_NEGATED) }
+}
+
+/// Returns a pattern that matches any character except `\n`, like `.`.
+pub fn any() -> Pattern {
+    Pattern { ast: ~Dot(FLAG_EMPTY) }
+}
+
+/// Returns a pattern that matches at the beginning of the text, like `^`.
+pub fn begin() -> Pattern {
+    Pattern { ast: ~Begin(FLAG_EMPTY) }
+}
+
+/// Returns a pattern that matches at the end of the text, like `$`.
+pub fn end() -> Pattern {
+    Pattern { ast: ~End(FLAG_EMPTY) }
+}
+
+/// Returns a pattern that matches at a word boundary, like `\b`.
+pub fn word_boundary() -> Pattern {
+    Pattern { ast: ~WordBoundary(FLAG_UNICODE) }
+}
+
+/// Returns a pattern that matches each of the patterns given in turn. It
+/// matches the empty string if none are given.
+pub fn concat(ps: Vec<Pattern>) -> Pattern {
+    let mut xs: Vec<~Ast> = ps.move_iter().map(|p| p.ast).collect();
+    let ast = match xs.len() {
+        0 => ~Nothing,
+        1 => xs.pop().unwrap(),
+        _ => ~Cat(xs),
+    };
+    Pattern { ast: ast }
+}
+
+/// Returns a pattern that matches any one of the patterns given, preferring
+/// the ones that come first. It never matches if none are given.
+pub fn alternate(ps: Vec<Pattern>) -> Pattern {
+    let mut ps = ps;
+    let mut ast = match ps.pop() {
+        None => return Pattern { ast: ~Class(vec!(), FLAG_EMPTY) },
+        Some(p) => p.ast,
+    };
+    while ps.len() > 0 {
+        ast = ~Alt(ps.pop().unwrap().ast, ast);
+    }
+    Pattern { ast: ast }
+}
+
+impl Pattern {
+    /// Puts the pattern in a capture group.
+    pub fn group(self) -> Pattern {
+        Pattern { ast: ~Capture(0, None, self.ast) }
+    }
+
+    /// Puts the pattern in a named capture group. (The name is checked when
+    /// the pattern is compiled.)
+    pub fn named(self, name: &str) -> Pattern {
+        Pattern { ast: ~Capture(0, Some(name.to_owned()), self.ast) }
+    }
+
+    /// Repeats the pattern at least `min` times, and at most `max` times if
+    /// it's given, like `{min,max}`. (The counts are checked when the
+    /// pattern is compiled.)
+    ///
+    /// A pattern that matches without consuming any characters, like
+    /// `begin()`, doesn't change when it's repeated, so repeating it just
+    /// makes it optional (if `min` is `0`).
+    pub fn repeat(self, min: uint, max: Option<uint>) -> Pattern {
+        let rep = match (min, max) {
+            (0, Some(1)) => ZeroOne,
+            (0, None) => ZeroMore,
+            (1, None) => OneMore,
+            (min, max) => Counted(min, max),
+        };
+        match *self.ast {
+            Nothing | Begin(_) | End(_) | WordBoundary(_) => {
+                if min == 0 {
+                    Pattern { ast: ~Nothing }
+                } else {
+                    self
+                }
+            }
+            _ => Pattern { ast: ~Rep(self.ast, rep, Greedy) },
+        }
+    }
+
+    /// Makes the pattern optional, like `?`.
+    pub fn optional(self) -> Pattern {
+        self.repeat(0, Some(1))
+    }
+
+    /// Repeats the pattern any number of times, like `*`.
+    pub fn zero_or_more(self) -> Pattern {
+        self.repeat(0, None)
+    }
+
+    /// Repeats the pattern at least once, like `+`.
+    pub fn one_or_more(self) -> Pattern {
+        self.repeat(1, None)
+    }
+
+    /// Makes a repetition prefer to match less, like `*?`. Any other
+    /// pattern is returned as is.
+    pub fn lazy(self) -> Pattern {
+        match self.ast {
+            ~Rep(x, rep, _) => Pattern { ast: ~Rep(x, rep, Ungreedy) },
+            ast => Pattern { ast: ast },
+        }
+    }
+
+    /// Makes the pattern match letters regardless of their case, like the
+    /// `i` flag.
+    pub fn case_insensitive(self) -> Pattern {
+        Pattern { ast: set_nocase(self.ast) }
+    }
+
+    /// Returns the abstract syntax of the pattern, with its capture groups
+    /// numbered.
+    pub fn to_ast(&self) -> ~Ast {
+        let mut caps = 0;
+        number_captures(self.ast.clone(), &mut caps)
+    }
+
+    /// Returns a regular expression that's equivalent to the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use regex::pattern::literal;
+    ///
+    /// let p = literal("a.b").group().one_or_more().lazy();
+    /// assert_eq!(p.to_pattern(), ~r"(a\.b)+?");
+    /// ```
+    pub fn to_pattern(&self) -> ~str {
+        self.to_ast().to_pattern()
+    }
+
+    /// Compiles the pattern, without writing it as an expression and
+    /// parsing it. An error is returned if it has an invalid or duplicate
+    /// capture name, or a repetition count that is too large (or a maximum
+    /// that is smaller than its minimum). The error's position and span are
+    /// always zero.
+    pub fn compile(&self) -> Result<Regex, parse::Error> {
+        let ast = self.to_ast();
+        try!(parse::check(&*ast));
+        Ok(re::compile_ast(ast))
+    }
+}
+
+// Returns the ranges in a class, in order and without overlaps.
+fn class_ranges(ranges: &[(char, char)]) -> Vec<(char, char)> {
+    let ranges = ranges.iter().map(|&(s, e)| {
+        if s <= e { (s, e) } else { (e, s) }
+    }).collect();
+    combine_ranges(ranges)
+}
+
+// Numbers the capture groups in the order they appear, after the `caps`
+// groups that come before them.
+fn number_captures(ast: ~Ast, caps: &mut uint) -> ~Ast {
+    match ast {
+        ~Capture(_, name, x) => {
+            *caps += 1;
+            let cap = *caps;
+            ~Capture(cap, name, number_captures(x, caps))
+        }
+        ~Rep(x, rep, greed) => ~Rep(number_captures(x, caps), rep, greed),
+        ~Cat(xs) => ~Cat(xs.move_iter().map(|x| number_captures(x, caps))
+                                       .collect()),
+        ~Alt(x, y) => {
+            let x = number_captures(x, caps);
+            ~Alt(x, number_captures(y, caps))
+        }
+        ast => ast,
+    }
+}
+
+// Sets the case insensitive flag on every character in the pattern.
+fn set_nocase(ast: ~Ast) -> ~Ast {
+    match ast {
+        ~Literal(c, flags) => ~Literal(c, flags | FLAG_NOCASE),
+        ~Class(ranges, flags) => ~Class(ranges, flags | FLAG_NOCASE),
+        ~Capture(cap, name, x) => ~Capture(cap, name, set_nocase(x)),
+        ~Rep(x, rep, greed) => ~Rep(set_nocase(x), rep, greed),
+        ~Cat(xs) => ~Cat(xs.move_iter().map(set_nocase).collect()),
+        ~Alt(x, y) => ~Alt(set_nocase(x), set_nocase(y)),
+        ast => ast,
+    }
+}
@@ -9,26 +9,90 @@
 // except according to those terms.
 
 use collections::HashMap;
+use std::cmp;
 use std::fmt;
 use std::from_str::from_str;
+use std::hash::Hash;
 use std::str::{MaybeOwned, Owned, Slice};
+use std::vec;
+use rand::Rng;
+use sync::Arc;
 
-use compile::Program;
+use backtrack;
+use compile;
+use compile::{Program, LazyProgram, EmptyWordBoundary};
+use compile::DEFAULT_BACKTRACK_LIMIT;
+use gen;
+use language;
+use language::Language;
 use parse;
+use simplify;
 use vm;
 use vm::{CaptureLocs, MatchKind, Exists, Location, Submatches};
+use vm::{Input, ChunkReader};
+
+// The number of random strings that `Regex::gen_match` tries before giving
+// up.
+static GEN_MATCH_TRIES: uint = 100;
+
+/// Compiles abstract syntax that has been checked with `parse::check` (and
+/// wasn't parsed from an expression). The expression that the regex shows
+/// is written with `Ast::to_pattern`.
+pub fn compile_ast(ast: ~parse::Ast) -> Regex {
+    let mut builder = RegexBuilder::new(ast.to_pattern().as_slice());
+    // The expression has to parse again when it has backreferences and
+    // the like.
+    if parse::needs_backtrack(&*ast) {
+        builder.backtrack(true);
+    }
+    builder.compile(ast)
+}
+
+/// The error returned by `Regex::try_is_match` and the like when a search
+/// takes more steps than `RegexBuilder::backtrack_limit` allows, so it
+/// can't tell whether the regex matches.
+#[deriving(Clone, Eq, Show)]
+pub struct LimitExceeded {
+    /// The limit that was exceeded.
+    pub limit: uint,
+}
 
 /// Escapes all regular expression meta characters in `text` so that it may be
 /// safely used in a regular expression as a literal string.
 pub fn quote(text: &str) -> ~str {
     let mut quoted = StrBuf::with_capacity(text.len());
+    quote_into(text, &mut quoted);
+    quoted.into_owned()
+}
+
+/// Does the same as `quote`, except the escaped text is appended to `buf`
+/// instead of a new string.
+///
+/// # Example
+///
+/// ```rust
+/// # use regex::quote_into;
+/// let mut re = StrBuf::from_str("^");
+/// quote_into("1+1=2", &mut re);
+/// re.push_char('$');
+/// assert_eq!(re.as_slice(), r"^1\+1=2$");
+/// ```
+pub fn quote_into(text: &str, buf: &mut StrBuf) {
     for c in text.chars() {
-        if parse::is_punct(c) {
-            quoted.push_char('\\')
+        if is_meta_character(c) {
+            buf.push_char('\\')
         }
-        quoted.push_char(c);
+        buf.push_char(c);
     }
-    quoted.into_owned()
+}
+
+/// Returns true if the given character has special meaning in a regular
+/// expression (including whitespace and `#`, which have special meaning
+/// with the `x` flag, and `-`, which does in a character class). These are
+/// exactly the characters that `quote` escapes, and escaping any of them
+/// always gives the character itself.
+pub fn is_meta_character(c: char) -> bool {
+    parse::is_escapable(c)
 }
 
 /// Tests if the given regular expression matches somewhere in the text given.
@@ -45,6 +109,84 @@ pub fn is_match(regex: &str, text: &str) -> Result<bool, parse::Error> {
     Regex::new(regex).map(|r| r.is_match(text))
 }
 
+/// Replaces all of the matches of several regexes in `text` with their
+/// replacements in a single pass, instead of a pass for each regex (as
+/// calling `replace_all` with each of them in turn would take).
+///
+/// The text is searched from left to right, and each time, the leftmost
+/// match of any of the regexes is replaced. (If more than one matches at
+/// the same place, then the one that comes first in `reps` is used.) The
+/// search goes on after the match, so matches never overlap, and text that
+/// was put in by a replacement is never searched. So the result is the same
+/// as replacing with each regex in turn, as long as none of the regexes
+/// matches text that another one matches or that a replacement puts in.
+///
+/// # Example
+///
+/// ```rust
+/// # use regex::{Regex, replace_all_multi};
+/// let mut reps = [(Regex::new(r"B").unwrap(), "(c|g|t)"),
+///                 (Regex::new(r"K").unwrap(), "(g|t)")];
+/// let text = replace_all_multi("aBcKB", &mut reps);
+/// assert_eq!(text.as_slice(), "a(c|g|t)c(g|t)(c|g|t)");
+///
+/// // Text that was already replaced isn't replaced again.
+/// let mut swap = [(Regex::new(r"a").unwrap(), "b"),
+///                 (Regex::new(r"b").unwrap(), "a")];
+/// assert_eq!(replace_all_multi("abba", &mut swap).as_slice(), "baab");
+/// ```
+pub fn replace_all_multi<R: Replacer>(text: &str, reps: &mut [(Regex, R)])
+                                     -> StrBuf {
+    let mut new = StrBuf::with_capacity(text.len());
+    let (mut last_end, mut last_match) = (0, None);
+    // The next match of each regex (if it has one). A match is only found
+    // again once it starts before the end of the last match, so each regex
+    // searches the text about once.
+    let mut nexts: Vec<Option<(uint, uint)>> = reps.iter().map(|&(ref re, _)| {
+        next_match(re, text, 0, None)
+    }).collect();
+    loop {
+        // The regex with the leftmost match, and where it is.
+        let mut best = None;
+        for i in range(0, reps.len()) {
+            let stale = match *nexts.get(i) {
+                Some((s, e)) => {
+                    s < last_end || (s == e && Some(s) == last_match)
+                }
+                None => false,
+            };
+            if stale {
+                let (ref re, _) = reps[i];
+                *nexts.get_mut(i) = next_match(re, text, last_end,
+                                               last_match);
+            }
+            match (*nexts.get(i), best) {
+                (Some((s, _)), Some((_, (bs, _)))) if s >= bs => {}
+                (Some(m), _) => best = Some((i, m)),
+                (None, _) => {}
+            }
+        }
+        let (i, (s, e)) = match best {
+            None => break,
+            Some(best) => best,
+        };
+        new.push_str(text.slice(last_end, s));
+        let (ref re, ref mut rep) = reps[i];
+        if rep.no_expansion().is_some() {
+            new.push_str(rep.no_expansion().unwrap().as_slice());
+        } else {
+            // Searching from the start of the match finds the same match.
+            let locs = exec_slice(re, Submatches, text, s, text.len());
+            let caps = Captures::new(re, text, locs).unwrap();
+            new.push_str(rep.reg_replace(&caps).as_slice());
+        }
+        last_end = e;
+        last_match = Some(e);
+    }
+    new.push_str(text.slice(last_end, text.len()));
+    new
+}
+
 /// Regex is a compiled regular expression, represented as either a sequence
 /// of bytecode instructions (dynamic) or as a specialized Rust function
 /// (native). It can be used to search, split
@@ -65,6 +207,9 @@ pub fn is_match(regex: &str, text: &str) -> Result<bool, parse::Error> {
 /// methods. All other methods (searching and splitting) return borrowed
 /// pointers into the string given.
 ///
+/// A `Regex` is `Send` and `Share`, so a single compiled expression can be
+/// used by many tasks at the same time (e.g., by wrapping it in an `Arc`).
+///
 /// # Examples
 ///
 /// Find the location of a US phone number:
@@ -98,7 +243,6 @@ pub fn is_match(regex: &str, text: &str) -> Result<bool, parse::Error> {
 /// makes it much faster when searching text.
 /// More details about the `regex!` macro can be found in the `regex` crate
 /// documentation.
-#[deriving(Clone)]
 #[allow(visible_private_types)]
 pub struct Regex {
     /// The representation of `Regex` is exported to support the `regex!`
@@ -112,6 +256,34 @@ pub struct Regex {
     pub names: ~[Option<~str>],
     #[doc(hidden)]
     pub p: MaybeNative,
+    /// Thread queues reused by successive searches with a dynamic regex.
+    /// They are allocated lazily, and there is one set for every search
+    /// running at the same time. (Native regexes don't need them since their
+    /// queues live on the stack.)
+    #[doc(hidden)]
+    pub scratch: vm::ScratchPool,
+    /// The program for searching backwards (with `rfind`). It's compiled the
+    /// first time it's needed.
+    #[doc(hidden)]
+    pub reverse: LazyProgram,
+    /// The program that only matches all of the text (for `is_full_match`).
+    /// It's compiled the first time it's needed.
+    #[doc(hidden)]
+    pub full: LazyProgram,
+}
+
+impl Clone for Regex {
+    /// Clones the expression, but not its scratch memory.
+    fn clone(&self) -> Regex {
+        Regex {
+            original: self.original.clone(),
+            names: self.names.clone(),
+            p: self.p.clone(),
+            scratch: vm::ScratchPool::new(),
+            reverse: self.reverse.clone(),
+            full: self.full.clone(),
+        }
+    }
 }
 
 impl fmt::Show for Regex {
@@ -121,6 +293,23 @@ impl fmt::Show for Regex {
     }
 }
 
+impl Eq for Regex {
+    /// Two regular expressions are equal if they were compiled from the same
+    /// expression with the same options.
+    fn eq(&self, other: &Regex) -> bool {
+        self.original == other.original && self.options() == other.options()
+    }
+}
+
+impl TotalEq for Regex {}
+
+impl<S: Writer> Hash<S> for Regex {
+    fn hash(&self, state: &mut S) {
+        self.original.hash(state);
+        self.options().hash(state);
+    }
+}
+
 pub enum MaybeNative {
     Dynamic(Program),
     Native(fn(MatchKind, &str, uint, uint) -> Vec<Option<uint>>),
@@ -135,6 +324,139 @@ impl Clone for MaybeNative {
     }
 }
 
+/// RegexBuilder compiles a dynamic regular expression with options that
+/// apply to the whole expression. Each option is set by calling a method,
+/// and `build` compiles the expression.
+///
+/// # Example
+///
+/// ```rust
+/// # use regex::RegexBuilder;
+/// let re = RegexBuilder::new(r"\d+").unicode(false).build().unwrap();
+/// assert_eq!(re.find("١٢3"), Some((4, 5)));
+/// ```
+pub struct RegexBuilder {
+    pattern: ~str,
+    flags: parse::Flags,
+    backtrack_limit: uint,
+    alternates: bool,
+}
+
+impl RegexBuilder {
+    /// Creates a builder for the expression given with the default options.
+    pub fn new(re: &str) -> RegexBuilder {
+        RegexBuilder {
+            pattern: re.to_owned(),
+            flags: parse::FLAG_UNICODE,
+            backtrack_limit: DEFAULT_BACKTRACK_LIMIT,
+            alternates: false,
+        }
+    }
+
+    /// Sets the `u` flag for the whole expression, which makes `\d`, `\s`
+    /// and `\w` Unicode aware. It is enabled by default. (It can still be
+    /// changed inside the expression with `(?u)` and `(?-u)`.)
+    pub fn unicode(&mut self, yes: bool) -> &mut RegexBuilder {
+        self.set_flag(parse::FLAG_UNICODE, yes)
+    }
+
+    /// Allows backreferences, lookaround assertions, atomic groups and
+    /// possessive repetitions in the expression.
+    /// It is disabled by default. (See the crate documentation for the
+    /// syntax.)
+    ///
+    /// Backreferences match the same text that a capture group last matched:
+    /// `\1` through `\9` refer to groups by index and `(?P=name)` refers to
+    /// a group by name. (So `\1` through `\7` are no longer octal escapes.
+    /// `\10` through `\99` are also backreferences if there are enough
+    /// groups.)
+    ///
+    /// An expression with these can't be matched in linear time, so it's
+    /// run by a backtracking engine instead of the usual one. The amount of
+    /// work it may do is limited by `backtrack_limit`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::RegexBuilder;
+    /// let re = RegexBuilder::new(r"\b(\w+) \1\b").backtrack(true)
+    ///                                           .build().unwrap();
+    /// assert_eq!(re.find("it was the the end"), Some((7, 14)));
+    /// ```
+    pub fn backtrack(&mut self, yes: bool) -> &mut RegexBuilder {
+        self.set_flag(parse::FLAG_BACKTRACK, yes)
+    }
+
+    /// Sets the maximum number of steps that the backtracking engine may take
+    /// from any one starting position in a search. If a search needs more,
+    /// then it stops without deciding whether there's a match.
+    /// `try_is_match`, `try_find` and `try_captures` report this with a
+    /// `LimitExceeded` error. Every other search reports that there is no
+    /// match (`is_match` returns false, and `find`, `captures` and the like
+    /// return `None`, so iterators over matches end there), which may be
+    /// wrong. So use the `try_` methods when the expression or the text isn't
+    /// trusted. The default is `1000000`.
+    ///
+    /// This has no effect on expressions that don't need backtracking.
+    pub fn backtrack_limit(&mut self, limit: uint) -> &mut RegexBuilder {
+        self.backtrack_limit = limit;
+        self
+    }
+
+    /// Records which of the expression's top-level alternates matched, so
+    /// that `Captures::alternate` can report it. It is disabled by default,
+    /// since it makes searching for captures slower.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::RegexBuilder;
+    /// let re = RegexBuilder::new("GET|POST|PUT|DELETE").alternates(true)
+    ///                                                 .build().unwrap();
+    /// let caps = re.captures("POST /index.html").unwrap();
+    /// assert_eq!(caps.alternate(), Some(1));
+    /// ```
+    pub fn alternates(&mut self, yes: bool) -> &mut RegexBuilder {
+        self.alternates = yes;
+        self
+    }
+
+    /// Compiles the expression with the options that have been set.
+    ///
+    /// If an invalid expression is given, then an error is returned.
+    pub fn build(&self) -> Result<Regex, parse::Error> {
+        let ast = try!(parse::parse_with_flags(self.pattern.as_slice(),
+                                               self.flags));
+        Ok(self.compile(ast))
+    }
+
+    // Compiles abstract syntax that the expression parses to with the
+    // options that have been set.
+    fn compile(&self, ast: ~parse::Ast) -> Regex {
+        let (mut prog, names) = Program::new(ast, self.alternates);
+        prog.backtrack_limit = self.backtrack_limit;
+        prog.flags = self.flags;
+        Regex {
+            original: self.pattern.clone(),
+            names: names,
+            p: Dynamic(prog),
+            scratch: vm::ScratchPool::new(),
+            reverse: LazyProgram::new(),
+            full: LazyProgram::new(),
+        }
+    }
+
+    fn set_flag(&mut self, flag: parse::Flags, yes: bool)
+               -> &mut RegexBuilder {
+        if yes {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+        self
+    }
+}
+
 impl Regex {
     /// Compiles a dynamic regular expression. Once compiled, it can be
     /// used repeatedly to search, split or replace text in a string.
@@ -143,10 +465,449 @@ impl Regex {
     /// safer and always faster.
     ///
     /// If an invalid expression is given, then an error is returned.
+    ///
+    /// To compile an expression with options that aren't the defaults, use
+    /// `RegexBuilder`.
     pub fn new(re: &str) -> Result<Regex, parse::Error> {
-        let ast = try!(parse::parse(re));
-        let (prog, names) = Program::new(ast);
-        Ok(Regex { original: re.to_owned(), names: names, p: Dynamic(prog) })
+        RegexBuilder::new(re).build()
+    }
+
+    /// Compiles abstract syntax (see the `syntax` module) that was built or
+    /// changed by hand, without writing it as an expression and parsing it.
+    ///
+    /// The syntax is checked for the errors that parsing would catch, like a
+    /// lookbehind that isn't bounded. Capture groups must be numbered in the
+    /// order they open, starting at `1`, and character class ranges can be
+    /// in any order. Since there's no expression, the error's position and
+    /// span are always zero. Backtracking is enabled if the syntax needs it.
+    ///
+    /// The regex shows an expression that is equivalent to the syntax, as
+    /// written by `Ast::to_pattern`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use regex::Regex;
+    /// use regex::syntax::{parse, Rep, Ungreedy};
+    ///
+    /// // Makes the repetition lazy.
+    /// let ast = match parse(r"a+").unwrap() {
+    ///     ~Rep(x, rep, _) => ~Rep(x, rep, Ungreedy),
+    ///     ast => ast,
+    /// };
+    /// let re = Regex::from_ast(ast).unwrap();
+    /// assert_eq!(re.find("aaa"), Some((0, 1)));
+    /// assert_eq!(re.to_str(), ~"a+?");
+    /// ```
+    pub fn from_ast(ast: ~parse::Ast) -> Result<Regex, parse::Error> {
+        try!(parse::check(&*ast));
+        Ok(compile_ast(parse::combine_classes(ast)))
+    }
+
+    /// Compiles a regex that matches any of the expressions given,
+    /// preferring the ones that come first, and reports which one matched
+    /// with `Captures::alternate`.
+    ///
+    /// Unlike joining the expressions with `|`, this works for any
+    /// expressions: one that is an alternation itself is still a single
+    /// alternate, and flags set in one (like `(?i)`) don't apply to the
+    /// others. Each expression is parsed as it is by `Regex::new`. If any of
+    /// them is invalid, then the first error found is returned. (Its
+    /// position is in the expression that has the error.) The capture groups
+    /// of each expression follow those of the expressions before it, and
+    /// none of them can have the same name. If no expressions are given,
+    /// then nothing matches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::union(&[r"foo", r"bar(\d+)|baz", r"(?i)qux"]).unwrap();
+    /// let caps = re.captures("bar42").unwrap();
+    /// assert_eq!(caps.alternate(), Some(1));
+    /// assert_eq!(caps.at(1), "42");
+    /// assert_eq!(re.captures("baz").unwrap().alternate(), Some(1));
+    /// assert_eq!(re.captures("QUX").unwrap().alternate(), Some(2));
+    /// assert!(!re.is_match("BAZ"));
+    /// ```
+    pub fn union(res: &[&str]) -> Result<Regex, parse::Error> {
+        let mut alts = vec!();
+        let mut ncaps = 0;
+        for &re in res.iter() {
+            let (ast, spans) =
+                try!(parse::parse_with_spans(re, parse::FLAG_UNICODE));
+            alts.push(shift_captures(ast, ncaps, &[]));
+            ncaps += spans.len();
+        }
+        let never = || ~parse::Class(vec!(), parse::FLAG_EMPTY);
+        let ast = match alts.pop() {
+            None => never(),
+            Some(last) => {
+                // Wrapping the last expression wouldn't keep its alternation
+                // (if it has one) from being counted as separate expressions
+                // once the regex is parsed again, so it's followed by one
+                // that never matches instead.
+                let last = match last {
+                    ~parse::Alt(x, y) => ~parse::Alt(~parse::Alt(x, y),
+                                                     never()),
+                    last => last,
+                };
+                alts.move_iter().rev().fold(last, |ast, alt| {
+                    ~parse::Alt(alt, ast)
+                })
+            }
+        };
+        try!(parse::check(&*ast));
+        let mut builder = RegexBuilder::new(ast.to_pattern().as_slice());
+        builder.alternates(true);
+        Ok(builder.compile(ast))
+    }
+
+    /// Returns the number of capture groups in the regular expression,
+    /// including the group for the entire match. (So it's always at least
+    /// `1`.) Every `Captures` for this regex has exactly this length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::new(r"(\d{4})-(?P<m>\d{2})").unwrap();
+    /// assert_eq!(re.captures_len(), 3);
+    /// ```
+    pub fn captures_len(&self) -> uint {
+        // There's a name (or `None`) for every capture group up to the last
+        // one, if there are any.
+        cmp::max(1, self.names.len())
+    }
+
+    /// Returns where each capture group is in the original expression, as
+    /// the byte indices of its opening parenthesis and just past its closing
+    /// one. Group `0` (the entire match) spans all of the expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::new(r"(\d{4})-(?P<m>\d{2})").unwrap();
+    /// assert_eq!(re.capture_spans(), vec![(0, 20), (0, 7), (8, 20)]);
+    /// ```
+    pub fn capture_spans(&self) -> Vec<(uint, uint)> {
+        let (_, spans) = self.parse_again_with_spans();
+        let mut all = vec!((0, self.original.len()));
+        all.push_all(spans.as_slice());
+        all
+    }
+
+    /// Returns the index of the capture group named `name`, if there is
+    /// one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::new(r"(\d{4})-(?P<m>\d{2})").unwrap();
+    /// assert_eq!(re.capture_index("m"), Some(2));
+    /// assert_eq!(re.capture_index("y"), None);
+    /// ```
+    pub fn capture_index(&self, name: &str) -> Option<uint> {
+        self.names.iter().position(|n| {
+            n.as_ref().map_or(false, |n| n.as_slice() == name)
+        })
+    }
+
+    /// Returns a listing of the instructions that the regular expression
+    /// is compiled to, one per line, along with any literals used to speed
+    /// up searching. This is only meant for debugging, so the format may
+    /// change at any time.
+    ///
+    /// A native regex (from `regex!`) doesn't keep its instructions around,
+    /// so they are compiled again from the original expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::new("a+b").unwrap();
+    /// println!("{}", re.debug_program());
+    /// // Output:
+    /// // 0000 save 0
+    /// // 0001 char 'a'
+    /// // 0002 split 1, 3
+    /// // 0003 char 'b'
+    /// // 0004 save 1
+    /// // 0005 match
+    /// // prefix: "a"
+    /// // inner: "b"
+    /// ```
+    pub fn debug_program(&self) -> ~str {
+        match self.p {
+            Dynamic(ref prog) => format!("{}", prog),
+            Native(_) => {
+                let (prog, _) = Program::new(self.parse_again(), false);
+                format!("{}", prog)
+            }
+        }
+    }
+
+    /// Returns the instructions that the regular expression is compiled to
+    /// as a graph in the Graphviz dot language, which can be rendered with
+    /// `dot -Tsvg`, for instance. Like `debug_program`, this is only meant
+    /// for debugging (e.g., to see which part of an expression makes the
+    /// program big).
+    ///
+    /// Each instruction is a node. The instructions that consume a character
+    /// are circles, and the edge leaving one is labeled with the character
+    /// (or class) it matches. The edges leaving a split are labeled `1` and
+    /// `2`, in the order they're tried.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let dot = Regex::new("a|b").unwrap().debug_program_dot();
+    /// assert!(dot.starts_with("digraph program {"));
+    /// assert!(dot.contains("1 -> 2 [label=\"1\"];"));
+    /// assert!(dot.contains("2 -> 3 [label=\"char 'a'\"];"));
+    /// ```
+    pub fn debug_program_dot(&self) -> ~str {
+        self.with_program(|prog| prog.to_dot())
+    }
+
+    /// Returns statistics about the program that the regular expression is
+    /// compiled to. They can be used to limit the size or features of
+    /// expressions given by users.
+    ///
+    /// A native regex (from `regex!`) doesn't keep its instructions around,
+    /// so they are compiled again from the original expression. (The
+    /// statistics are those of the same expression compiled with
+    /// `Regex::new`.)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let stats = Regex::new(r"\b(\w+)\b").unwrap().program_stats();
+    /// assert_eq!(stats.slots, 4);
+    /// assert!(stats.word_boundary);
+    /// ```
+    pub fn program_stats(&self) -> ProgramStats {
+        let stats = |prog: &Program| ProgramStats {
+            insts: prog.insts.len(),
+            slots: prog.num_slots(),
+            word_boundary: prog.insts.iter().any(|inst| {
+                match *inst {
+                    EmptyWordBoundary(_) => true,
+                    _ => false,
+                }
+            }),
+            backtrack: prog.backtrack,
+            heap_size: prog.heap_size(),
+        };
+        self.with_program(stats)
+    }
+
+    /// Returns the fewest bytes that a match of the regex can span. Text
+    /// that's shorter can't contain a match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::new(r"\d{3}-\d{4}").unwrap();
+    /// assert_eq!(re.min_match_len(), 8);
+    /// ```
+    pub fn min_match_len(&self) -> uint {
+        self.match_lens().val0()
+    }
+
+    /// Returns the most bytes that a match of the regex can span, or `None`
+    /// if there's no limit (e.g., the regex has a `*`).
+    ///
+    /// A character that the regex doesn't pin down (like one matched by `.`
+    /// or `\w`) is counted as 4 bytes, since that's the most a character
+    /// can take in UTF-8. Lookaround assertions don't count toward a match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// assert_eq!(Regex::new(r"[a-z]{2,3}").unwrap().max_match_len(),
+    ///            Some(3));
+    /// assert_eq!(Regex::new(r"a.").unwrap().max_match_len(), Some(5));
+    /// assert_eq!(Regex::new(r"a+").unwrap().max_match_len(), None);
+    /// ```
+    pub fn max_match_len(&self) -> Option<uint> {
+        self.match_lens().val1()
+    }
+
+    // Returns the minimum and maximum lengths of a match, which are computed
+    // when the program is compiled.
+    fn match_lens(&self) -> (uint, Option<uint>) {
+        self.with_program(|prog| (prog.min_len, prog.max_len))
+    }
+
+    /// Returns the literal strings that the compiler found every match of
+    /// the regex must contain. They're what a search uses to skip ahead
+    /// quickly, and they can also be used to rule out text (or documents in
+    /// an index) before searching it at all.
+    ///
+    /// The compiler only looks for a few kinds of literals, so there may be
+    /// others that it didn't find. (See `Literals` for what each kind
+    /// means.)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::new(r"(foo|bar)\d+@example\.com$").unwrap();
+    /// let lits = re.literals();
+    /// assert_eq!(lits.prefixes, vec!(~"bar", ~"foo"));
+    /// assert_eq!(lits.suffix, ~"@example.com");
+    /// ```
+    pub fn literals(&self) -> Literals {
+        let literals = |prog: &Program| {
+            let prefixes =
+                if prog.prefix.len() > 0 {
+                    vec!(prog.prefix.clone())
+                } else {
+                    match prog.prefix_set {
+                        None => vec!(),
+                        Some(ref ac) => {
+                            let mut lits = Vec::from_slice(ac.patterns());
+                            lits.sort();
+                            lits
+                        }
+                    }
+                };
+            Literals {
+                prefixes: prefixes,
+                inner: prog.inner.clone(),
+                suffix: prog.suffix.clone(),
+            }
+        };
+        self.with_program(literals)
+    }
+
+    /// Returns true if every match of the regex must start at the beginning
+    /// of the text, i.e., the regex begins with `^` (not in multi-line mode)
+    /// or `\A`. Such a regex can only match at the start of a search.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// assert!(Regex::new(r"^abc").unwrap().is_anchored_start());
+    /// assert!(!Regex::new(r"(?m)^abc").unwrap().is_anchored_start());
+    /// ```
+    pub fn is_anchored_start(&self) -> bool {
+        self.with_program(|prog| prog.is_anchored_start())
+    }
+
+    /// Returns true if every match of the regex must end at the end of the
+    /// text, i.e., the regex ends with `$` (not in multi-line mode) or `\z`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// assert!(Regex::new(r"abc$").unwrap().is_anchored_end());
+    /// assert!(!Regex::new(r"abc|d$").unwrap().is_anchored_end());
+    /// ```
+    pub fn is_anchored_end(&self) -> bool {
+        compile::is_anchored_end(&*self.parse_again())
+    }
+
+    /// Returns a description of how a search for the regex is done: which
+    /// engine runs it, how it finds where a match can start and which
+    /// literals it uses to skip text. This is meant to help find out why a
+    /// regex is slow, e.g., when the compiler found no literal prefix to
+    /// skip ahead to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// use regex::{NfaEngine, PrefixScan, FullScan};
+    ///
+    /// let exp = Regex::new(r"foo\d+$").unwrap().explain();
+    /// assert_eq!(exp.engine, NfaEngine);
+    /// assert_eq!(exp.start, PrefixScan);
+    /// assert!(exp.reverse_from_end);
+    /// assert_eq!(exp.literals.prefixes, vec!(~"foo"));
+    ///
+    /// let exp = Regex::new(r"\w+").unwrap().explain();
+    /// assert_eq!(exp.start, FullScan);
+    /// ```
+    pub fn explain(&self) -> Explanation {
+        let engine = match self.p {
+            Dynamic(ref prog) if prog.backtrack => BacktrackEngine,
+            Dynamic(_) => NfaEngine,
+            Native(_) => NativeEngine,
+        };
+        let literals = self.literals();
+        let explain = |prog: &Program| {
+            // Only the VM uses literals other than a single prefix.
+            let nfa = engine == NfaEngine;
+            let start =
+                if prog.anchored_start || prog.is_anchored_start() {
+                    AnchoredScan
+                } else if prog.prefix.len() > 0 {
+                    PrefixScan
+                } else if prog.prefix_set.is_some() && engine != NativeEngine {
+                    PrefixSetScan
+                } else {
+                    FullScan
+                };
+            Explanation {
+                engine: engine,
+                start: start,
+                reverse_from_end: nfa && prog.anchored_end,
+                inner_check: nfa && prog.inner.len() > 0,
+                literals: literals.clone(),
+            }
+        };
+        self.with_program(explain)
+    }
+
+    /// Runs a search for the regex in `text` between byte indices `start`
+    /// and `end`, doing only as much work as `kind` asks for. This is what
+    /// every other search method uses.
+    ///
+    /// Assertions like `^` and `\b` see the text outside of the range, so
+    /// searching for successive matches with a new `start` each time works
+    /// as expected. (`end` must be on a character boundary.)
+    ///
+    /// The locations returned depend on `kind`:
+    ///
+    /// * `Exists` returns `[Some(0), Some(0)]` if there's a match and
+    ///   `[None, None]` otherwise.
+    /// * `Location` returns the start and end byte indices of the
+    ///   leftmost-first match (or `[None, None]`).
+    /// * `Submatches` returns the start and end of every capture group in
+    ///   order, with the entire match first, so there are two for each
+    ///   group. (If `RegexBuilder::alternates` is set, then there's one more
+    ///   location for each top-level alternate after them.) Every location is
+    ///   `None` if there's no match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// use regex::{Exists, Location, Submatches};
+    ///
+    /// let re = regex!(r"(\d+)-(\d+)?");
+    /// let text = "12- 3-4";
+    /// assert_eq!(re.exec(Exists, text, 4, 7), vec![Some(0), Some(0)]);
+    /// assert_eq!(re.exec(Location, text, 1, 7), vec![Some(1), Some(3)]);
+    /// assert_eq!(re.exec(Submatches, text, 3, 7),
+    ///            vec![Some(4), Some(7), Some(4), Some(5), Some(6), Some(7)]);
+    /// # }
+    /// ```
+    pub fn exec(&self, kind: MatchKind, text: &str, start: uint, end: uint)
+               -> Vec<Option<uint>> {
+        exec_slice(self, kind, text, start, end)
     }
 
     /// Returns true if and only if the regex matches the string given.
@@ -169,6 +930,155 @@ impl Regex {
         has_match(&exec(self, Exists, text))
     }
 
+    /// Returns true if and only if the regex matches the string given, like
+    /// `is_match`. But if the backtracking engine takes more steps than
+    /// `RegexBuilder::backtrack_limit` allows, then an error is returned
+    /// instead of reporting that there's no match. (This never happens for
+    /// expressions that don't need backtracking.)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::{RegexBuilder, LimitExceeded};
+    /// let re = RegexBuilder::new(r"(a*)*\1b").backtrack(true)
+    ///                                        .backtrack_limit(1000)
+    ///                                        .build().unwrap();
+    /// let text = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    /// assert_eq!(re.try_is_match(text), Err(LimitExceeded { limit: 1000 }));
+    /// assert_eq!(re.try_is_match("aab"), Ok(true));
+    /// ```
+    pub fn try_is_match(&self, text: &str) -> Result<bool, LimitExceeded> {
+        try_exec(self, Exists, text).map(|caps| has_match(&caps))
+    }
+
+    /// Returns true if and only if the regex matches all of `text`, as if
+    /// it began with `\A` and ended with `\z`.
+    ///
+    /// This isn't the same as checking whether `find` returns the whole
+    /// text, since the leftmost-first match can be shorter than a match of
+    /// the whole text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let re = regex!(r"a|ab");
+    /// assert!(re.is_full_match("ab"));
+    /// assert!(!re.is_full_match("abc"));
+    /// assert_eq!(re.find("ab"), Some((0, 1)));
+    /// # }
+    /// ```
+    pub fn is_full_match(&self, text: &str) -> bool {
+        has_match(&exec_full(self, Exists, text))
+    }
+
+    /// Returns true if and only if the regex matches a suffix of `text`,
+    /// i.e., some match ends at the end of `text`, as if the regex ended
+    /// with `\z`.
+    ///
+    /// The regex is run backwards from the end of `text`, so only as much
+    /// of it is read as a match could span. (The backtracking engine can
+    /// only search forwards, so a regex that needs it searches all of
+    /// `text` instead.)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let re = regex!(r"\.(?:jpe?g|png)");
+    /// assert!(re.is_suffix_match("photo.jpeg"));
+    /// assert!(!re.is_suffix_match("photo.png.txt"));
+    /// # }
+    /// ```
+    pub fn is_suffix_match(&self, text: &str) -> bool {
+        match self.p {
+            Dynamic(ref prog) if prog.backtrack => {
+                let ast = ~parse::Cat(vec!(self.parse_again(),
+                                           ~parse::End(parse::FLAG_EMPTY)));
+                let (mut suffix, _) = Program::new(ast, false);
+                suffix.backtrack_limit = prog.backtrack_limit;
+                let caps = backtrack::run(Exists, &suffix, text, 0, text.len());
+                has_match(&caps)
+            }
+            _ => {
+                let rev = self.reverse_program();
+                // See `rfind_slice`.
+                let mut scratch = match self.p {
+                    Dynamic(ref prog) => self.scratch.get(prog),
+                    Native(_) => self.scratch.get(&*rev),
+                };
+                let start = vm::run_reverse(&*rev, &mut scratch, text,
+                                            0, text.len());
+                self.scratch.put(scratch);
+                start.is_some()
+            }
+        }
+    }
+
+    /// Returns a `Feed`, which matches the regex against text that arrives a
+    /// piece at a time (e.g., as it's typed). After each piece, it says
+    /// whether the regex matches all of the text so far, or could match it
+    /// once more text is added, or neither.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::{Regex, FullMatch, PartialMatch, NoMatch};
+    /// let re = Regex::new(r"\d{3}-\d{4}").unwrap();
+    /// let mut feed = re.feed();
+    /// assert_eq!(feed.push("555"), PartialMatch);
+    /// assert_eq!(feed.push("-1234"), FullMatch);
+    /// assert_eq!(feed.push("5"), NoMatch);
+    /// ```
+    pub fn feed<'r>(&'r self) -> Feed<'r> {
+        let (prog, _) = Program::new(self.parse_again(), false);
+        Feed {
+            re: self,
+            scratch: vm::Scratch::new(&prog),
+            prog: prog,
+            text: StrBuf::new(),
+        }
+    }
+
+    /// Returns a random string that the regex matches all of (see
+    /// `is_full_match`), e.g., for testing. Repetitions without an upper
+    /// bound (like `*` and `+`) repeat at most `max_repeat` times more than
+    /// they must.
+    ///
+    /// A string is built from the parts of the expression, so assertions
+    /// (like `^`, `\b` and lookarounds) can keep it from matching. Then it's
+    /// thrown away and another one is tried, up to a limit. If none of them
+    /// match, then `None` is returned. (For example, `a\bb` never matches
+    /// anything.)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # extern crate rand;
+    /// # fn main() {
+    /// let re = regex!(r"[a-z]{2,4}@\d+\.com");
+    /// let s = re.gen_match(&mut rand::task_rng(), 5).unwrap();
+    /// assert!(re.is_full_match(s.as_slice()));
+    /// # }
+    /// ```
+    pub fn gen_match<R: Rng>(&self, rng: &mut R, max_repeat: uint)
+                            -> Option<~str> {
+        let ast = self.parse_again();
+        for _ in range(0, GEN_MATCH_TRIES) {
+            let s = gen::generate(&*ast, rng, max_repeat);
+            if self.is_full_match(s.as_slice()) {
+                return Some(s)
+            }
+        }
+        None
+    }
+
     /// Returns the start and end byte range of the leftmost-first match in
     /// `text`. If no match exists, then `None` is returned.
     ///
@@ -199,10 +1109,86 @@ impl Regex {
         }
     }
 
+    /// Returns the leftmost-first match in `text` like `find`, or an error
+    /// if the backtracking limit is exceeded (see `try_is_match`).
+    pub fn try_find(&self, text: &str)
+                   -> Result<Option<(uint, uint)>, LimitExceeded> {
+        let caps = try!(try_exec(self, Location, text));
+        if has_match(&caps) {
+            Ok(Some((caps.get(0).unwrap(), caps.get(1).unwrap())))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns true if and only if the regex matches the text made of
+    /// `chunks` joined together (e.g., the pieces of a rope). The chunks
+    /// aren't copied, and assertions like `^`, `$` and `\b` work across
+    /// their boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::new(r"\bfoo bar\b").unwrap();
+    /// assert!(re.is_match_chunks(&["a fo", "o b", "", "ar"]));
+    /// assert!(!re.is_match_chunks(&["a fo", "o b", "", "ars"]));
+    /// ```
+    pub fn is_match_chunks(&self, chunks: &[&str]) -> bool {
+        self.is_match_input(ChunkReader::new(chunks))
+    }
+
+    /// Returns the start and end byte indices of the leftmost-first match in
+    /// the text made of `chunks` joined together, searching from byte index
+    /// `start`. The indices are into the joined text. (See
+    /// `is_match_chunks`.)
+    ///
+    /// Searching chunks skips the optimizations that look for literals in
+    /// the text, so it may be slower than searching a single string. A regex
+    /// that needs the backtracking engine copies the chunks into one string
+    /// first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::new(r"\d+").unwrap();
+    /// let chunks = ["a1", "23 b", "4"];
+    /// assert_eq!(re.find_chunks(&chunks, 0), Some((1, 4)));
+    /// assert_eq!(re.find_chunks(&chunks, 4), Some((6, 7)));
+    /// ```
+    pub fn find_chunks(&self, chunks: &[&str], start: uint)
+                      -> Option<(uint, uint)> {
+        self.find_input(ChunkReader::new(chunks), start)
+    }
+
+    /// Returns true if and only if the regex matches the text read from
+    /// `input`. (See `Input`.) This is how `is_match_chunks` works, and it
+    /// can search any other representation of text the same way.
+    pub fn is_match_input<I: Input>(&self, input: I) -> bool {
+        has_match(&exec_input(self, Exists, input, 0))
+    }
+
+    /// Returns the start and end byte indices of the leftmost-first match in
+    /// the text read from `input`, searching from byte index `start`. (See
+    /// `find_chunks`.)
+    pub fn find_input<I: Input>(&self, input: I, start: uint)
+                               -> Option<(uint, uint)> {
+        let caps = exec_input(self, Location, input, start);
+        if has_match(&caps) {
+            Some((caps.get(0).unwrap(), caps.get(1).unwrap()))
+        } else {
+            None
+        }
+    }
+
     /// Returns an iterator for each successive non-overlapping match in
     /// `text`, returning the start and end byte indices with respect to
     /// `text`.
     ///
+    /// An empty match that immediately follows another match is skipped,
+    /// and the search continues at the next character.
+    ///
     /// # Example
     ///
     /// Find the start and end location of the first word with exactly 13
@@ -229,6 +1215,116 @@ impl Regex {
             search: text,
             last_end: 0,
             last_match: None,
+            bytewise: false,
+        }
+    }
+
+    /// Returns the number of successive non-overlapping matches in `text`.
+    /// The matches counted are the same ones that `find_iter` returns.
+    ///
+    /// This is faster than counting the matches returned by `find_iter`,
+    /// since only the end of each match is used (to know where to search
+    /// next) and the same thread queues are used for every search.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let re = regex!(r"agggtaaa|tttaccct");
+    /// assert_eq!(re.count_matches("agggtaaatttaccctagggtaa"), 2);
+    /// # }
+    /// ```
+    pub fn count_matches(&self, text: &str) -> uint {
+        let mut n = 0;
+        let prog = match self.p {
+            Dynamic(ref prog) if !prog.backtrack => prog,
+            _ => {
+                for _ in self.find_iter(text) {
+                    n += 1;
+                }
+                return n
+            }
+        };
+        let mut scratch = self.scratch.get(prog);
+        let (mut last_end, mut last_match) = (0, None);
+        while last_end <= text.len() {
+            let caps = vm::run_scratch(Location, prog, &mut scratch, text,
+                                       last_end, text.len());
+            if !has_match(&caps) {
+                break
+            }
+            // A match can't start before `last_end`, so it's empty if it
+            // ends there. (See `FindMatches`.)
+            let e = caps.get(1).unwrap();
+            if e == last_end && Some(e) == last_match {
+                last_end = skip_empty(text, e, false);
+                continue
+            }
+            n += 1;
+            last_end = e;
+            last_match = Some(e);
+        }
+        self.scratch.put(scratch);
+        n
+    }
+
+    /// Returns the start and end byte range of the match in `text` that ends
+    /// last. If no match exists, then `None` is returned.
+    ///
+    /// The text is searched backwards from its end, so this is faster than
+    /// looking at every match with `find_iter`. When several matches end at
+    /// the same place, preferences like greediness and the order of
+    /// alternates are applied as if the expression were reading the text
+    /// from right to left. So the match returned can overlap the last match
+    /// returned by `find_iter`, or be longer than it.
+    ///
+    /// An expression that needs the backtracking engine can't be searched
+    /// backwards, so the last match found by searching forwards is returned
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let text = "2011-02-03, 2012-04-05 and 2013-06-07";
+    /// let pos = regex!(r"\d{4}-\d{2}-\d{2}").rfind(text);
+    /// assert_eq!(pos, Some((27, 37)));
+    /// # }
+    /// ```
+    pub fn rfind(&self, text: &str) -> Option<(uint, uint)> {
+        rfind_slice(self, text, text.len())
+    }
+
+    /// Returns an iterator for each successive non-overlapping match in
+    /// `text` found by searching backwards, starting with the match that
+    /// ends last (see `rfind`). It returns the start and end byte indices
+    /// with respect to `text`.
+    ///
+    /// An empty match that immediately precedes another match is skipped,
+    /// and the search continues at the previous character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let text = "a1 b22 c333";
+    /// let got: Vec<(uint, uint)> = regex!(r"\d+").rfind_iter(text).collect();
+    /// assert_eq!(got, vec![(8, 11), (4, 6), (1, 2)]);
+    /// # }
+    /// ```
+    pub fn rfind_iter<'r, 't>(&'r self, text: &'t str)
+                             -> RFindMatches<'r, 't> {
+        RFindMatches {
+            re: self,
+            search: text,
+            last_start: Some(text.len()),
+            last_match: None,
         }
     }
 
@@ -289,6 +1385,161 @@ impl Regex {
         Captures::new(self, text, caps)
     }
 
+    /// Returns the capture groups of the leftmost-first match in `text` like
+    /// `captures`, or an error if the backtracking limit is exceeded (see
+    /// `try_is_match`).
+    pub fn try_captures<'t>(&self, text: &'t str)
+                           -> Result<Option<Captures<'t>>, LimitExceeded> {
+        let caps = try!(try_exec(self, Submatches, text));
+        Ok(Captures::new(self, text, caps))
+    }
+
+    /// Returns the capture groups for the leftmost-first match in `text`
+    /// like `captures`, except only the groups listed in `groups` (by
+    /// index) are found. The others are reported as not matching anything.
+    /// (Indices that aren't valid capture groups are ignored.)
+    ///
+    /// This is faster than `captures` for an expression with many groups,
+    /// since the groups that aren't needed don't have to be tracked while
+    /// searching. Group `0` (the entire match) is always found, and so is
+    /// `Captures::alternate`. Use `capture_index` to find a group by name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let re = regex!(r"(\d{4})-(\d{2})-(?P<d>\d{2})");
+    /// let d = re.capture_index("d").unwrap();
+    /// let caps = re.captures_only("on 2014-05-21", &[d]).unwrap();
+    /// assert_eq!(caps.at(0), "2014-05-21");
+    /// assert_eq!(caps.pos(1), None);
+    /// assert_eq!(caps.name("d"), "21");
+    /// # }
+    /// ```
+    pub fn captures_only<'t>(&self, text: &'t str, groups: &[uint])
+                            -> Option<Captures<'t>> {
+        let mut slots = vec!(0, 1);
+        let ncaps = self.captures_len();
+        for &i in groups.iter() {
+            if i > 0 && i < ncaps {
+                slots.push(2 * i);
+                slots.push(2 * i + 1);
+            }
+        }
+        let alternates = match self.p {
+            Dynamic(ref prog) => prog.alternates,
+            Native(_) => 0,
+        };
+        for i in range(0, alternates) {
+            slots.push(2 * ncaps + i);
+        }
+        slots.sort();
+        slots.dedup();
+
+        let locs = match self.p {
+            Dynamic(ref prog) if !prog.backtrack => {
+                let mut scratch = self.scratch.get(prog);
+                let locs = vm::run_slots(prog, &mut scratch, slots.as_slice(),
+                                         text, 0, text.len());
+                self.scratch.put(scratch);
+                locs
+            }
+            _ => {
+                let mut locs = exec(self, Submatches, text);
+                for (slot, loc) in locs.mut_iter().enumerate() {
+                    if !slots.contains(&slot) {
+                        *loc = None;
+                    }
+                }
+                locs
+            }
+        };
+        Captures::new(self, text, locs)
+    }
+
+    /// Returns the capture groups of a match of all of `text` (see
+    /// `is_full_match`). If the regex doesn't match all of `text`, then
+    /// `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let re = regex!(r"(\w+?)(\d*)");
+    /// let caps = re.full_captures("abc12").unwrap();
+    /// assert_eq!(caps.at(1), "abc");
+    /// assert_eq!(caps.at(2), "12");
+    /// # }
+    /// ```
+    pub fn full_captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
+        let caps = exec_full(self, Submatches, text);
+        Captures::new(self, text, caps)
+    }
+
+    /// Returns the language of this regex: the strings that it matches all
+    /// of (see `is_full_match`). Languages can be intersected and
+    /// complemented, and whether a string is in one is decided in a single
+    /// pass without backtracking (see `Language`).
+    ///
+    /// `None` is returned if the regex has backreferences, lookarounds or
+    /// atomic groups, since those can't be matched by a finite automaton.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let re = Regex::new(r"a+").unwrap();
+    /// let lang = re.language().unwrap();
+    /// assert!(lang.is_match("aaa"));
+    /// assert!(!lang.is_match("aab"));
+    /// ```
+    pub fn language(&self) -> Option<Language> {
+        self.with_program(|prog| language::from_program(prog.clone()))
+    }
+
+    /// Returns the language of the strings that both this regex and `other`
+    /// match all of. This is the same as intersecting their languages (see
+    /// `language`), and `None` is returned if either one has no language.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let word = Regex::new(r"\w+").unwrap();
+    /// let hex = Regex::new(r"[0-9a-fA-F]+").unwrap();
+    /// let lang = word.intersect(&hex).unwrap();
+    /// assert!(lang.is_match("c0ffee"));
+    /// assert!(!lang.is_match("coffee"));
+    /// assert!(!lang.is_match("c0 ffee"));
+    /// ```
+    pub fn intersect(&self, other: &Regex) -> Option<Language> {
+        match (self.language(), other.language()) {
+            (Some(x), Some(y)) => Some(x.intersect(&y)),
+            _ => None,
+        }
+    }
+
+    /// Returns the language of the strings that this regex doesn't match
+    /// all of. This is the same as complementing its language (see
+    /// `language`), and `None` is returned if it has no language.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::Regex;
+    /// let lang = Regex::new(r"a+").unwrap().complement().unwrap();
+    /// assert!(lang.is_match("aab"));
+    /// assert!(lang.is_match(""));
+    /// assert!(!lang.is_match("aaa"));
+    /// ```
+    pub fn complement(&self) -> Option<Language> {
+        self.language().map(|lang| lang.complement())
+    }
+
     /// Returns an iterator over all the non-overlapping capture groups matched
     /// in `text`. This is operationally the same as `find_iter` (except it
     /// yields information about submatches).
@@ -320,6 +1571,7 @@ impl Regex {
             search: text,
             last_match: None,
             last_end: 0,
+            bytewise: false,
         }
     }
 
@@ -350,6 +1602,28 @@ impl Regex {
         }
     }
 
+    /// Returns an iterator of the same substrings as `split`, along with the
+    /// start and end byte indices of each substring in `text`.
+    ///
+    /// This method will *not* copy the text given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let re = regex!(r",\s*");
+    /// let fields: Vec<(&str, uint, uint)> =
+    ///     re.split_with_positions("a, bc,d").collect();
+    /// assert_eq!(fields, vec!(("a", 0, 1), ("bc", 3, 5), ("d", 6, 7)));
+    /// # }
+    /// ```
+    pub fn split_with_positions<'r, 't>(&'r self, text: &'t str)
+                                       -> RegexSplitsPos<'r, 't> {
+        RegexSplitsPos { splits: self.split(text) }
+    }
+
     /// Returns an iterator of at most `limit` substrings of `text` delimited
     /// by a match of the regular expression. (A `limit` of `0` will return no
     /// substrings.)
@@ -382,6 +1656,46 @@ impl Regex {
         }
     }
 
+    /// Returns `text` with every match at its beginning and every match at
+    /// its end removed (see `trim_left_matches` and `trim_right_matches`).
+    /// If they overlap, then an empty string is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let re = regex!(r"\s+|,");
+    /// assert_eq!(re.trim_matches(" a, b ,, "), "a, b");
+    /// # }
+    /// ```
+    pub fn trim_matches<'t>(&self, text: &'t str) -> &'t str {
+        let start = trim_left_index(self, text);
+        let end = trim_right_index(self, text);
+        text.slice(start, cmp::max(start, end))
+    }
+
+    /// Returns `text` with every match at its beginning removed. That is,
+    /// as long as a (non-empty) match starts where the text left so far
+    /// starts, it's removed.
+    ///
+    /// Assertions still see the text that was removed, so `^` only matches
+    /// at the start of `text`.
+    pub fn trim_left_matches<'t>(&self, text: &'t str) -> &'t str {
+        text.slice_from(trim_left_index(self, text))
+    }
+
+    /// Returns `text` with every match at its end removed. That is, as long
+    /// as a (non-empty) match ends where the text left so far ends, it's
+    /// removed. The match removed each time is the one `rfind` finds.
+    ///
+    /// Assertions still see the text that was removed, so `$` only matches
+    /// at the end of `text`.
+    pub fn trim_right_matches<'t>(&self, text: &'t str) -> &'t str {
+        text.slice_to(trim_right_index(self, text))
+    }
+
     /// Replaces the leftmost-first match with the replacement provided.
     /// The replacement can be a regular string (where `$N` and `$name` are
     /// expanded to match capture groups) or a function that takes the matches'
@@ -474,10 +1788,79 @@ impl Regex {
     /// See the documentation for `replace` for details on how to access
     /// submatches in the replacement string.
     pub fn replacen<R: Replacer>
-                   (&self, text: &str, limit: uint, mut rep: R) -> StrBuf {
-        let mut new = StrBuf::with_capacity(text.len());
+                   (&self, text: &str, limit: uint, rep: R) -> StrBuf {
+        match self.replacen_cow(text, limit, rep) {
+            Slice(text) => StrBuf::from_str(text),
+            Owned(new) => StrBuf::from_owned_str(new),
+        }
+    }
+
+    /// The same as `replace`, except the text given is borrowed instead of
+    /// copied when there is no match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// use std::str::Slice;
+    ///
+    /// let re = regex!(r"\d+");
+    /// assert_eq!(re.replace_cow("age: 26", "N").as_slice(), "age: N");
+    /// match re.replace_cow("no digits", "N") {
+    ///     Slice(text) => assert_eq!(text, "no digits"),
+    ///     _ => fail!("the text should not have been copied"),
+    /// }
+    /// # }
+    /// ```
+    pub fn replace_cow<'t, R: Replacer>(&self, text: &'t str, rep: R)
+                                       -> MaybeOwned<'t> {
+        self.replacen_cow(text, 1, rep)
+    }
+
+    /// The same as `replace_all`, except the text given is borrowed instead
+    /// of copied when there is no match.
+    pub fn replace_all_cow<'t, R: Replacer>(&self, text: &'t str, rep: R)
+                                           -> MaybeOwned<'t> {
+        self.replacen_cow(text, 0, rep)
+    }
+
+    /// The same as `replacen`, except the text given is borrowed instead of
+    /// copied when there is no match.
+    pub fn replacen_cow<'t, R: Replacer>
+                       (&self, text: &'t str, limit: uint, mut rep: R)
+                       -> MaybeOwned<'t> {
+        let mut new = StrBuf::new();
         let mut last_match = 0u;
         let mut i = 0;
+
+        // If the replacement doesn't need any capture groups, then only the
+        // location of each match is needed, which is cheaper to find.
+        match rep.no_expansion() {
+            None => {}
+            Some(rep) => {
+                for (s, e) in self.find_iter(text) {
+                    if limit > 0 && i >= limit {
+                        break
+                    }
+                    i += 1;
+
+                    if i == 1 {
+                        new.reserve(text.len());
+                    }
+                    new.push_str(text.slice(last_match, s));
+                    new.push_str(rep.as_slice());
+                    last_match = e;
+                }
+                if i == 0 {
+                    return Slice(text)
+                }
+                new.push_str(text.slice(last_match, text.len()));
+                return Owned(new.into_owned())
+            }
+        }
+
         for cap in self.captures_iter(text) {
             // It'd be nicer to use the 'take' iterator instead, but it seemed
             // awkward given that '0' => no limit.
@@ -486,15 +1869,229 @@ impl Regex {
             }
             i += 1;
 
+            if i == 1 {
+                new.reserve(text.len());
+            }
             let (s, e) = cap.pos(0).unwrap(); // captures only reports matches
             new.push_str(text.slice(last_match, s));
             new.push_str(rep.reg_replace(&cap).as_slice());
             last_match = e;
         }
-        new.append(text.slice(last_match, text.len()))
+        if i == 0 {
+            return Slice(text)
+        }
+        new.push_str(text.slice(last_match, text.len()));
+        Owned(new.into_owned())
+    }
+
+    // Calls `f` with the program that the regex is compiled to. (A native
+    // regex has no program, so it's compiled just for this.)
+    fn with_program<T>(&self, f: |&Program| -> T) -> T {
+        match self.p {
+            Dynamic(ref prog) => f(prog),
+            Native(_) => {
+                let (prog, _) = Program::new(self.parse_again(), false);
+                f(&prog)
+            }
+        }
+    }
+
+    // Parses the expression again, with the flags it was compiled with.
+    fn parse_again(&self) -> ~parse::Ast {
+        let (ast, _) = self.parse_again_with_spans();
+        ast
+    }
+
+    // Parses the expression again like `parse_again`, and also returns the
+    // spans of its capture groups (see `parse::parse_with_spans`).
+    fn parse_again_with_spans(&self) -> (~parse::Ast, Vec<(uint, uint)>) {
+        let (flags, _, _) = self.options();
+        // The expression was already parsed successfully once.
+        parse::parse_with_spans(self.original.as_slice(), flags).unwrap()
+    }
+
+    // Returns the program that searches backwards, compiling it if this is
+    // the first time it's needed.
+    fn reverse_program(&self) -> Arc<Program> {
+        self.reverse.get(|| {
+            Program::new_reverse(simplify::simplify(self.parse_again()))
+        })
+    }
+
+    // Returns the program that only matches all of the text, compiling it if
+    // this is the first time it's needed.
+    fn full_program(&self) -> Arc<Program> {
+        self.full.get(|| {
+            let (flags, limit, alternates) = self.options();
+            let (mut prog, _) = Program::new(anchor_both(self.parse_again()),
+                                             alternates > 0);
+            prog.backtrack_limit = limit;
+            prog.flags = flags;
+            prog
+        })
+    }
+
+    // Returns the options that the expression was compiled with. Along with
+    // the original expression, they identify a regex.
+    fn options(&self) -> (parse::Flags, uint, uint) {
+        match self.p {
+            Dynamic(ref prog) =>
+                (prog.flags, prog.backtrack_limit, prog.alternates),
+            // `regex!` always uses the default options.
+            Native(_) => (parse::FLAG_UNICODE, DEFAULT_BACKTRACK_LIMIT, 0),
+        }
+    }
+}
+
+/// Statistics about the program that a regular expression is compiled to,
+/// as returned by `Regex::program_stats`.
+#[deriving(Clone, Eq, Show)]
+pub struct ProgramStats {
+    /// The number of instructions in the program.
+    pub insts: uint,
+    /// The number of slots used to record where capture groups match. There
+    /// are two for every capture group (including the whole match), plus
+    /// one for every top-level alternate if `RegexBuilder::alternates` is
+    /// set.
+    pub slots: uint,
+    /// Whether the expression has a word boundary assertion (`\b` or `\B`).
+    pub word_boundary: bool,
+    /// Whether the expression is run by the backtracking engine.
+    pub backtrack: bool,
+    /// The approximate number of bytes that the program uses on the heap.
+    /// This doesn't include the memory used while searching, which grows
+    /// with the number of instructions and slots.
+    pub heap_size: uint,
+}
+
+/// A description of how a search for a regular expression is done, as
+/// returned by `Regex::explain`.
+#[deriving(Clone, Eq, Show)]
+pub struct Explanation {
+    /// The engine that runs the search.
+    pub engine: Engine,
+    /// How the search finds where a match can start.
+    pub start: StartScan,
+    /// Whether every match must end at the end of the text, so the search
+    /// runs the regex backwards from there to find where a match starts.
+    /// (The text is first checked to end with `literals.suffix`.)
+    pub reverse_from_end: bool,
+    /// Whether the search looks for `literals.inner` before anything else.
+    /// If it isn't in the text, then there's no match.
+    pub inner_check: bool,
+    /// The literals that the compiler found.
+    pub literals: Literals,
+}
+
+/// The engines that run a search, as reported by `Regex::explain`.
+#[deriving(Clone, Eq, Show)]
+pub enum Engine {
+    /// The VM, which simulates every path through the program at once.
+    /// It's the only engine that uses every kind of literal.
+    NfaEngine,
+    /// The backtracking engine, which is used when the regex has
+    /// constructs that need it (or `RegexBuilder::backtrack` is set).
+    BacktrackEngine,
+    /// The code generated by `regex!`, which is like the VM, but only uses
+    /// a literal prefix.
+    NativeEngine,
+}
+
+/// The ways a search finds where a match can start, as reported by
+/// `Regex::explain`.
+#[deriving(Clone, Eq, Show)]
+pub enum StartScan {
+    /// A match can only start where the search starts, e.g., the regex
+    /// begins with `^`.
+    AnchoredScan,
+    /// The search skips ahead to each occurrence of the literal prefix.
+    PrefixScan,
+    /// The search skips ahead to each occurrence of any of the literal
+    /// prefixes, with an Aho-Corasick automaton.
+    PrefixSetScan,
+    /// A match is tried at every position in the text.
+    FullScan,
+}
+
+/// The literal strings that every match of a regular expression must
+/// contain, as returned by `Regex::literals`. Each is empty if the compiler
+/// didn't find one.
+#[deriving(Clone, Eq, Show)]
+pub struct Literals {
+    /// Every match starts with one of these strings (sorted).
+    pub prefixes: Vec<~str>,
+    /// Every match contains this string somewhere after its beginning.
+    pub inner: ~str,
+    /// Every match ends with this string, at the end of the text.
+    pub suffix: ~str,
+}
+
+/// Feed matches a regex against text that arrives a piece at a time. (See
+/// `Regex::feed`.)
+///
+/// Each time the state is checked, all of the text so far is searched, so
+/// this is meant for short text like a line of input.
+///
+/// `'r` is the lifetime of the compiled expression.
+pub struct Feed<'r> {
+    re: &'r Regex,
+    // The program that's run to find out whether a match could go on. (A
+    // native regex has no program of its own.)
+    prog: Program,
+    scratch: vm::Scratch,
+    text: StrBuf,
+}
+
+impl<'r> Feed<'r> {
+    /// Adds `text` to the end of the text so far, and returns the state of
+    /// the match against all of it.
+    pub fn push(&mut self, text: &str) -> FeedState {
+        self.text.push_str(text);
+        self.state()
+    }
+
+    /// Returns the state of the match against the text so far.
+    ///
+    /// A regex that needs the backtracking engine can't tell whether a match
+    /// could go on, so it's always `PartialMatch` when it isn't a
+    /// `FullMatch`.
+    pub fn state(&mut self) -> FeedState {
+        let text = self.text.as_slice();
+        if self.re.is_full_match(text) {
+            FullMatch
+        } else if self.prog.backtrack
+                  || vm::hits_end(&self.prog, &mut self.scratch,
+                                  text, 0, text.len()) {
+            PartialMatch
+        } else {
+            NoMatch
+        }
+    }
+
+    /// Returns the text so far.
+    pub fn text<'a>(&'a self) -> &'a str {
+        self.text.as_slice()
+    }
+
+    /// Removes all of the text so far, to start over.
+    pub fn clear(&mut self) {
+        self.text.truncate(0)
     }
 }
 
+/// The state of a match against text that may not be complete, as returned
+/// by `Feed::push`.
+#[deriving(Clone, Eq, Show)]
+pub enum FeedState {
+    /// The regex matches all of the text.
+    FullMatch,
+    /// The regex doesn't match all of the text, but it might once more text
+    /// is added.
+    PartialMatch,
+    /// The regex can't match all of the text, whatever is added to it.
+    NoMatch,
+}
+
 /// NoExpand indicates literal string replacement.
 ///
 /// It can be used with `replace` and `replace_all` to do a literal
@@ -512,6 +2109,15 @@ pub trait Replacer {
     /// The `'a` lifetime refers to the lifetime of a borrowed string when
     /// a new owned string isn't needed (e.g., for `NoExpand`).
     fn reg_replace<'a>(&'a mut self, caps: &Captures) -> MaybeOwned<'a>;
+
+    /// Returns the replacement for every match if it never depends on the
+    /// capture groups of the match. When it doesn't, replacing can skip
+    /// finding the capture groups.
+    ///
+    /// The default implementation returns `None`.
+    fn no_expansion<'a>(&'a mut self) -> Option<MaybeOwned<'a>> {
+        None
+    }
 }
 
 impl<'t> Replacer for NoExpand<'t> {
@@ -519,12 +2125,26 @@ impl<'t> Replacer for NoExpand<'t> {
         let NoExpand(s) = *self;
         Slice(s)
     }
+
+    fn no_expansion<'a>(&'a mut self) -> Option<MaybeOwned<'a>> {
+        let NoExpand(s) = *self;
+        Some(Slice(s))
+    }
 }
 
 impl<'t> Replacer for &'t str {
     fn reg_replace<'a>(&'a mut self, caps: &Captures) -> MaybeOwned<'a> {
         Owned(caps.expand(*self).into_owned())
     }
+
+    fn no_expansion<'a>(&'a mut self) -> Option<MaybeOwned<'a>> {
+        // Without a `$`, there's nothing to expand.
+        if self.contains_char('$') {
+            None
+        } else {
+            Some(Slice(*self))
+        }
+    }
 }
 
 impl<'a> Replacer for |&Captures|: 'a -> ~str {
@@ -564,6 +2184,23 @@ impl<'r, 't> Iterator<&'t str> for RegexSplits<'r, 't> {
     }
 }
 
+/// Yields all substrings delimited by a regular expression match, along with
+/// the start and end byte indices of each substring.
+///
+/// `'r` is the lifetime of the compiled expression and `'t` is the lifetime
+/// of the string being split.
+pub struct RegexSplitsPos<'r, 't> {
+    splits: RegexSplits<'r, 't>,
+}
+
+impl<'r, 't> Iterator<(&'t str, uint, uint)> for RegexSplitsPos<'r, 't> {
+    fn next(&mut self) -> Option<(&'t str, uint, uint)> {
+        // Each substring starts where the previous match ended.
+        let start = self.splits.last;
+        self.splits.next().map(|s| (s, start, start + s.len()))
+    }
+}
+
 /// Yields at most `N` substrings delimited by a regular expression match.
 ///
 /// The last substring will be whatever remains after splitting.
@@ -600,6 +2237,9 @@ impl<'r, 't> Iterator<&'t str> for RegexSplitsN<'r, 't> {
 /// via the `name` method. (Note that the 0th capture is always unnamed and so
 /// must be accessed with the `at` method.)
 ///
+/// Captures can also be indexed by number or name, e.g., `caps[1]` or
+/// `caps["name"]`, which do the same as `at` and `name`.
+///
 /// Positions returned from a capture group are always byte indices.
 ///
 /// `'t` is the lifetime of the matched text.
@@ -607,15 +2247,30 @@ pub struct Captures<'t> {
     text: &'t str,
     locs: CaptureLocs,
     named: Option<HashMap<~str, uint>>,
+    alternate: Option<uint>,
 }
 
 impl<'t> Captures<'t> {
-    fn new(re: &Regex, search: &'t str, locs: CaptureLocs)
+    fn new(re: &Regex, search: &'t str, mut locs: CaptureLocs)
           -> Option<Captures<'t>> {
         if !has_match(&locs) {
             return None
         }
 
+        // The slots for alternates (if any) follow the capture slots, and
+        // only the one for the alternate that matched is set.
+        let alternate =
+            match re.p {
+                Dynamic(ref prog) if prog.alternates > 0 => {
+                    let first = locs.len() - prog.alternates;
+                    let alt = locs.slice_from(first).iter()
+                                  .position(|loc| loc.is_some());
+                    locs.truncate(first);
+                    alt
+                }
+                _ => None,
+            };
+
         let named =
             if re.names.len() == 0 {
                 None
@@ -635,6 +2290,7 @@ impl<'t> Captures<'t> {
             text: search,
             locs: locs,
             named: named,
+            alternate: alternate,
         })
     }
 
@@ -668,17 +2324,40 @@ impl<'t> Captures<'t> {
     /// If `name` isn't a valid capture group or didn't match anything, then
     /// the empty string is returned.
     pub fn name(&self, name: &str) -> &'t str {
-        match self.named {
+        match self.pos_named(name) {
             None => "",
+            Some((s, e)) => self.text.slice(s, e),
+        }
+    }
+
+    /// Returns the start and end positions of the capture group named
+    /// `name`. Returns `None` if `name` isn't a valid capture group or if
+    /// the capture group did not match anything.
+    /// The positions returned are *always* byte indices with respect to the
+    /// original string matched.
+    pub fn pos_named(&self, name: &str) -> Option<(uint, uint)> {
+        match self.named {
+            None => None,
             Some(ref h) => {
                 match h.find_equiv(&name) {
-                    None => "",
-                    Some(i) => self.at(*i),
+                    None => None,
+                    Some(i) => self.pos(*i),
                 }
             }
         }
     }
 
+    /// Returns the index of the top-level alternate that matched, starting
+    /// at `0` for the leftmost one. (e.g., `POST` is alternate `1` in
+    /// `GET|POST|PUT`.)
+    ///
+    /// This is only available if the expression was compiled with
+    /// `RegexBuilder::alternates` enabled. Otherwise, `None` is returned.
+    /// If the expression isn't an alternation, then `Some(0)` is returned.
+    pub fn alternate(&self) -> Option<uint> {
+        self.alternate
+    }
+
     /// Creates an iterator of all the capture groups in order of appearance
     /// in the regular expression.
     pub fn iter(&'t self) -> SubCaptures<'t> {
@@ -692,6 +2371,33 @@ impl<'t> Captures<'t> {
         SubCapturesPos { idx: 0, caps: self, }
     }
 
+    /// Creates an iterator of the names of all the named capture groups in
+    /// order of appearance in the regular expression. Each one comes with
+    /// the string it matched, or `None` if the group didn't match anything.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(phase)]
+    /// # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+    /// # fn main() {
+    /// let re = regex!(r"(?P<y>\d{4})-(\d{2})(?P<d>-\d{2})?");
+    /// let caps = re.captures("2014-05").unwrap();
+    /// let named: Vec<(&str, Option<&str>)> = caps.iter_named().collect();
+    /// assert_eq!(named, vec![("y", Some("2014")), ("d", None)]);
+    /// # }
+    /// ```
+    pub fn iter_named(&'t self) -> SubCapturesNamed<'t> {
+        let mut names: Vec<(uint, &'t str)> = match self.named {
+            None => vec!(),
+            Some(ref h) => {
+                h.iter().map(|(name, &i)| (i, name.as_slice())).collect()
+            }
+        };
+        names.sort();
+        SubCapturesNamed { names: names.move_iter(), caps: self, }
+    }
+
     /// Expands all instances of `$name` in `text` to the corresponding capture
     /// group `name`.
     ///
@@ -720,6 +2426,22 @@ impl<'t> Captures<'t> {
     }
 }
 
+/// Gets the matched string for a capture group by index, the same as
+/// `Captures::at`. (So `caps[1]` is the same as `caps.at(1)`.)
+impl<'t> Index<uint, &'t str> for Captures<'t> {
+    fn index(&self, i: &uint) -> &'t str {
+        self.at(*i)
+    }
+}
+
+/// Gets the matched string for a capture group by name, the same as
+/// `Captures::name`. (So `caps["y"]` is the same as `caps.name("y")`.)
+impl<'t, 'a> Index<&'a str, &'t str> for Captures<'t> {
+    fn index(&self, name: & &'a str) -> &'t str {
+        self.name(*name)
+    }
+}
+
 impl<'t> Container for Captures<'t> {
     /// Returns the number of captured groups.
     #[inline]
@@ -770,6 +2492,25 @@ impl<'t> Iterator<Option<(uint, uint)>> for SubCapturesPos<'t> {
     }
 }
 
+/// An iterator over the named capture groups for a particular match of a
+/// regular expression. It yields each group's name and the string it
+/// matched (if it matched anything).
+///
+/// `'t` is the lifetime of the matched text.
+pub struct SubCapturesNamed<'t> {
+    names: vec::MoveItems<(uint, &'t str)>,
+    caps: &'t Captures<'t>,
+}
+
+impl<'t> Iterator<(&'t str, Option<&'t str>)> for SubCapturesNamed<'t> {
+    fn next(&mut self) -> Option<(&'t str, Option<&'t str>)> {
+        let text = self.caps.text;
+        self.names.next().map(|(i, name)| {
+            (name, self.caps.pos(i).map(|(s, e)| text.slice(s, e)))
+        })
+    }
+}
+
 /// An iterator that yields all non-overlapping capture groups matching a
 /// particular regular expression. The iterator stops when no more matches can
 /// be found.
@@ -781,6 +2522,20 @@ pub struct FindCaptures<'r, 't> {
     search: &'t str,
     last_match: Option<uint>,
     last_end: uint,
+    bytewise: bool,
+}
+
+impl<'r, 't> FindCaptures<'r, 't> {
+    /// Makes the iterator skip a single byte (rather than a whole character)
+    /// when it rejects an empty match that immediately follows a match.
+    ///
+    /// This is how earlier versions behaved. It can start the next search
+    /// in the middle of a character, so it should only be used for
+    /// compatibility.
+    pub fn bytewise_empty(mut self) -> FindCaptures<'r, 't> {
+        self.bytewise = true;
+        self
+    }
 }
 
 impl<'r, 't> Iterator<Captures<'t>> for FindCaptures<'r, 't> {
@@ -801,7 +2556,8 @@ impl<'r, 't> Iterator<Captures<'t>> for FindCaptures<'r, 't> {
         // Don't accept empty matches immediately following a match.
         // i.e., no infinite loops please.
         if e - s == 0 && Some(self.last_end) == self.last_match {
-            self.last_end += 1;
+            self.last_end = skip_empty(self.search, self.last_end,
+                                       self.bytewise);
             return self.next()
         }
         self.last_end = e;
@@ -823,6 +2579,20 @@ pub struct FindMatches<'r, 't> {
     search: &'t str,
     last_match: Option<uint>,
     last_end: uint,
+    bytewise: bool,
+}
+
+impl<'r, 't> FindMatches<'r, 't> {
+    /// Makes the iterator skip a single byte (rather than a whole character)
+    /// when it rejects an empty match that immediately follows a match.
+    ///
+    /// This is how earlier versions behaved. It can start the next search
+    /// in the middle of a character, so it should only be used for
+    /// compatibility.
+    pub fn bytewise_empty(mut self) -> FindMatches<'r, 't> {
+        self.bytewise = true;
+        self
+    }
 }
 
 impl<'r, 't> Iterator<(uint, uint)> for FindMatches<'r, 't> {
@@ -843,7 +2613,8 @@ impl<'r, 't> Iterator<(uint, uint)> for FindMatches<'r, 't> {
         // Don't accept empty matches immediately following a match.
         // i.e., no infinite loops please.
         if e - s == 0 && Some(self.last_end) == self.last_match {
-            self.last_end += 1;
+            self.last_end = skip_empty(self.search, self.last_end,
+                                       self.bytewise);
             return self.next()
         }
         self.last_end = e;
@@ -852,14 +2623,250 @@ impl<'r, 't> Iterator<(uint, uint)> for FindMatches<'r, 't> {
     }
 }
 
+/// An iterator over all non-overlapping matches for a particular string,
+/// found by searching backwards from the end of the string.
+///
+/// The iterator yields a tuple of integers corresponding to the start and end
+/// of the match. The indices are byte offsets. The iterator stops when no more
+/// matches can be found.
+///
+/// `'r` is the lifetime of the compiled expression and `'t` is the lifetime
+/// of the matched string.
+pub struct RFindMatches<'r, 't> {
+    re: &'r Regex,
+    search: &'t str,
+    last_match: Option<uint>,
+    last_start: Option<uint>,
+}
+
+impl<'r, 't> Iterator<(uint, uint)> for RFindMatches<'r, 't> {
+    fn next(&mut self) -> Option<(uint, uint)> {
+        let end = match self.last_start {
+            None => return None,
+            Some(end) => end,
+        };
+        let (s, e) = match rfind_slice(self.re, self.search, end) {
+            None => return None,
+            Some(m) => m,
+        };
+
+        // Don't accept empty matches immediately preceding a match.
+        if e - s == 0 && Some(end) == self.last_match {
+            self.last_start =
+                if end == 0 {
+                    None
+                } else {
+                    Some(self.search.char_range_at_reverse(end).next)
+                };
+            return self.next()
+        }
+        self.last_start = Some(s);
+        self.last_match = Some(s);
+        Some((s, e))
+    }
+}
+
+// Returns the match in `text` that ends last, but no later than `end`.
+fn rfind_slice(re: &Regex, text: &str, end: uint) -> Option<(uint, uint)> {
+    match re.p {
+        // The backtracking engine can only search forwards, so find every
+        // match before `end` and keep the last one.
+        Dynamic(ref prog) if prog.backtrack => {
+            let (mut last, mut start) = (None, 0);
+            while start <= end {
+                let caps = backtrack::run(Location, prog, text, start, end);
+                if !has_match(&caps) {
+                    break
+                }
+                let (s, e) = (caps.get(0).unwrap(), caps.get(1).unwrap());
+                last = Some((s, e));
+                start = if s == e { skip_empty(text, e, false) } else { e };
+            }
+            last
+        }
+        _ => {
+            let rev = re.reverse_program();
+            // The scratch memory for a dynamic regex must stay big enough
+            // for its forward program. (A native regex has no other use for
+            // it.)
+            let mut scratch = match re.p {
+                Dynamic(ref prog) => re.scratch.get(prog),
+                Native(_) => re.scratch.get(&*rev),
+            };
+            let m = vm::rfind(&*rev, &mut scratch, text, 0, end);
+            re.scratch.put(scratch);
+            m
+        }
+    }
+}
+
+// Returns where the text left after removing matches from the beginning of
+// `text` starts.
+fn trim_left_index(re: &Regex, text: &str) -> uint {
+    let mut start = 0;
+    while start < text.len() {
+        let caps = exec_slice(re, Location, text, start, text.len());
+        match (*caps.get(0), *caps.get(1)) {
+            (Some(s), Some(e)) if s == start && e > s => start = e,
+            _ => break,
+        }
+    }
+    start
+}
+
+// Returns where the text left after removing matches from the end of `text`
+// ends.
+fn trim_right_index(re: &Regex, text: &str) -> uint {
+    let mut end = text.len();
+    while end > 0 {
+        match rfind_slice(re, text, end) {
+            Some((s, e)) if e == end && s < e => end = s,
+            _ => break,
+        }
+    }
+    end
+}
+
+// Returns where to search next after rejecting an empty match at `i`. This
+// is the start of the next character (or just the next byte if `bytewise`
+// is true). It's past the end of `text` if `i` is at the end.
+fn skip_empty(text: &str, i: uint, bytewise: bool) -> uint {
+    if bytewise || i >= text.len() {
+        i + 1
+    } else {
+        text.char_range_at(i).next
+    }
+}
+
+// Returns the first match of `re` in `text` that starts at or after
+// `start`, skipping an empty match at the end of the last match (like
+// `find_iter`).
+fn next_match(re: &Regex, text: &str, start: uint, last_match: Option<uint>)
+             -> Option<(uint, uint)> {
+    let mut start = start;
+    while start <= text.len() {
+        let locs = exec_slice(re, Location, text, start, text.len());
+        if !has_match(&locs) {
+            return None
+        }
+        let (s, e) = (locs.get(0).unwrap(), locs.get(1).unwrap());
+        if s == e && Some(s) == last_match {
+            start = skip_empty(text, s, false);
+            continue
+        }
+        return Some((s, e))
+    }
+    None
+}
+
+// Adds `offset` to the index of every capture group and backreference in
+// the expression given, so that it can follow an expression with that many
+// groups. Group names that are in `taken` are dropped.
+fn shift_captures(ast: ~parse::Ast, offset: uint, taken: &[Option<~str>])
+                 -> ~parse::Ast {
+    let shift = |x| shift_captures(x, offset, taken);
+    match ast {
+        ~parse::Capture(i, name, x) => {
+            let name = if taken.contains(&name) { None } else { name };
+            ~parse::Capture(i + offset, name, shift(x))
+        }
+        ~parse::Backref(i, flags) => ~parse::Backref(i + offset, flags),
+        ~parse::Lookahead(x, flags) => ~parse::Lookahead(shift(x), flags),
+        ~parse::Lookbehind(x, flags) => ~parse::Lookbehind(shift(x), flags),
+        ~parse::Atomic(x) => ~parse::Atomic(shift(x)),
+        ~parse::Rep(x, rep, greed) => ~parse::Rep(shift(x), rep, greed),
+        ~parse::Cat(xs) => ~parse::Cat(xs.move_iter().map(shift).collect()),
+        ~parse::Alt(x, y) => ~parse::Alt(shift(x), shift(y)),
+        ast => ast,
+    }
+}
+
+// Returns the expression given with `\A` and `\z` around it. They're added
+// to each of its top-level alternates, so that the same alternates are
+// reported by `Captures::alternate`.
+fn anchor_both(ast: ~parse::Ast) -> ~parse::Ast {
+    match ast {
+        ~parse::Alt(x, y) => ~parse::Alt(anchor_both(x), anchor_both(y)),
+        ast => ~parse::Cat(vec!(~parse::Begin(parse::FLAG_EMPTY), ast,
+                                ~parse::End(parse::FLAG_EMPTY))),
+    }
+}
+
+// Runs the program that only matches all of `input`.
+fn exec_full(re: &Regex, which: MatchKind, input: &str) -> CaptureLocs {
+    let prog = re.full_program();
+    if prog.backtrack {
+        backtrack::run(which, &*prog, input, 0, input.len())
+    } else {
+        vm::run(which, &*prog, input, 0, input.len())
+    }
+}
+
 fn exec(re: &Regex, which: MatchKind, input: &str) -> CaptureLocs {
     exec_slice(re, which, input, 0, input.len())
 }
 
+// Runs the regex like `exec`, except that exceeding the backtracking limit
+// is an error instead of no match.
+fn try_exec(re: &Regex, which: MatchKind, input: &str)
+           -> Result<CaptureLocs, LimitExceeded> {
+    match re.p {
+        Dynamic(ref prog) if prog.backtrack => {
+            match backtrack::try_run(which, prog, input, 0, input.len()) {
+                Some(caps) => Ok(caps),
+                None => Err(LimitExceeded { limit: prog.backtrack_limit }),
+            }
+        }
+        _ => Ok(exec(re, which, input)),
+    }
+}
+
+fn exec_input<I: Input>(re: &Regex, which: MatchKind,
+                        mut input: I, start: uint) -> CaptureLocs {
+    let len = input.len();
+    match re.p {
+        // The backtracking engine only reads strings, so copy the text into
+        // one.
+        Dynamic(ref prog) if prog.backtrack => {
+            let mut text = StrBuf::with_capacity(len);
+            input.set(0);
+            loop {
+                match input.cur() {
+                    None => break,
+                    Some(c) => text.push_char(c),
+                }
+                input.advance();
+            }
+            backtrack::run(which, prog, text.as_slice(), start, len)
+        }
+        Dynamic(ref prog) => {
+            let mut scratch = re.scratch.get(prog);
+            let caps = vm::run_input(which, prog, &mut scratch,
+                                     input, start, len);
+            re.scratch.put(scratch);
+            caps
+        }
+        // The code generated for a native regex only reads strings, so
+        // compile it to a program that the VM can run.
+        Native(_) => {
+            let (prog, _) = Program::new(re.parse_again(), false);
+            let mut scratch = vm::Scratch::new(&prog);
+            vm::run_input(which, &prog, &mut scratch, input, start, len)
+        }
+    }
+}
+
 fn exec_slice(re: &Regex, which: MatchKind,
               input: &str, s: uint, e: uint) -> CaptureLocs {
     match re.p {
-        Dynamic(ref prog) => vm::run(which, prog, input, s, e),
+        Dynamic(ref prog) if prog.backtrack =>
+            backtrack::run(which, prog, input, s, e),
+        Dynamic(ref prog) => {
+            let mut scratch = re.scratch.get(prog);
+            let caps = vm::run_scratch(which, prog, &mut scratch, input, s, e);
+            re.scratch.put(scratch);
+            caps
+        }
         Native(exec) => exec(which, input, s, e),
     }
 }
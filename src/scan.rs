@@ -0,0 +1,285 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Splitting text into tokens, like a lexer does.
+//!
+//! A `Scanner` is compiled from a list of patterns. It matches them all at
+//! once, but only at a given position in the text (as if each pattern began
+//! with `\A` there), and reports which pattern matched and where the match
+//! ends. So a lexer can read one token after another without searching the
+//! rest of the text each time.
+//!
+//! When more than one pattern matches, the first one in the list wins, even
+//! if a later one matches more text. (Its match is the one it would find on
+//! its own, so `a+` matches as many `a`s as it can.) So keywords should be
+//! listed before identifiers, and end with `\b` so that they don't match
+//! the start of a longer identifier.
+//!
+//! # Example
+//!
+//! ```rust
+//! use regex::scan::Scanner;
+//!
+//! let scanner = Scanner::new(&[r"\d+", r"[a-z]+", r"\s+"]).unwrap();
+//! let tokens: Vec<(uint, uint, uint)> = scanner.tokens("ab 12").collect();
+//! assert_eq!(tokens, vec![(1, 0, 2), (2, 2, 3), (0, 3, 5)]);
+//! ```
+//!
+//! A `Lexer` pairs each pattern with a token of any type, and reports the
+//! token instead of the pattern's number. The `lexer!` macro (in the
+//! `regex_macros` crate) checks and compiles the patterns when your program
+//! is compiled:
+//!
+//! ```rust
+//! # #![feature(phase)]
+//! # extern crate regex; #[phase(syntax)] extern crate regex_macros;
+//! # fn main() {
+//! #[deriving(Eq, Show)]
+//! enum Token { Num, Ident, Space }
+//!
+//! let lexer = lexer!(r"\d+" => Num, r"[a-z]+" => Ident, r"\s+" => Space);
+//! let tokens: Vec<(&Token, &str)> = lexer.lex("ab 12").collect();
+//! assert_eq!(tokens, vec![(&Ident, "ab"), (&Space, " "), (&Num, "12")]);
+//! # }
+//! ```
+
+use compile::Program;
+use parse;
+use parse::{Cat, Alt};
+use vm;
+use vm::Submatches;
+
+/// Scanner matches a list of patterns at a single position in the text.
+/// (See the module documentation.)
+///
+/// Like a `Regex`, a `Scanner` is `Send` and `Share`.
+pub struct Scanner {
+    /// The representation of `Scanner` is exported to support the `lexer!`
+    /// syntax extension. Do not rely on it.
+    #[doc(hidden)]
+    pub patterns: ~[~str],
+    #[doc(hidden)]
+    pub prog: Program,
+    #[doc(hidden)]
+    pub scratch: vm::ScratchPool,
+}
+
+impl Scanner {
+    /// Compiles a scanner for the patterns given, which are numbered from
+    /// `0` in the order given. (Capture groups may be used in the patterns,
+    /// but where they match isn't reported.)
+    ///
+    /// If any of the patterns is invalid, then the first error found is
+    /// returned. (Its position is in the pattern that has the error.) If no
+    /// patterns are given, then nothing matches.
+    pub fn new(patterns: &[&str]) -> Result<Scanner, parse::Error> {
+        let mut alts = vec!();
+        for pat in patterns.iter() {
+            // Wrapping each pattern keeps its own alternation (if it has
+            // one) from being counted as separate patterns.
+            alts.push(~Cat(vec!(try!(parse::parse(*pat)))));
+        }
+        let ast = match alts.pop() {
+            None => ~parse::Class(vec!(), parse::FLAG_EMPTY),
+            Some(last) => {
+                alts.move_iter().rev().fold(last, |ast, alt| ~Alt(alt, ast))
+            }
+        };
+        let (mut prog, _) = Program::new(ast, true);
+        prog.anchor_start();
+        Ok(Scanner {
+            patterns: patterns.iter().map(|pat| pat.to_owned()).collect(),
+            prog: prog,
+            scratch: vm::ScratchPool::new(),
+        })
+    }
+
+    /// Returns the patterns that the scanner was compiled from.
+    pub fn patterns<'a>(&'a self) -> &'a [~str] {
+        self.patterns.as_slice()
+    }
+
+    /// Matches the patterns at byte index `start` in `text`. If one of them
+    /// matches there, then the number of the first one that does is
+    /// returned, along with where its match ends.
+    ///
+    /// Only the match is anchored, so assertions like `^` and `\b` still
+    /// look at the text before `start`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use regex::scan::Scanner;
+    /// let scanner = Scanner::new(&[r"if\b", r"\w+"]).unwrap();
+    /// assert_eq!(scanner.match_at("x if iffy", 2), Some((0, 4)));
+    /// assert_eq!(scanner.match_at("x if iffy", 5), Some((1, 9)));
+    /// assert_eq!(scanner.match_at("x if iffy", 1), None);
+    /// ```
+    pub fn match_at(&self, text: &str, start: uint) -> Option<(uint, uint)> {
+        let prog = &self.prog;
+        let mut scratch = self.scratch.get(prog);
+        let locs = vm::run_scratch(Submatches, prog, &mut scratch,
+                                   text, start, text.len());
+        self.scratch.put(scratch);
+        let end = match *locs.get(1) {
+            None => return None,
+            Some(end) => end,
+        };
+        // Only the slot for the pattern that matched is set.
+        let first = locs.len() - prog.alternates;
+        let pat = locs.slice_from(first).iter().position(|loc| loc.is_some());
+        Some((pat.unwrap(), end))
+    }
+
+    /// Returns an iterator over the tokens at the start of `text`. Each
+    /// token is the number of the pattern that matched, followed by the
+    /// start and end byte indices of its match. Each token starts where the
+    /// one before it ends.
+    ///
+    /// The iterator stops when no pattern matches, or when the match is
+    /// empty (since it couldn't advance). `Tokens::position` says where
+    /// that happened.
+    pub fn tokens<'s, 't>(&'s self, text: &'t str) -> Tokens<'s, 't> {
+        Tokens {
+            scanner: self,
+            text: text,
+            pos: 0,
+            done: false,
+        }
+    }
+}
+
+/// Yields each token read by a scanner from the start of some text.
+///
+/// `'s` is the lifetime of the scanner and `'t` is the lifetime of the
+/// text.
+pub struct Tokens<'s, 't> {
+    scanner: &'s Scanner,
+    text: &'t str,
+    pos: uint,
+    done: bool,
+}
+
+impl<'s, 't> Tokens<'s, 't> {
+    /// Returns where the next token starts (or where the last one ended).
+    /// Once the iterator is done, the text was read completely if and only
+    /// if this is its length.
+    pub fn position(&self) -> uint {
+        self.pos
+    }
+}
+
+impl<'s, 't> Iterator<(uint, uint, uint)> for Tokens<'s, 't> {
+    fn next(&mut self) -> Option<(uint, uint, uint)> {
+        if self.done {
+            return None
+        }
+        match self.scanner.match_at(self.text, self.pos) {
+            Some((pat, end)) if end > self.pos => {
+                let start = self.pos;
+                self.pos = end;
+                Some((pat, start, end))
+            }
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+
+/// Lexer reads tokens from text like a `Scanner`, but yields the token given
+/// with each pattern instead of the pattern's number.
+///
+/// A lexer can be compiled when your program is compiled with the `lexer!`
+/// macro, which takes `pattern => token` pairs separated by commas.
+pub struct Lexer<T> {
+    /// The representation of `Lexer` is exported to support the `lexer!`
+    /// syntax extension. Do not rely on it.
+    #[doc(hidden)]
+    pub scanner: Scanner,
+    #[doc(hidden)]
+    pub tokens: ~[T],
+}
+
+impl<T> Lexer<T> {
+    /// Compiles a lexer for the (pattern, token) pairs given. Patterns are
+    /// matched as they are by `Scanner::new`, so the first one that matches
+    /// wins.
+    ///
+    /// If any of the patterns is invalid, then the first error found is
+    /// returned.
+    pub fn new(rules: Vec<(&str, T)>) -> Result<Lexer<T>, parse::Error> {
+        let mut patterns = Vec::with_capacity(rules.len());
+        let mut tokens = Vec::with_capacity(rules.len());
+        for (pat, tok) in rules.move_iter() {
+            patterns.push(pat);
+            tokens.push(tok);
+        }
+        let scanner = try!(Scanner::new(patterns.as_slice()));
+        Ok(Lexer {
+            scanner: scanner,
+            tokens: tokens.move_iter().collect(),
+        })
+    }
+
+    /// Returns the scanner that matches the lexer's patterns.
+    pub fn scanner<'a>(&'a self) -> &'a Scanner {
+        &self.scanner
+    }
+
+    /// Matches the patterns at byte index `start` in `text` (as
+    /// `Scanner::match_at` does). If one of them matches, then its token is
+    /// returned, along with where its match ends.
+    pub fn match_at<'a>(&'a self, text: &str, start: uint)
+                       -> Option<(&'a T, uint)> {
+        self.scanner.match_at(text, start).map(|(pat, end)| {
+            (&self.tokens[pat], end)
+        })
+    }
+
+    /// Returns an iterator over the tokens at the start of `text`, along
+    /// with the text that each one matched. It stops in the same places as
+    /// `Scanner::tokens`.
+    pub fn lex<'l, 't>(&'l self, text: &'t str) -> Lexemes<'l, 't, T> {
+        Lexemes {
+            tokens: self.scanner.tokens(text),
+            lexer: self,
+        }
+    }
+}
+
+/// Yields each token read by a lexer from the start of some text, along
+/// with the text it matched.
+///
+/// `'l` is the lifetime of the lexer and `'t` is the lifetime of the text.
+pub struct Lexemes<'l, 't, T> {
+    tokens: Tokens<'l, 't>,
+    lexer: &'l Lexer<T>,
+}
+
+impl<'l, 't, T> Lexemes<'l, 't, T> {
+    /// Returns where the next token starts (or where the last one ended).
+    /// (See `Tokens::position`.)
+    pub fn position(&self) -> uint {
+        self.tokens.position()
+    }
+}
+
+impl<'l, 't, T> Iterator<(&'l T, &'t str)> for Lexemes<'l, 't, T> {
+    fn next(&mut self) -> Option<(&'l T, &'t str)> {
+        let (lexer, text) = (self.lexer, self.tokens.text);
+        match self.tokens.next() {
+            None => None,
+            Some((pat, s, e)) => Some((&lexer.tokens[pat], text.slice(s, e))),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This module implements a simplification pass over the abstract syntax of a
+// regular expression. It runs after parsing and before compilation. Every
+// rewrite preserves the meaning of the expression, including which match is
+// preferred and the locations of capture groups. The point is to produce
+// fewer instructions, which makes the VM (and the code generated by `regex!`)
+// faster.
+//
+// The rewrites are:
+//
+// 1. `Nothing` is removed from concatenations, nested concatenations are
+//    flattened and a concatenation of zero or one expressions is replaced
+//    by `Nothing` or the expression itself.
+// 2. Adjacent alternates that each match a single character from a set
+//    (i.e., case sensitive literals and non-negated classes) are merged into
+//    one class. e.g., `a|b|[x-z]|foo` becomes `[abx-z]|foo`. Since both
+//    alternates consume exactly one character and are followed by the same
+//    thing, it doesn't matter which one is preferred.
+// 3. A repetition of a repetition with the same greediness is collapsed.
+//    e.g., `(?:a+)*` becomes `a*`.
+// 4. A small counted repetition is expanded into copies of the expression.
+//    e.g., `a{2,3}` becomes `aaa?`. The VM runs the copies faster than a
+//    counted loop, and the other optimizations (like literal prefixes) can
+//    see them. Larger counted repetitions are compiled to a loop with a
+//    counter instead. (See `compile.rs`.)
+
+use parse::{
+    Ast, Flags, FLAG_EMPTY, FLAG_NOCASE, FLAG_NEGATED,
+    Nothing, Literal, Class, Cat, Alt, Rep,
+    ZeroOne, ZeroMore, OneMore, Counted,
+    combine_ranges, fold, map,
+};
+
+// The maximum number of nodes that expanding a counted repetition may
+// produce. Any bigger than this and it's compiled to a loop instead.
+static MAX_EXPAND: uint = 64;
+
+/// Returns a simplified version of the expression given.
+pub fn simplify(ast: ~Ast) -> ~Ast {
+    map(ast, simplify_one)
+}
+
+// Simplifies an expression whose sub-expressions are already simplified.
+// (An expression that this builds is simplified with another call, which
+// never goes deeper than that.)
+fn simplify_one(ast: ~Ast) -> ~Ast {
+    match ast {
+        ~Cat(xs) => {
+            let mut ys = Vec::with_capacity(xs.len());
+            for x in xs.move_iter() {
+                match x {
+                    ~Nothing => {}
+                    ~Cat(zs) => ys.push_all_move(zs),
+                    y => ys.push(y),
+                }
+            }
+            match ys.len() {
+                0 => ~Nothing,
+                1 => ys.pop().unwrap(),
+                _ => ~Cat(ys),
+            }
+        }
+        ~Alt(x, y) => {
+            // Alternations are nested to the right, so after simplifying,
+            // `y` is either a single alternate or an `Alt` whose first
+            // alternate is the one immediately following `x`.
+            match y {
+                ~Alt(y1, y2) => match union(&*x, &*y1) {
+                    Some(xy) => ~Alt(xy, y2),
+                    None => ~Alt(x, ~Alt(y1, y2)),
+                },
+                y => match union(&*x, &*y) {
+                    Some(xy) => xy,
+                    None => ~Alt(x, y),
+                },
+            }
+        }
+        ~Rep(x, Counted(min, max), greed) => {
+            let copies = match max { None => min + 1, Some(max) => max };
+            if copies * size(&*x) > MAX_EXPAND {
+                return ~Rep(x, Counted(min, max), greed)
+            }
+            // Require `min` copies and then repeat it (up to `max` times
+            // optionally, if there is a max).
+            let mut xs = vec!();
+            for _ in range(0, min) {
+                xs.push(x.clone())
+            }
+            match max {
+                None => xs.push(simplify_one(~Rep(x, ZeroMore, greed))),
+                Some(max) => {
+                    for _ in range(min, max) {
+                        let y = ~Rep(x.clone(), ZeroOne, greed);
+                        xs.push(simplify_one(y))
+                    }
+                }
+            }
+            simplify_one(~Cat(xs))
+        }
+        ~Rep(x, rep, greed) => {
+            let inner = match *x {
+                Rep(_, Counted(_, _), _) => None,
+                Rep(_, rep2, greed2)
+                    if greed.is_greedy() == greed2.is_greedy() => Some(rep2),
+                _ => None,
+            };
+            match (inner, x) {
+                (Some(rep2), ~Rep(x, _, _)) => {
+                    let rep = match (rep, rep2) {
+                        (ZeroOne, ZeroOne) => ZeroOne,
+                        (OneMore, OneMore) => OneMore,
+                        _ => ZeroMore,
+                    };
+                    ~Rep(x, rep, greed)
+                }
+                (_, x) => ~Rep(x, rep, greed),
+            }
+        }
+        ast => ast,
+    }
+}
+
+// Returns the number of nodes in the expression.
+fn size(ast: &Ast) -> uint {
+    fold(ast, |_, sizes: Vec<uint>| sizes.iter().fold(1, |n, &m| n + m))
+}
+
+// If both expressions match exactly one character from a set, then a single
+// class matching the union of both sets is returned.
+fn union(x: &Ast, y: &Ast) -> Option<~Ast> {
+    match (char_set(x), char_set(y)) {
+        (Some(mut ranges), Some(ys)) => {
+            ranges.push_all_move(ys);
+            Some(~Class(combine_ranges(ranges), FLAG_EMPTY))
+        }
+        _ => None,
+    }
+}
+
+// Returns the set of characters matched by a case sensitive literal or a
+// non-negated, case sensitive class.
+//
+// Case insensitive classes are left alone since the VM only folds the
+// endpoints of each range.
+fn char_set(ast: &Ast) -> Option<Vec<(char, char)>> {
+    match *ast {
+        Literal(c, flags) if !casei(flags) => Some(vec!((c, c))),
+        Class(ref ranges, flags)
+            if !casei(flags) && flags & FLAG_NEGATED == 0 => {
+            Some(ranges.clone())
+        }
+        _ => None,
+    }
+}
+
+fn casei(flags: Flags) -> bool {
+    flags & FLAG_NOCASE > 0
+}
@@ -30,6 +30,22 @@ macro_rules! regex(
             Err(err) => fail!("{}", err),
         }
     );
+    ($re:expr, $flags:expr) => (
+        regex!(format!("(?{}){}", $flags, $re).as_slice())
+    );
+)
+
+macro_rules! regex_small(
+    ($($args:expr),+) => (regex!($($args),+));
+)
+
+macro_rules! lexer(
+    ($($re:expr => $tok:expr),+) => (
+        match ::regex::scan::Lexer::new(vec!($(($re, $tok)),+)) {
+            Ok(lexer) => lexer,
+            Err(err) => fail!("{}", err),
+        }
+    );
 )
 
 #[path = "bench.rs"]
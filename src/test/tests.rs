@@ -10,7 +10,38 @@
 
 // ignore-tidy-linelength
 
-use regex::{Regex, NoExpand};
+use collections::HashSet;
+use rand::task_rng;
+use std::io::BufReader;
+use std::str::{Owned, Slice};
+use sync::Arc;
+use time;
+use regex::{Regex, RegexBuilder, NoExpand, Literals, LimitExceeded};
+use regex::{NfaEngine, BacktrackEngine};
+use regex::{AnchoredScan, PrefixScan, PrefixSetScan, FullScan};
+use regex::{Exists, Location, Submatches, Input};
+use regex::{FullMatch, PartialMatch, NoMatch};
+use regex::{quote, quote_into, is_meta_character, replace_all_multi};
+use regex::{
+    lint, LintKind, AlwaysEmptyGroup, NestedRepetition, RedundantEscape,
+    SuspiciousRange, EmptyClass, BarePosixClass,
+};
+use regex::{
+    ErrorKind, UnclosedParen, UnopenedParen, EmptyGroup, DoubleRepeat,
+    RepetitionTooLarge, InvalidRepetitionRange, InvalidRange, InvalidEscape,
+    InvalidControl, UnknownUnicodeClass, DuplicateCaptureName,
+    UndefinedGroup, BacktrackRequired, UnrecognizedFlag, InvalidCaptureIndex,
+    UnboundedLookbehind, RepeatEmptyWidth,
+};
+use regex::syntax;
+use regex::conformance;
+use regex::gen::PatternGen;
+use regex::pattern;
+use regex::pattern::{literal, class, not_class, any, concat, alternate};
+use regex::cached;
+use regex::parallel;
+use regex::grep::LineMatcher;
+use regex::scan::{Scanner, Lexer};
 
 #[test]
 fn splitn() {
@@ -28,6 +59,1403 @@ fn split() {
     assert_eq!(subs, vec!("cauchy", "plato", "tyler", "binx"));
 }
 
+#[test]
+fn quote_meta() {
+    let text = r"a.b*c\d(e)[f]{g}|^$?+";
+    let mut quoted = StrBuf::from_str("^");
+    quote_into(text, &mut quoted);
+    assert_eq!(quoted.as_slice().slice_from(1), quote(text).as_slice());
+    quoted.push_char('$');
+    assert!(Regex::new(quoted.as_slice()).unwrap().is_match(text));
+    for c in text.chars() {
+        assert_eq!(is_meta_character(c), !c.is_alphabetic());
+    }
+
+    // Quoted text means the same with the `x` flag.
+    let text = "a b#c-d\te\n\rf";
+    let re = format!("(?x)^{}$", quote(text));
+    assert!(Regex::new(re.as_slice()).unwrap().is_match(text));
+    assert!(!Regex::new(re.as_slice()).unwrap().is_match("a"));
+    let re = format!("[{}]", quote("a-z"));
+    assert!(!Regex::new(re.as_slice()).unwrap().is_match("b"));
+    for &c in [' ', '\t', '\n', '\r', '#', '-'].iter() {
+        assert!(is_meta_character(c));
+    }
+}
+
+#[test]
+fn reuse_between_match_kinds() {
+    // Searches with different match kinds share the same thread queues, so
+    // make sure stale capture groups don't leak between them.
+    let re = regex!(r"(a)(b)?c");
+    assert!(re.is_match("xac"));
+    assert_eq!(re.find("xabc"), Some((1, 4)));
+    let caps = re.captures("abc ac").unwrap();
+    assert_eq!(caps.pos(2), Some((1, 2)));
+    let caps: Vec<Option<(uint, uint)>> =
+        re.captures_iter("abc ac").map(|c| c.pos(2)).collect();
+    assert_eq!(caps, vec!(Some((1, 2)), None));
+}
+
+#[test]
+fn debug_program() {
+    let got = regex!(r"a+b").debug_program();
+    let expected = "0000 save 0\n\
+                    0001 char 'a'\n\
+                    0002 split 1, 3\n\
+                    0003 char 'b'\n\
+                    0004 save 1\n\
+                    0005 match\n\
+                    prefix: \"a\"\n\
+                    inner: \"b\"\n";
+    assert_eq!(got.as_slice(), expected);
+}
+
+#[test]
+fn debug_program_flags() {
+    let got = regex!(r"(?i)[^a-c]").debug_program();
+    assert!(got.as_slice().contains("0001 class [a-c] (i, negated)\n"));
+}
+
+#[test]
+fn debug_program_prefix_nocase() {
+    let got = regex!(r"(?i)a1b").debug_program();
+    assert!(got.as_slice().ends_with(
+        "prefixes: \"A1B\" \"A1b\" \"a1B\" \"a1b\"\n"));
+}
+
+#[test]
+fn debug_program_dot() {
+    let got = regex!(r"a+\\").debug_program_dot();
+    let expected = "digraph program {\n    \
+                    0 [label=\"0: save 0\", shape=box];\n    \
+                    0 -> 1;\n    \
+                    1 [label=\"1\", shape=circle];\n    \
+                    1 -> 2 [label=\"char 'a'\"];\n    \
+                    2 [label=\"2: split 1, 3\", shape=box];\n    \
+                    2 -> 1 [label=\"1\"];\n    \
+                    2 -> 3 [label=\"2\"];\n    \
+                    3 [label=\"3\", shape=circle];\n    \
+                    3 -> 4 [label=\"char '\\\\\\\\'\"];\n    \
+                    4 [label=\"4: save 1\", shape=box];\n    \
+                    4 -> 5;\n    \
+                    5 [label=\"5\", shape=doublecircle];\n\
+                    }\n";
+    assert_eq!(got.as_slice(), expected);
+}
+
+#[test]
+fn simplify_alternate_chars() {
+    let got = regex!(r"a|b|[x-z]|foo").debug_program();
+    assert!(got.as_slice().contains("class [abx-z]\n"));
+}
+
+#[test]
+fn builder_unicode() {
+    let re = RegexBuilder::new(r"\w+").unicode(false).build().unwrap();
+    assert_eq!(re.find("δab"), Some((2, 4)));
+    let re = RegexBuilder::new(r"(?u)\w+").unicode(false).build().unwrap();
+    assert_eq!(re.find("δab"), Some((0, 4)));
+    let re = RegexBuilder::new(r"\w+").unicode(true).build().unwrap();
+    assert_eq!(re.find("δab"), Some((0, 4)));
+}
+
+fn backtrack(re: &str) -> Regex {
+    RegexBuilder::new(re).backtrack(true).build().unwrap()
+}
+
+#[test]
+fn backref_numbered() {
+    let re = backtrack(r"(\w+) \1");
+    assert_eq!(re.find("a bc bc"), Some((2, 7)));
+    assert!(!re.is_match("ab cd"));
+    let cap = re.captures("go go").unwrap();
+    assert_eq!(cap.at(1), "go");
+}
+
+#[test]
+fn backref_named() {
+    let re = backtrack(r"(?P<c>[a-z])x(?P=c)");
+    assert_eq!(re.find("axb bxb"), Some((4, 7)));
+}
+
+#[test]
+fn backref_casei() {
+    let re = backtrack(r"(?i)(a+)\1");
+    assert_eq!(re.find("aAaA"), Some((0, 4)));
+    let re = backtrack(r"(a+)(?i:\1)");
+    assert_eq!(re.find("aA"), Some((0, 2)));
+    assert!(!backtrack(r"(a+)\1").is_match("aA"));
+}
+
+#[test]
+fn backref_unmatched_group() {
+    let re = backtrack(r"(?:(a)|b)\1");
+    assert!(!re.is_match("b"));
+    assert_eq!(re.find("baa"), Some((1, 3)));
+}
+
+#[test]
+fn backref_two_digits() {
+    let re = backtrack(r"(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)\10");
+    assert!(re.is_match("abcdefghijj"));
+    // With fewer than 11 groups, `\11` is group 1 followed by a `1`.
+    let re = backtrack(r"(a)\11");
+    assert!(re.is_match("aa1"));
+}
+
+#[test]
+fn backref_replace() {
+    let re = backtrack(r"\b(\w+) \1\b");
+    assert_eq!(re.replace_all("the the cat cat sat", "$1").as_slice(),
+               "the cat sat");
+}
+
+#[test]
+fn backref_octal_without_backtrack() {
+    let re = Regex::new(r"\101").unwrap();
+    assert!(re.is_match("A"));
+}
+
+#[test]
+fn backref_limit() {
+    let re = RegexBuilder::new(r"(a*)*\1b").backtrack(true)
+                                           .backtrack_limit(1000)
+                                           .build().unwrap();
+    let text = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    assert!(!re.is_match(text));
+    assert_eq!(re.find(text), None);
+    assert!(re.captures(text).is_none());
+    assert_eq!(re.find_iter(text).count(), 0);
+    // The try_ methods report the limit instead.
+    assert_eq!(re.try_is_match(text), Err(LimitExceeded { limit: 1000 }));
+    assert_eq!(re.try_find(text), Err(LimitExceeded { limit: 1000 }));
+    assert!(re.try_captures(text).is_err());
+    assert_eq!(re.try_is_match("aab"), Ok(true));
+    assert_eq!(re.try_find("xaab"), Ok(Some((1, 4))));
+    assert_eq!(re.try_captures("aab").unwrap().unwrap().at(1), "a");
+    assert_eq!(re.try_is_match("ccc"), Ok(false));
+    // Expressions that don't backtrack never exceed it.
+    let re = RegexBuilder::new(r"(a*)*b").backtrack_limit(1)
+                                         .build().unwrap();
+    assert_eq!(re.try_is_match(text), Ok(false));
+    // A lookaround that gives up doesn't count as failing, so a negative
+    // one doesn't match either.
+    let re = RegexBuilder::new(r"(?!(a*)*\1b)a").backtrack(true)
+                                                .backtrack_limit(1000)
+                                                .build().unwrap();
+    assert!(!re.is_match(text));
+    // The limit applies to each starting position separately.
+    let re = RegexBuilder::new(r"(a)\1").backtrack(true)
+                                        .backtrack_limit(10)
+                                        .build().unwrap();
+    assert_eq!(re.find("bcdefghijkaa"), Some((10, 12)));
+}
+
+#[test]
+fn backtrack_ascii_word_boundary() {
+    let re = backtrack(r"(?-u)(?=.)\bx");
+    assert_eq!(re.find("éx"), Some((2, 3)));
+    assert_eq!(re.find("ax"), None);
+}
+
+#[test]
+fn lookahead_positive() {
+    let re = backtrack(r"\w+(?=!)");
+    assert_eq!(re.find("hi there!"), Some((3, 8)));
+    let re = backtrack(r"(?=\d{3})\d");
+    assert_eq!(re.find_iter("12 3456").collect::<Vec<(uint, uint)>>(),
+               vec![(3, 4), (4, 5)]);
+}
+
+#[test]
+fn lookahead_negative() {
+    let re = backtrack(r"foo(?!bar)");
+    assert_eq!(re.find("foobar foobaz"), Some((7, 10)));
+    let re = backtrack(r"^(?!.*password).{8,}$");
+    assert!(re.is_match("correct horse"));
+    assert!(!re.is_match("my password"));
+}
+
+#[test]
+fn lookahead_captures() {
+    let cap = backtrack(r"(?=(\w+))\w").captures("abc").unwrap();
+    assert_eq!(cap.at(0), "a");
+    assert_eq!(cap.at(1), "abc");
+    let cap = backtrack(r"(?!(a)b)(\w)").captures("ac").unwrap();
+    assert_eq!(cap.pos(1), None);
+    assert_eq!(cap.at(2), "a");
+}
+
+#[test]
+fn lookahead_with_backref() {
+    let re = backtrack(r"(\w)(?!\1)\w");
+    assert_eq!(re.find("aabc"), Some((1, 3)));
+}
+
+#[test]
+fn lookbehind_positive() {
+    let re = backtrack(r"(?<=\$)\d+");
+    assert_eq!(re.find("cost: 5 $42"), Some((9, 11)));
+    let re = backtrack(r"(?<=ab|c)d");
+    assert_eq!(re.find("abd"), Some((2, 3)));
+    assert_eq!(re.find("cd"), Some((1, 2)));
+    let re = backtrack(r"(?<=a{1,3})b");
+    assert_eq!(re.find("xaab"), Some((3, 4)));
+}
+
+#[test]
+fn lookbehind_negative() {
+    let re = backtrack(r#"(?<!\\)""#);
+    assert_eq!(re.find(r#"a\"b""#), Some((4, 5)));
+}
+
+#[test]
+fn lookbehind_before_search_start() {
+    let re = backtrack(r"(?<=a)b");
+    assert_eq!(re.find_iter("abab").collect::<Vec<(uint, uint)>>(),
+               vec![(1, 2), (3, 4)]);
+}
+
+#[test]
+fn lookbehind_anchors_and_captures() {
+    let re = backtrack(r"(?<=^a)b");
+    assert!(re.is_match("ab"));
+    assert!(!re.is_match("aab"));
+    let cap = backtrack(r"(?<=(\w)(\w))x").captures("abx").unwrap();
+    assert_eq!(cap.at(1), "a");
+    assert_eq!(cap.at(2), "b");
+}
+
+#[test]
+fn atomic_group() {
+    assert_eq!(backtrack(r"(?>a+)b").find("aaab"), Some((0, 4)));
+    assert!(!backtrack(r"(?>a+)ab").is_match("aaab"));
+    assert_eq!(backtrack(r"(?>ab|a)c").find("ac"), Some((0, 2)));
+    assert_eq!(backtrack(r"(?>a|ab)c").find("abc"), None);
+    let cap = backtrack(r"(?>(a+))b").captures("xaab").unwrap();
+    assert_eq!(cap.at(1), "aa");
+}
+
+#[test]
+fn possessive() {
+    assert!(!backtrack(r"a*+a").is_match("aaa"));
+    assert_eq!(backtrack(r"a++b").find("xaab"), Some((1, 4)));
+    assert!(!backtrack(r"^a?+a$").is_match("a"));
+    assert!(!backtrack(r"^a{1,2}+a$").is_match("aa"));
+    assert_eq!(backtrack(r"a{1,2}+a").find("aaa"), Some((0, 3)));
+    assert_eq!(backtrack(r"\d{2}+").find("1234"), Some((0, 2)));
+}
+
+#[test]
+fn backref_errors() {
+    assert!(RegexBuilder::new(r"(a)\2").backtrack(true).build().is_err());
+    assert!(RegexBuilder::new(r"(?P=a)").backtrack(true).build().is_err());
+    assert!(RegexBuilder::new(r"(a)[\1]").backtrack(true).build().is_err());
+    assert!(RegexBuilder::new(r"(?<=a+)b").backtrack(true).build().is_err());
+    assert!(RegexBuilder::new(r"(a)(?<=\1)").backtrack(true).build().is_err());
+}
+
+fn alternate(re: &Regex, text: &str) -> Option<uint> {
+    re.captures(text).and_then(|caps| caps.alternate())
+}
+
+#[test]
+fn alternates() {
+    let re = RegexBuilder::new(r"GET|POST|(P)UT|a|b").alternates(true)
+                                                     .build().unwrap();
+    assert_eq!(alternate(&re, "GET /"), Some(0));
+    assert_eq!(alternate(&re, "x POST"), Some(1));
+    assert_eq!(alternate(&re, "PUT"), Some(2));
+    assert_eq!(alternate(&re, "a"), Some(3));
+    assert_eq!(alternate(&re, "b"), Some(4));
+    assert_eq!(alternate(&re, "x"), None);
+
+    let caps = re.captures("PUT").unwrap();
+    assert_eq!(caps.len(), 2);
+    assert_eq!(caps.at(1), "P");
+
+    let alts: Vec<Option<uint>> =
+        re.captures_iter("b GET a").map(|caps| caps.alternate()).collect();
+    assert_eq!(alts, vec![Some(4), Some(0), Some(3)]);
+
+    let re = RegexBuilder::new(r"a+").alternates(true).build().unwrap();
+    assert_eq!(alternate(&re, "aa"), Some(0));
+    let re = Regex::new(r"a|b").unwrap();
+    assert_eq!(alternate(&re, "b"), None);
+    let re = RegexBuilder::new(r"(a)\1|b").backtrack(true).alternates(true)
+                                          .build().unwrap();
+    assert_eq!(alternate(&re, "b"), Some(1));
+}
+
+#[test]
+fn macro_flags() {
+    let re = regex!(r"^foo$", "im");
+    assert_eq!(re.find("bar\nFOO"), Some((4, 7)));
+    assert!(re == regex!(r"(?im)^foo$"));
+    assert!(!regex!(r"a.b").is_match("a\nb"));
+    assert!(regex!(r"a.b", "s").is_match("a\nb"));
+}
+
+#[test]
+fn macro_concat() {
+    let re = regex!(concat!(r"\d{4}", "-", r"\d{2}"));
+    assert_eq!(re.find("on 2014-05"), Some((3, 10)));
+    let re = regex!((concat!("a", "b+")), "i");
+    assert!(re.is_match("ABB"));
+}
+
+#[test]
+fn captures_index() {
+    let re = regex!(r"(?P<y>\d{4})-(\d{2})(x)?");
+    let caps = re.captures("on 2014-05").unwrap();
+    assert_eq!(caps[0], "2014-05");
+    assert_eq!(caps[1], "2014");
+    assert_eq!(caps[2], "05");
+    assert_eq!(caps["y"], "2014");
+    assert_eq!(caps[3], "");
+    assert_eq!(caps[9], "");
+    assert_eq!(caps["nope"], "");
+}
+
+#[test]
+fn captures_pos_named() {
+    let re = regex!(r"(?P<y>\d{4})-(?P<m>\d{2})(?P<x>x)?");
+    let caps = re.captures("on 2014-05").unwrap();
+    assert_eq!(caps.pos_named("y"), Some((3, 7)));
+    assert_eq!(caps.pos_named("m"), Some((8, 10)));
+    assert_eq!(caps.pos_named("x"), None);
+    assert_eq!(caps.pos_named("nope"), None);
+}
+
+#[test]
+fn capture_spans() {
+    let re = regex!(r"(a)(?:b)(?P<c>(?i)c(d)|☃)(?<e>e)\)");
+    assert_eq!(re.capture_spans(), vec![(0, 36), (0, 3), (8, 27), (19, 22),
+                                        (27, 34)]);
+    assert_eq!(regex!(r"abc").capture_spans(), vec![(0, 3)]);
+
+    let (_, spans) = syntax::parse_with_spans(r"(?x) ( a ) # (b)",
+                                              syntax::FLAG_UNICODE).unwrap();
+    assert_eq!(spans, vec![(5, 10)]);
+}
+
+#[test]
+fn captures_only() {
+    let re = regex!(r"(a)(?P<b>b)?(c)(?:(d)|e)");
+    assert_eq!(re.capture_index("b"), Some(2));
+    assert_eq!(re.capture_index("a"), None);
+    let caps = re.captures_only("xabcd", &[3, 4, 9]).unwrap();
+    let got: Vec<Option<(uint, uint)>> = caps.iter_pos().collect();
+    assert_eq!(got, vec![Some((1, 5)), None, None, Some((3, 4)),
+                         Some((4, 5))]);
+    let caps = re.captures_only("ace", &[]).unwrap();
+    assert_eq!(caps.pos(0), Some((0, 3)));
+    assert_eq!(caps.pos(1), None);
+    assert_eq!(caps.len(), 5);
+    assert!(re.captures_only("xyz", &[1]).is_none());
+
+    let re = RegexBuilder::new(r"(a)(b)|(c)").alternates(true).build().unwrap();
+    let caps = re.captures_only("c", &[3]).unwrap();
+    assert_eq!(caps.at(3), "c");
+    assert_eq!(caps.alternate(), Some(1));
+
+    let caps = backtrack(r"(a)(b)\2").captures_only("abb", &[2]).unwrap();
+    assert_eq!(caps.pos(1), None);
+    assert_eq!(caps.pos(2), Some((1, 2)));
+}
+
+#[test]
+fn captures_iter_named() {
+    let re = regex!(r"(?P<z>a)(b)(?P<a>c)?(?P<m>d)");
+    let caps = re.captures("abd").unwrap();
+    let got: Vec<(&str, Option<&str>)> = caps.iter_named().collect();
+    assert_eq!(got, vec![("z", Some("a")), ("a", None), ("m", Some("d"))]);
+
+    let caps = regex!(r"(a)").captures("a").unwrap();
+    assert_eq!(caps.iter_named().next(), None);
+}
+
+#[test]
+fn empty_match_iter_unicode() {
+    let re = regex!(r"");
+    let got: Vec<(uint, uint)> = re.find_iter("☃a").collect();
+    assert_eq!(got, vec![(0, 0), (3, 3), (4, 4)]);
+    let got: Vec<(uint, uint)> =
+        re.find_iter("☃a").bytewise_empty().collect();
+    assert_eq!(got, vec![(0, 0), (1, 1), (2, 2), (3, 3), (4, 4)]);
+
+    let re = regex!(r"a*");
+    let got: Vec<(uint, uint)> = re.find_iter("a☃a").collect();
+    assert_eq!(got, vec![(0, 1), (4, 5)]);
+    let got: Vec<&str> = re.captures_iter("☃☃").map(|c| c.at(0)).collect();
+    assert_eq!(got, vec!["", "", ""]);
+}
+
+#[test]
+fn deeply_nested_groups() {
+    let nested = |depth: uint| {
+        format!("{}{}", repeat("(a|b", depth), repeat(")*", depth))
+    };
+    let re = Regex::new(nested(500).as_slice()).unwrap();
+    assert_eq!(re.captures_len(), 501);
+    let caps = re.captures("ab").unwrap();
+    assert_eq!(caps.at(0), "ab");
+    assert_eq!(caps.at(1), "b");
+
+    // Compiling never recurses, so no nesting is too deep. (The anchor
+    // makes a reverse program from a copy of the expression, and the
+    // alternates are each simplified on their own.)
+    let re = Regex::new(nested(10000).as_slice()).unwrap();
+    assert_eq!(re.captures_len(), 10001);
+    let re = format!("{}$", nested(10000));
+    assert!(Regex::new(re.as_slice()).is_ok());
+    let re = format!("x|{}", nested(10000));
+    let re = RegexBuilder::new(re.as_slice()).alternates(true).build();
+    assert!(re.is_ok());
+    let re = format!("{}{}", repeat("(?:a{2}", 10000), repeat(")", 10000));
+    assert!(Regex::new(re.as_slice()).is_ok());
+
+    // Groups that don't capture are concatenations nested as deep, which
+    // simplify to one long concatenation.
+    let re = format!("{}{}", repeat("a(?:b", 10000), repeat(")", 10000));
+    let re = Regex::new(re.as_slice()).unwrap();
+    assert!(re.is_match(repeat("ab", 10000).as_slice()));
+    assert!(!re.is_match(repeat("ab", 9999).as_slice()));
+}
+
+fn repeat(s: &str, n: uint) -> ~str {
+    let mut buf = StrBuf::new();
+    for _ in range(0, n) {
+        buf.push_str(s);
+    }
+    buf.into_owned()
+}
+
+#[test]
+fn counted_loops() {
+    // Big repetitions are compiled to a loop instead of copies.
+    let re = Regex::new(r"a{500}b").unwrap();
+    assert!(re.program_stats().insts < 10);
+    let text = format!("{}b", repeat("a", 600));
+    assert_eq!(re.find(text.as_slice()), Some((100, 601)));
+    let text = format!("{}b", repeat("a", 499));
+    assert!(!re.is_match(text.as_slice()));
+
+    let re = Regex::new(r"(a|b){100}").unwrap();
+    let text = repeat("ab", 60);
+    let caps = re.captures(text.as_slice()).unwrap();
+    assert_eq!(caps.pos(0), Some((0, 100)));
+    assert_eq!(caps.at(1), "b");
+
+    let text = repeat("a", 300);
+    let find = |re: &str| Regex::new(re).unwrap().find(text.as_slice());
+    assert_eq!(find(r"a{70,200}"), Some((0, 200)));
+    assert_eq!(find(r"a{70,200}?"), Some((0, 70)));
+    assert_eq!(find(r"a{100,}"), Some((0, 300)));
+    assert_eq!(find(r"a{301,}"), None);
+    assert_eq!(find(r"a{100}$"), Some((200, 300)));
+    let re = Regex::new(r"a{100}").unwrap();
+    assert_eq!(re.rfind(text.as_slice()), Some((200, 300)));
+
+    // A loop whose body can match the empty string still ends.
+    assert_eq!(Regex::new(r"(?:a?){100}b").unwrap().find("aab"),
+               Some((0, 3)));
+    assert_eq!(Regex::new(r"(?:a*){100,}b").unwrap().find("b"), Some((0, 1)));
+
+    let re = Regex::new(r"^(?:b{70}a){30}$").unwrap();
+    let unit = format!("{}a", repeat("b", 70));
+    assert!(re.is_match(repeat(unit.as_slice(), 30).as_slice()));
+    assert!(!re.is_match(repeat(unit.as_slice(), 29).as_slice()));
+
+    // Counts can go past 1000, as long as no expression is repeated more
+    // than 100000 times in all.
+    let re = Regex::new(r"^a{5000}$").unwrap();
+    assert!(re.is_match(repeat("a", 5000).as_slice()));
+    assert!(!re.is_match(repeat("a", 4999).as_slice()));
+    assert!(Regex::new(r"(?:a{1000}|b){100}").is_ok());
+
+    // Every starting position has its own thread in the loop, so thousands
+    // of threads share an instruction. Finding one still takes constant
+    // time.
+    let re = Regex::new(r"a{5000}").unwrap();
+    let text = repeat("a", 100000);
+    let start = time::precise_time_ns();
+    assert_eq!(re.find(text.as_slice()), Some((0, 5000)));
+    assert!(!re.is_match(repeat("a", 4999).as_slice()));
+    assert!(time::precise_time_ns() - start < 10_000_000_000);
+
+    let re = backtrack(r"(a){100}\1");
+    assert_eq!(re.find(repeat("a", 101).as_slice()), Some((0, 101)));
+    assert!(!re.is_match(repeat("a", 100).as_slice()));
+
+    let re = regex!(r"x{100}");
+    assert!(re.is_match(repeat("x", 100).as_slice()));
+    assert!(!re.is_match(repeat("x", 99).as_slice()));
+}
+
+#[test]
+fn program_stats() {
+    let stats = regex!(r"a+b").program_stats();
+    assert_eq!(stats.insts, 6);
+    assert_eq!(stats.slots, 2);
+    assert!(!stats.word_boundary);
+    assert!(!stats.backtrack);
+    assert!(stats.heap_size > 0);
+
+    let stats = regex!(r"\b(a)(?P<b>b)").program_stats();
+    assert_eq!(stats.slots, 6);
+    assert!(stats.word_boundary);
+
+    let stats = backtrack(r"(a)\1").program_stats();
+    assert!(stats.backtrack);
+
+    let re = RegexBuilder::new(r"a|b|c").alternates(true).build().unwrap();
+    assert_eq!(re.program_stats().slots, 5);
+
+    let small = regex!(r"a").program_stats().heap_size;
+    assert!(regex!(r"[a-z]{1,20}").program_stats().heap_size > small);
+}
+
+#[test]
+fn cached_new() {
+    let a = cached::new(r"cached_new\d+").unwrap();
+    let b = cached::new(r"cached_new\d+").unwrap();
+    assert!(&*a as *Regex == &*b as *Regex);
+    assert!(a.is_match("cached_new12"));
+    assert!(cached::new(r"cached_new(").is_err());
+    assert!(cached::len() <= cached::CAPACITY);
+}
+
+#[test]
+fn exec_kinds() {
+    let re = regex!(r"\b(a)(b)?");
+    assert_eq!(re.exec(Exists, "xa a", 0, 4), vec![Some(0), Some(0)]);
+    assert_eq!(re.exec(Exists, "xa a", 0, 2), vec![None, None]);
+    assert_eq!(re.exec(Location, "xa ab", 1, 5), vec![Some(3), Some(5)]);
+    assert_eq!(re.exec(Submatches, "xa ab", 1, 5),
+               vec![Some(3), Some(5), Some(3), Some(4), Some(4), Some(5)]);
+    assert_eq!(re.exec(Submatches, "xa", 0, 2), vec![None, None, None, None,
+                                                      None, None]);
+
+    let re = RegexBuilder::new(r"a|b").alternates(true).build().unwrap();
+    assert_eq!(re.exec(Submatches, "b", 0, 1),
+               vec![Some(0), Some(1), None, Some(0)]);
+}
+
+#[test]
+fn gen_match() {
+    let mut rng = task_rng();
+    let res = [r"", r"a|bc*|[^a-z]+", r"(?i)[a-z]{2,}\d?", r"(?s).{3}x$",
+               r"^(\w+) \b\S+\b", r"[☃-☈]+|\pN"];
+    for re in res.iter() {
+        let re = Regex::new(*re).unwrap();
+        for _ in range(0, 20) {
+            let s = re.gen_match(&mut rng, 5).unwrap();
+            assert!(re.is_full_match(s.as_slice()), "{} {}", re, s);
+        }
+    }
+    let s = regex!(r"a{3}b{2,}").gen_match(&mut rng, 0).unwrap();
+    assert_eq!(s.as_slice(), "aaabb");
+    let re = backtrack(r"(\w{1,3})-\1");
+    let s = re.gen_match(&mut rng, 5).unwrap();
+    assert!(re.is_full_match(s.as_slice()));
+    assert_eq!(regex!(r"a\bb").gen_match(&mut rng, 5), None);
+}
+
+#[test]
+fn gen_pattern() {
+    let mut rng = task_rng();
+    let mut gen = PatternGen::new();
+    for _ in range(0, 200) {
+        let pattern = gen.gen(&mut rng);
+        assert!(Regex::new(pattern.as_slice()).is_ok(), "{}", pattern);
+    }
+    gen.backtrack(true).depth(6).width(2);
+    for _ in range(0, 200) {
+        let ast = gen.gen_ast(&mut rng);
+        let pattern = ast.to_pattern();
+        assert!(backtrack_ok(pattern.as_slice()), "{} {}", ast, pattern);
+    }
+    gen.depth(1);
+    let pattern = gen.gen(&mut rng);
+    let atom = regex!(r"^(?:\(\?[is]:)?(?:.|\\.|\[.+\])\)?$");
+    assert!(atom.is_match(pattern.as_slice()), "{}", pattern);
+}
+
+fn backtrack_ok(re: &str) -> bool {
+    RegexBuilder::new(re).backtrack(true).build().is_ok()
+}
+
+#[test]
+fn pattern_builder() {
+    let word = class([('a', 'z'), ('Z', 'A')]).one_or_more();
+    let p = concat(vec!(
+        pattern::begin(),
+        word.clone().group(),
+        alternate(vec!(literal("+"), literal("(*)"))).named("op"),
+        word.group().case_insensitive(),
+        pattern::end(),
+    ));
+    let expected = r"^([A-Za-z]+)(?P<op>\+|\(\*\))((?i:[A-Za-z])+)$";
+    assert_eq!(p.to_pattern().as_slice(), expected);
+    let re = p.compile().unwrap();
+    assert_eq!(re.to_str().as_slice(), expected);
+    let caps = re.captures("ab(*)CD").unwrap();
+    assert_eq!(caps.at(1), "ab");
+    assert_eq!(caps.name("op"), "(*)");
+    assert_eq!(caps.at(3), "CD");
+    assert!(!re.is_match("ab-cd"));
+
+    let lazy_any = any().zero_or_more().lazy();
+    let p = concat(vec!(literal("ab").repeat(2, Some(3)), lazy_any));
+    assert_eq!(p.to_pattern(), ~r"(?:ab){2,3}.*?");
+    assert_eq!(p.compile().unwrap().find("abababx"), Some((0, 6)));
+    let p = concat(vec!(not_class([('0', '9')]), pattern::begin().optional()));
+    assert_eq!(p.compile().unwrap().find("1a2"), Some((1, 2)));
+    assert!(!alternate(vec!()).compile().unwrap().is_match("a"));
+    assert!(concat(vec!()).compile().unwrap().is_match(""));
+
+    let dup = concat(vec!(any().named("x"), any().named("x")));
+    assert_eq!(dup.compile().err().unwrap().kind,
+               DuplicateCaptureName(~"x"));
+    let err = any().repeat(3, Some(2)).compile().err().unwrap();
+    assert_eq!(err.kind, InvalidRepetitionRange(3, 2));
+    let err = any().repeat(100001, None).compile().err().unwrap();
+    assert_eq!(err.kind, RepetitionTooLarge(100001, 100000));
+    let err = any().repeat(1000, None).repeat(0, Some(101)).compile();
+    assert_eq!(err.err().unwrap().kind, RepetitionTooLarge(101000, 100000));
+}
+
+#[test]
+fn regex_from_ast() {
+    use regex::syntax::{
+        Ast, Literal, Class, Begin, Capture, Cat, Alt, Rep, Backref,
+        Lookbehind, Nothing, ZeroMore, OneMore, Greedy, FLAG_EMPTY,
+    };
+    fn from_ast_err(ast: ~Ast) -> ErrorKind {
+        Regex::from_ast(ast).err().unwrap().kind
+    }
+    fn lit(c: char) -> ~Ast {
+        ~Literal(c, FLAG_EMPTY)
+    }
+
+    // Ranges are sorted and merged.
+    let class = ~Class(vec!(('x', 'z'), ('a', 'c'), ('b', 'd')), FLAG_EMPTY);
+    let ast = ~Cat(vec!(~Capture(1, Some(~"w"), ~Rep(class, OneMore, Greedy)),
+                        ~Alt(~Nothing, lit('-'))));
+    let re = Regex::from_ast(ast).unwrap();
+    assert_eq!(re.to_str(), ~r"(?P<w>[a-dx-z]+)(?:(?:)|-)");
+    assert_eq!(re.captures("zab-").unwrap().name("w"), "zab");
+
+    let ast = ~Cat(vec!(~Capture(1, None, lit('a')), ~Backref(1, FLAG_EMPTY)));
+    let re = Regex::from_ast(ast).unwrap();
+    assert_eq!(re.find("xaa"), Some((1, 3)));
+    let re = Regex::from_ast(~Cat(vec!(~Lookbehind(lit('a'), FLAG_EMPTY),
+                                       lit('b')))).unwrap();
+    assert_eq!(re.find("bab"), Some((2, 3)));
+
+    assert_eq!(from_ast_err(~Capture(2, None, lit('a'))),
+               InvalidCaptureIndex(2));
+    assert_eq!(from_ast_err(~Cat(vec!(~Backref(1, FLAG_EMPTY),
+                                      ~Capture(1, None, lit('a'))))),
+               UndefinedGroup(1));
+    assert_eq!(from_ast_err(~Lookbehind(~Rep(lit('a'), ZeroMore, Greedy),
+                                        FLAG_EMPTY)),
+               UnboundedLookbehind);
+    assert_eq!(from_ast_err(~Rep(~Begin(FLAG_EMPTY), ZeroMore, Greedy)),
+               RepeatEmptyWidth);
+    assert_eq!(from_ast_err(~Class(vec!(('z', 'a')), FLAG_EMPTY)),
+               InvalidRange('z', 'a'));
+}
+
+#[test]
+fn replace_multi() {
+    fn multi(text: &str, reps: &[(&str, &str)]) -> ~str {
+        let mut reps: Vec<(Regex, &str)> = reps.iter().map(|&(re, rep)| {
+            (Regex::new(re).unwrap(), rep)
+        }).collect();
+        replace_all_multi(text, reps.as_mut_slice()).into_owned()
+    }
+    assert_eq!(multi("aBcKB", [("B", "(c|g|t)"), ("K", "(g|t)")]),
+               ~"a(c|g|t)c(g|t)(c|g|t)");
+    assert_eq!(multi("abba", [("a", "b"), ("b", "a")]), ~"baab");
+    // The leftmost match wins, and then the first regex.
+    assert_eq!(multi("aab", [("ab", "X"), ("a", "Y")]), ~"YX");
+    assert_eq!(multi("ab12", [(r"\d+", "N"), (r"[a-z]+\d", "W")]), ~"WN");
+    assert_eq!(multi("a=1 b=2", [(r"(\w)=(\d)", "$2=$1")]), ~"1=a 2=b");
+    assert_eq!(multi("abc", [("x*", "-")]),
+               regex!("x*").replace_all("abc", "-").into_owned());
+    assert_eq!(multi("abc", [("x", "y")]), ~"abc");
+    assert_eq!(multi("abc", []), ~"abc");
+}
+
+#[test]
+fn regex_union() {
+    let re = Regex::union(&[r"foo|fo", r"(?i)bar", r"", r"(x)(?P<y>y)|z"])
+                   .unwrap();
+    assert_eq!(re.captures_len(), 3);
+    let alt = |text: &str| re.captures(text).and_then(|c| c.alternate());
+    assert_eq!(alt("fo"), Some(0));
+    assert_eq!(alt("BAR"), Some(1));
+    assert_eq!(alt("-"), Some(2));
+    assert_eq!(alt("z"), Some(3));
+    let caps = re.captures("xy").unwrap();
+    assert_eq!(caps.alternate(), Some(3));
+    assert_eq!((caps.at(1), caps.name("y")), ("x", "y"));
+    assert_eq!(re.find("FOO"), Some((0, 0)));
+    assert_eq!(re.full_captures("z").unwrap().alternate(), Some(3));
+
+    assert!(!Regex::union(&[]).unwrap().is_match(""));
+    assert_eq!(Regex::union(&[r"a", r"("]).err().unwrap().kind, UnclosedParen);
+    assert_eq!(Regex::union(&[r"(?P<a>x)", r"(?P<a>y)"]).err().unwrap().kind,
+               DuplicateCaptureName(~"a"));
+}
+
+#[test]
+fn is_full_match() {
+    assert!(regex!(r"a|ab").is_full_match("ab"));
+    assert!(!regex!(r"a|ab").is_full_match("abc"));
+    assert!(!regex!(r"a").is_full_match("ba"));
+    assert!(regex!(r"").is_full_match(""));
+    assert!(!regex!(r"").is_full_match("a"));
+    assert!(regex!(r"(?m)^a$").is_full_match("a"));
+    assert!(!regex!(r"a$").is_full_match("a\n"));
+    assert!(backtrack(r"(\w+) \1").is_full_match("ab ab"));
+    assert!(!backtrack(r"(\w+) \1").is_full_match("ab abc"));
+}
+
+#[test]
+fn anchored() {
+    assert!(regex!(r"^abc").is_anchored_start());
+    assert!(!regex!(r"\Aa|b").is_anchored_start());
+    assert!(regex!(r"(^a)b").is_anchored_start());
+    assert!(!regex!(r"(?m)^abc").is_anchored_start());
+    assert!(!regex!(r"a^").is_anchored_start());
+    assert!(backtrack(r"^(a)\1").is_anchored_start());
+
+    assert!(regex!(r"abc$").is_anchored_end());
+    assert!(regex!(r"a$|b\z").is_anchored_end());
+    assert!(regex!(r"(a$)").is_anchored_end());
+    assert!(!regex!(r"abc|d$").is_anchored_end());
+    assert!(!regex!(r"(?m)abc$").is_anchored_end());
+    assert!(backtrack(r"(a)\1$").is_anchored_end());
+}
+
+#[test]
+fn literals() {
+    let lits = regex!(r"abc\w+").literals();
+    assert_eq!(lits.prefixes, vec!(~"abc"));
+    assert_eq!(lits.inner, ~"");
+    assert_eq!(lits.suffix, ~"");
+
+    let lits = regex!(r"(?i)ab\d").literals();
+    assert_eq!(lits.prefixes, vec!(~"AB", ~"Ab", ~"aB", ~"ab"));
+
+    let lits = regex!(r"\w+@gmail\.com").literals();
+    assert_eq!(lits.prefixes, vec!());
+    assert_eq!(lits.inner, ~"@gmail.com");
+
+    let lits = regex!(r"[a-z]+foo$").literals();
+    assert_eq!(lits.suffix, ~"foo");
+
+    let lits = regex!(r"a*").literals();
+    assert_eq!(lits, Literals { prefixes: vec!(), inner: ~"", suffix: ~"" });
+
+    // Every one of the 2^30 paths through this ends in the same literal, so
+    // extracting it has to give up instead of following them all.
+    let re = Regex::new("(?:(a)|a)".repeat(30).as_slice()).unwrap();
+    assert_eq!(re.literals().prefixes, vec!());
+    assert!(re.is_match("a".repeat(30).as_slice()));
+}
+
+#[test]
+fn explain() {
+    let exp = Regex::new(r"abc\w+").unwrap().explain();
+    assert_eq!(exp.engine, NfaEngine);
+    assert_eq!(exp.start, PrefixScan);
+    assert!(!exp.reverse_from_end);
+    assert!(!exp.inner_check);
+    assert_eq!(exp.literals, Regex::new(r"abc\w+").unwrap().literals());
+
+    let exp = Regex::new(r"(?i)ab\d").unwrap().explain();
+    assert_eq!(exp.start, PrefixSetScan);
+
+    let exp = Regex::new(r"^\w+@gmail\.com").unwrap().explain();
+    assert_eq!(exp.start, AnchoredScan);
+    assert!(exp.inner_check);
+
+    let exp = Regex::new(r"[a-z]+foo$").unwrap().explain();
+    assert_eq!(exp.start, FullScan);
+    assert!(exp.reverse_from_end);
+
+    let exp = backtrack(r"(\w)\1$").explain();
+    assert_eq!(exp.engine, BacktrackEngine);
+    assert!(!exp.reverse_from_end);
+}
+
+#[test]
+fn match_lens() {
+    let lens = |re: &Regex| (re.min_match_len(), re.max_match_len());
+    assert_eq!(lens(&regex!(r"")), (0, Some(0)));
+    assert_eq!(lens(&regex!(r"abc")), (3, Some(3)));
+    assert_eq!(lens(&regex!(r"a|bcd")), (1, Some(3)));
+    assert_eq!(lens(&regex!(r"(ab){2,3}c?")), (4, Some(7)));
+    assert_eq!(lens(&regex!(r"[a-zé]")), (1, Some(2)));
+    assert_eq!(lens(&regex!(r"[^a]")), (1, Some(4)));
+    assert_eq!(lens(&regex!(r"(?i)s")), (1, Some(2)));
+    assert_eq!(lens(&regex!(r"☃+")), (3, None));
+    assert_eq!(lens(&regex!(r"^a\b$")), (1, Some(1)));
+    assert_eq!(lens(&backtrack(r"(a)\1")), (1, None));
+    assert_eq!(lens(&backtrack(r"a(?=bc)")), (1, Some(1)));
+}
+
+#[test]
+fn intersect_complement() {
+    let lang = regex!(r"\w+").intersect(&regex!(r"[0-9a-f]+")).unwrap();
+    assert!(lang.is_match("c0ffee"));
+    assert!(!lang.is_match("coffee"));
+    assert!(!lang.is_match("c0 ffee"));
+    assert!(!lang.is_match(""));
+
+    let lang = regex!(r"a|ab").complement().unwrap();
+    assert!(lang.is_match(""));
+    assert!(lang.is_match("abc"));
+    assert!(lang.is_match("b\na"));
+    assert!(!lang.is_match("ab"));
+    assert!(!lang.is_match("a"));
+
+    // Matches one but not the other, and complementing twice.
+    let word = Regex::new(r"\w+").unwrap().language().unwrap();
+    let digits = Regex::new(r"\d+").unwrap().language().unwrap();
+    let lang = word.intersect(&digits.complement());
+    assert!(lang.is_match("x86"));
+    assert!(!lang.is_match("86"));
+    assert!(lang.complement().is_match("86"));
+    assert!(lang.complement().complement().is_match("x86"));
+
+    // Anchors, word boundaries, case insensitivity and counted loops work
+    // like they do in a search.
+    let lang = Regex::new(r"(?m)a$\n^b").unwrap().language().unwrap();
+    assert!(lang.is_match("a\nb"));
+    let lang = Regex::new(r"\bx\b.*").unwrap().language().unwrap();
+    assert!(lang.is_match("x y"));
+    assert!(!lang.is_match("xy"));
+    let lang = Regex::new(r"(?i)ab{100}").unwrap().language().unwrap();
+    let text = format!("A{}", repeat("B", 100));
+    assert!(lang.is_match(text.as_slice()));
+    assert!(!lang.is_match(text.as_slice().slice_from(1)));
+    assert!(lang.complement().is_match(format!("{}b", text).as_slice()));
+
+    // Expressions that need backtracking have no language.
+    assert!(backtrack(r"(a)\1").language().is_none());
+    assert!(backtrack(r"a(?=b)").complement().is_none());
+    assert!(regex!(r"a").intersect(&backtrack(r"(?>a)")).is_none());
+}
+
+#[test]
+fn language_one_pass() {
+    // A backtracking search for this takes exponential time, but deciding
+    // membership only reads the text once.
+    let lang = Regex::new(r"(?:a|a)*b").unwrap().complement().unwrap();
+    let text = repeat("a", 10000);
+    assert!(lang.is_match(text.as_slice()));
+    assert!(!lang.is_match(format!("{}b", text).as_slice()));
+}
+
+#[test]
+fn trim_matches() {
+    let re = regex!(r"\s+");
+    assert_eq!(re.trim_matches("  a b \n"), "a b");
+    assert_eq!(re.trim_left_matches("  a b \n"), "a b \n");
+    assert_eq!(re.trim_right_matches("  a b \n"), "  a b");
+    assert_eq!(re.trim_matches("   "), "");
+    assert_eq!(re.trim_matches(""), "");
+
+    let re = regex!(r"['\x22]");
+    assert_eq!(re.trim_matches("\"'a'\""), "a");
+    assert_eq!(regex!(r"ab|b").trim_matches("ab"), "");
+    assert_eq!(regex!(r"x*").trim_matches("abc"), "abc");
+    assert_eq!(regex!(r"^a").trim_left_matches("aab"), "ab");
+    assert_eq!(regex!(r"b$").trim_right_matches("abb"), "ab");
+    assert_eq!(backtrack(r"(\w)\1").trim_matches("aabcdd"), "bc");
+}
+
+#[test]
+fn parallel_find_all() {
+    let text = Arc::new(~"ab aab  b\nbaab☃ ab ba aaaab");
+    let pats = [r"a+b", r"\b", r"b*", r"a+ +b|☃", r"(?m)^b\w*|\n"];
+    for pat in pats.iter() {
+        let re = Arc::new(Regex::new(*pat).unwrap());
+        let all: Vec<(uint, uint)> = re.find_iter(text.as_slice()).collect();
+        for parts in range(1u, 12) {
+            assert_eq!(parallel::find_all(&re, &text, parts), all);
+        }
+    }
+    let re = Arc::new(Regex::new(r"a*").unwrap());
+    assert_eq!(parallel::find_all(&re, &Arc::new(~""), 4), vec!((0, 0)));
+}
+
+#[test]
+fn search_chunks() {
+    let re = regex!(r"\bfoo bar\b");
+    assert!(re.is_match_chunks(&["a fo", "o b", "", "ar"]));
+    assert!(!re.is_match_chunks(&["a fo", "o b", "", "ars"]));
+    assert!(!re.is_match_chunks(&["afo", "o bar"]));
+    assert!(!re.is_match_chunks(&[]));
+
+    let re = regex!(r"(?m)^\w+$");
+    let chunks = ["ab\n", "c", "d\n☃", "é"];
+    assert_eq!(re.find_chunks(&chunks, 0), Some((0, 2)));
+    assert_eq!(re.find_chunks(&chunks, 2), Some((3, 5)));
+    assert_eq!(re.find_chunks(&chunks, 4), None);
+    assert_eq!(regex!(r"é$").find_chunks(&chunks, 0), Some((9, 11)));
+    assert_eq!(regex!(r"^$").find_chunks(&["", ""], 0), Some((0, 0)));
+
+    let re = Regex::new(r"c\w+").unwrap();
+    assert_eq!(re.find_chunks(&["abc", "def", "g"], 0), Some((2, 7)));
+    let re = backtrack(r"(\w)\1");
+    assert_eq!(re.find_chunks(&["ab", "bc"], 0), Some((1, 3)));
+}
+
+// Reads a list of characters, as a test of searching an `Input`.
+struct CharList {
+    chars: Vec<char>,
+    // The index of the current character (which is past the end after
+    // advancing from the end) and its byte position.
+    i: uint,
+    pos: uint,
+}
+
+impl CharList {
+    fn next(&self) -> uint {
+        match self.cur() {
+            None => self.len() + 1,
+            Some(c) => self.pos + c.len_utf8_bytes(),
+        }
+    }
+}
+
+impl Input for CharList {
+    fn len(&self) -> uint {
+        self.chars.iter().fold(0, |n, c| n + c.len_utf8_bytes())
+    }
+    fn pos(&self) -> uint { self.pos }
+    fn set(&mut self, ic: uint) -> uint {
+        self.i = 0;
+        self.pos = 0;
+        while self.pos < ic && self.i < self.chars.len() {
+            self.pos += self.chars.get(self.i).len_utf8_bytes();
+            self.i += 1;
+        }
+        self.next()
+    }
+    fn advance(&mut self) -> uint {
+        if self.i < self.chars.len() {
+            self.pos += self.chars.get(self.i).len_utf8_bytes();
+        }
+        self.i += 1;
+        self.next()
+    }
+    fn prev(&self) -> Option<char> {
+        if self.i == 0 || self.i > self.chars.len() {
+            None
+        } else {
+            Some(*self.chars.get(self.i - 1))
+        }
+    }
+    fn cur(&self) -> Option<char> {
+        self.chars.as_slice().get(self.i).map(|&c| c)
+    }
+}
+
+#[test]
+fn feed() {
+    let re = regex!(r"[a-z]+@[a-z]+\.com");
+    let mut feed = re.feed();
+    assert_eq!(feed.state(), PartialMatch);
+    assert_eq!(feed.push("bob"), PartialMatch);
+    assert_eq!(feed.push("@ex"), PartialMatch);
+    assert_eq!(feed.push("ample.com"), FullMatch);
+    assert_eq!(feed.text(), "bob@example.com");
+    assert_eq!(feed.push("!"), NoMatch);
+    feed.clear();
+    assert_eq!(feed.push("@"), NoMatch);
+
+    let re = regex!(r"ab*");
+    let mut feed = re.feed();
+    assert_eq!(feed.push("a"), FullMatch);
+    assert_eq!(feed.push("bb"), FullMatch);
+    assert_eq!(feed.push("a"), NoMatch);
+
+    let re = regex!(r"a\Bb|c$");
+    let mut feed = re.feed();
+    assert_eq!(feed.push("a"), PartialMatch);
+    assert_eq!(feed.push("b"), FullMatch);
+    let mut feed = re.feed();
+    assert_eq!(feed.push("c"), FullMatch);
+    assert_eq!(feed.push("c"), NoMatch);
+
+    let re = backtrack(r"(a)\1");
+    let mut feed = re.feed();
+    assert_eq!(feed.push("a"), PartialMatch);
+    assert_eq!(feed.push("a"), FullMatch);
+}
+
+#[test]
+fn search_input() {
+    let input = |s: &str| CharList { chars: s.chars().collect(), i: 0, pos: 0 };
+    let re = regex!(r"\b\w+$");
+    assert!(re.is_match_input(input("a bé")));
+    assert!(!re.is_match_input(input("a bé ")));
+    assert_eq!(re.find_input(input("a bé"), 0), Some((2, 5)));
+    assert_eq!(Regex::new(r"é").unwrap().find_input(input("aé"), 0),
+               Some((1, 3)));
+    assert_eq!(regex!(r"a$").find_input(input("a"), 0), Some((0, 1)));
+    assert_eq!(regex!(r"a").find_input(input("a"), 1), None);
+    assert_eq!(backtrack(r"(\w)\1").find_input(input("xyy"), 0),
+               Some((1, 3)));
+}
+
+#[test]
+fn is_suffix_match() {
+    assert!(regex!(r"a|ab").is_suffix_match("xab"));
+    assert!(regex!(r"b+").is_suffix_match("abbb"));
+    assert!(!regex!(r"b+").is_suffix_match("abbba"));
+    assert!(regex!(r"").is_suffix_match(""));
+    assert!(regex!(r"x*").is_suffix_match("abc"));
+    assert!(regex!(r"^abc").is_suffix_match("abc"));
+    assert!(!regex!(r"^bc").is_suffix_match("abc"));
+    assert!(regex!(r"\bc").is_suffix_match("ab c"));
+    assert!(!regex!(r"\bc").is_suffix_match("abc"));
+    assert!(regex!(r"(?m)a$").is_suffix_match("b\na"));
+    assert!(backtrack(r"(\w)\1").is_suffix_match("abcc"));
+    assert!(!backtrack(r"(\w)\1").is_suffix_match("aabc"));
+}
+
+#[test]
+fn full_captures() {
+    let caps = regex!(r"(\w+?)(\d*)").full_captures("abc12").unwrap();
+    assert_eq!(caps.at(0), "abc12");
+    assert_eq!(caps.at(1), "abc");
+    assert_eq!(caps.at(2), "12");
+    assert!(regex!(r"(\d+)").full_captures("12a").is_none());
+
+    let re = RegexBuilder::new(r"a|ab|abc").alternates(true).build().unwrap();
+    assert_eq!(re.full_captures("ab").unwrap().alternate(), Some(1));
+}
+
+#[test]
+fn count_matches() {
+    assert_eq!(regex!(r"\d+").count_matches("1 22 a 333"), 3);
+    assert_eq!(regex!(r"x").count_matches("abc"), 0);
+    assert_eq!(regex!(r"").count_matches("☃a"), 3);
+    assert_eq!(regex!(r"a*").count_matches("a☃a"), 2);
+    assert_eq!(regex!(r"a*").count_matches("baaab"), 3);
+    assert_eq!(backtrack(r"(\w)\1").count_matches("aabbcd"), 2);
+}
+
+#[test]
+fn split_with_positions() {
+    let re = regex!(r"\d+");
+    let text = "cauchy123plato456tyler789binx";
+    let fields: Vec<(&str, uint, uint)> =
+        re.split_with_positions(text).collect();
+    assert_eq!(fields, vec!(("cauchy", 0, 6), ("plato", 9, 14),
+                            ("tyler", 17, 22), ("binx", 25, 29)));
+    for &(field, s, e) in fields.iter() {
+        assert_eq!(field, text.slice(s, e));
+    }
+
+    let fields: Vec<(&str, uint, uint)> =
+        re.split_with_positions("1a22").collect();
+    assert_eq!(fields, vec!(("", 0, 0), ("a", 1, 2)));
+}
+
+#[test]
+fn grep_lines() {
+    let text = "foo bar\r\nbaz\n\nbar bar$\nbar";
+    let re = regex!(r"bar$");
+    let got: Vec<(uint, uint, ~str, Vec<(uint, uint)>)> =
+        LineMatcher::new(&re, BufReader::new(text.as_bytes())).map(|m| {
+            let m = m.unwrap();
+            (m.line_number, m.offset, m.line, m.matches)
+        }).collect();
+    assert_eq!(got, vec![
+        (1, 0, ~"foo bar", vec![(4, 7)]),
+        (5, 23, ~"bar", vec![(0, 3)]),
+    ]);
+
+    let re = regex!(r"a");
+    // "a\n\xffa\n", which isn't valid UTF-8.
+    let invalid = ['a' as u8, '\n' as u8, 0xff, 'a' as u8, '\n' as u8];
+    let mut it = LineMatcher::new(&re, BufReader::new(invalid.as_slice()));
+    assert_eq!(it.next().unwrap().unwrap().line_number, 1);
+    assert!(it.next().unwrap().is_err());
+}
+
+#[test]
+fn scan_tokens() {
+    let scanner = Scanner::new(&[r"if\b", r"[a-z]+|_", r"\d+", r" +"]).unwrap();
+    let mut it = scanner.tokens("if iffy 12 _?");
+    let got: Vec<(uint, uint, uint)> = it.by_ref().collect();
+    assert_eq!(got, vec![(0, 0, 2), (3, 2, 3), (1, 3, 7), (3, 7, 8),
+                         (2, 8, 10), (3, 10, 11), (1, 11, 12)]);
+    assert_eq!(it.position(), 12);
+
+    // Matches are anchored, but assertions see the text before them.
+    assert_eq!(scanner.match_at("a 12", 1), Some((3, 2)));
+    assert_eq!(scanner.match_at("a 12", 0), Some((1, 1)));
+    assert_eq!(Scanner::new(&[r"^a"]).unwrap().match_at("aa", 1), None);
+
+    // An empty match stops the scan.
+    let scanner = Scanner::new(&[r"a*"]).unwrap();
+    let got: Vec<(uint, uint, uint)> = scanner.tokens("aab").collect();
+    assert_eq!(got, vec![(0, 0, 2)]);
+
+    assert_eq!(Scanner::new(&[]).unwrap().match_at("a", 0), None);
+    assert!(Scanner::new(&[r"a", r"(b"]).is_err());
+}
+
+#[deriving(Eq, Show)]
+enum Token { Keyword, Ident, Number, Space }
+
+#[test]
+fn lex_tokens() {
+    let lexer = lexer!(r"(?:if|else)\b" => Keyword, r"\w+" => Ident,
+                       r"\d+" => Number, r"\s+" => Space);
+    let mut it = lexer.lex("if x1 else 2;");
+    let got: Vec<(&Token, &str)> = it.by_ref().collect();
+    assert_eq!(got, vec![(&Keyword, "if"), (&Space, " "), (&Ident, "x1"),
+                         (&Space, " "), (&Keyword, "else"), (&Space, " "),
+                         (&Ident, "2")]);
+    assert_eq!(it.position(), 12);
+    assert_eq!(lexer.match_at("x 12", 2), Some((&Ident, 4)));
+
+    let lexer = Lexer::new(vec!((r"\d+", 10u), (r"[a-z]+", 20u))).unwrap();
+    assert_eq!(lexer.match_at("abc", 0), Some((&20u, 3)));
+    assert_eq!(lexer.scanner().patterns()[1].as_slice(), "[a-z]+");
+}
+
+#[test]
+fn rfind() {
+    assert_eq!(regex!(r"a+").rfind("aa baaa"), Some((4, 7)));
+    assert_eq!(regex!(r"a+?").rfind("aa baaa"), Some((6, 7)));
+    assert_eq!(regex!(r"a|ab").rfind("ab"), Some((0, 2)));
+    assert_eq!(regex!(r"^a").rfind("aa"), Some((0, 1)));
+    assert_eq!(regex!(r"a$").rfind("aa"), Some((1, 2)));
+    assert_eq!(regex!(r"\bfoo\b").rfind("foo foobar foo"), Some((11, 14)));
+    assert_eq!(regex!(r"\bfoo\b").rfind("foo foobar"), Some((0, 3)));
+    assert_eq!(regex!(r"☃+").rfind("a☃☃b"), Some((1, 7)));
+    assert_eq!(regex!(r"").rfind("☃"), Some((3, 3)));
+    assert_eq!(regex!(r"x").rfind("abc"), None);
+    assert_eq!(backtrack(r"(\w)\1").rfind("aabbcd"), Some((2, 4)));
+}
+
+#[test]
+fn rfind_iter() {
+    let got: Vec<(uint, uint)> = regex!(r"\w+").rfind_iter("ab cd e").collect();
+    assert_eq!(got, vec![(6, 7), (3, 5), (0, 2)]);
+    let got: Vec<(uint, uint)> = regex!(r"").rfind_iter("☃a").collect();
+    assert_eq!(got, vec![(4, 4), (3, 3), (0, 0)]);
+    let got: Vec<(uint, uint)> = regex!(r"a*").rfind_iter("a☃a").collect();
+    assert_eq!(got, vec![(4, 5), (0, 1)]);
+    let got: Vec<(uint, uint)> =
+        backtrack(r"(\w)\1").rfind_iter("aabbcc").collect();
+    assert_eq!(got, vec![(4, 6), (2, 4), (0, 2)]);
+}
+
+#[test]
+fn captures_len() {
+    assert_eq!(regex!(r"a").captures_len(), 1);
+    assert_eq!(regex!(r"(a)(?:b)(?P<c>c(d))").captures_len(), 4);
+    let re = regex!(r"(a)|(b)|(c)");
+    assert_eq!(re.captures_len(), re.captures("c").unwrap().len());
+}
+
+#[test]
+fn macro_small() {
+    let re = regex_small!(r"(?P<y>\d{4})-(?P<m>\d{2})");
+    let caps = re.captures("on 2014-05").unwrap();
+    assert_eq!(caps.name("m"), "05");
+    assert_eq!(regex_small!(r"a|b|[x-z]|foo").find("__foo"), Some((2, 5)));
+    assert_eq!(regex_small!(r"[a-z]+\d$").find("a1 ab2"), Some((3, 6)));
+    assert_eq!(regex_small!(r"\w+@gmail\.com").find("me@gmail.com"),
+               Some((0, 12)));
+    assert!(regex_small!(r"^foo$", "im").is_match("bar\nFOO"));
+    assert!(regex_small!(r"a+") == Regex::new(r"a+").unwrap());
+}
+
+#[test]
+fn eq_and_hash() {
+    assert!(regex!(r"a+") == Regex::new(r"a+").unwrap());
+    assert!(regex!(r"a+") != regex!(r"a*"));
+    let ascii = RegexBuilder::new(r"\d").unicode(false).build().unwrap();
+    assert!(Regex::new(r"\d").unwrap() != ascii);
+
+    let mut set = HashSet::new();
+    set.insert(regex!(r"a+"));
+    set.insert(Regex::new(r"a+").unwrap());
+    set.insert(regex!(r"b+"));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn share_between_tasks() {
+    fn assert_send_share<T: Send + Share>(_: &T) {}
+    assert_send_share(&regex!(r"a"));
+
+    let re = Arc::new(regex!(r"\d+"));
+    let (tx, rx) = channel();
+    for i in range(0u, 4) {
+        let (re, tx) = (re.clone(), tx.clone());
+        spawn(proc() {
+            let text = format!("task {}", i);
+            tx.send(re.find(text.as_slice()));
+        });
+    }
+    for _ in range(0, 4) {
+        assert_eq!(rx.recv(), Some((5, 6)));
+    }
+}
+
+#[deriving(Show)]
+struct Rule {
+    re: Regex,
+}
+
+#[test]
+fn show() {
+    assert_eq!(format!("{}", regex!(r"\d+")).as_slice(), r"\d+");
+    let rule = Rule { re: Regex::new(r"a|b").unwrap() };
+    assert_eq!(format!("{}", rule).as_slice(), r"Rule { re: a|b }");
+}
+
+#[test]
+fn syntax_round_trip() {
+    let patterns = [
+        r"a", r"abc", r"a|b|c", r"(?:a|b)|c", r"(a|b)c", r"a(?:b|c)d",
+        r"(?P<foo>a+?)(b*)(c?)", r"(?:ab)+", r"(?:a*)?", r"a{2,4}",
+        r"(?i)abc", r"(?im)^a$", r"(?s).", r"(?U)a*b*?", r"\\\.\[\]\{",
+        r"\bfoo\B", r"\A\z", r"\n\t\x00", r"[a-z0-9_]", r"[^]a]",
+        r"[]\\^\[-]", r"[:a]", r"[!--]", r"[[:alpha:]]", r"\d\S\pN",
+        r"(?i)[^a-c]", r"☃+(?:☺|x)", r"[*--]x", r"[a-c&&x-z]",
+        r"[^a-c&&x-z]", r"[\w&&[^_]]", r"a\Z", r"a{3}", r"(?:ab){2,}?",
+        r"[\x00-\x1F]", r"[!-\-]", r"[+-\\]", r"[0-\[]",
+        r"(?:)|a", r"(a|(?:))", r"(?:)*", r"(?-u)\bx\B", r"\b(?-u:\B)",
+    ];
+    for &pat in patterns.iter() {
+        let ast = syntax::parse(pat).unwrap();
+        let printed = ast.to_pattern();
+        let reparsed = match syntax::parse(printed.as_slice()) {
+            Ok(ast) => ast,
+            Err(err) => fail!("'{}' printed as '{}', which doesn't parse: {}",
+                              pat, printed, err),
+        };
+        assert!(format!("{}", ast) == format!("{}", reparsed),
+                "'{}' printed as '{}', which parses differently", pat, printed);
+    }
+}
+
+fn recovered_kinds(re: &str) -> Vec<ErrorKind> {
+    match syntax::parse_with_recovery(re, syntax::FLAG_UNICODE) {
+        Ok(_) => fail!("Regex '{}' should cause a parse error.", re),
+        Err(errs) => errs.move_iter().map(|err| err.kind).collect(),
+    }
+}
+
+#[test]
+fn syntax_recovery() {
+    assert_eq!(recovered_kinds(r"a**|[z-a]|\q"),
+               vec!(DoubleRepeat, InvalidRange('z', 'a'), InvalidEscape('q')));
+    assert_eq!(recovered_kinds(r"(a|)b("), vec!(EmptyGroup, UnclosedParen));
+    assert_eq!(recovered_kinds(r"(?a)b)"),
+               vec!(UnrecognizedFlag('a'), UnopenedParen));
+    assert_eq!(recovered_kinds(r"(?=a)\p{Foo}"),
+               vec!(BacktrackRequired, UnknownUnicodeClass(~"Foo")));
+    for &re in [r"a**b", r"(a", r"a|", r"[z-a]\q", r"(?a)b)"].iter() {
+        assert_eq!(*recovered_kinds(re).get(0), error_kind(re));
+    }
+    let ok = syntax::parse_with_recovery(r"a(b)c", syntax::FLAG_UNICODE);
+    assert!(ok.is_ok());
+}
+
+fn tokens(re: &str) -> Vec<(syntax::TokenKind, ~str)> {
+    syntax::tokenize(re, syntax::FLAG_UNICODE).move_iter().map(|t| {
+        let (s, e) = t.span;
+        (t.kind, re.slice(s, e).to_owned())
+    }).collect()
+}
+
+#[test]
+fn syntax_tokenize() {
+    use regex::syntax::{
+        LiteralToken, EscapeToken, ClassToken, RepeatToken, AnchorToken,
+        BackrefToken, GroupOpenToken, GroupCloseToken, FlagsToken, AltToken,
+        CommentToken, ErrorToken, FLAG_UNICODE, FLAG_NOCASE,
+        FLAG_IGNORE_SPACE,
+    };
+    let u = FLAG_UNICODE;
+    assert_eq!(tokens(r"^a.[b-c]*?\d{2,3}|\x41\b$"), vec!(
+        (AnchorToken, ~"^"), (LiteralToken, ~"a"), (ClassToken, ~"."),
+        (ClassToken, ~"[b-c]"), (RepeatToken, ~"*?"), (EscapeToken, ~"\\d"),
+        (RepeatToken, ~"{2,3}"), (AltToken, ~"|"), (EscapeToken, ~"\\x41"),
+        (AnchorToken, ~"\\b"), (AnchorToken, ~"$"),
+    ));
+    assert_eq!(tokens(r"(?P<n>δ)(?i:x)(?#note)(?i)"), vec!(
+        (GroupOpenToken(u), ~"(?P<n>"), (LiteralToken, ~"δ"),
+        (GroupCloseToken, ~")"), (GroupOpenToken(u | FLAG_NOCASE), ~"(?i:"),
+        (LiteralToken, ~"x"), (GroupCloseToken, ~")"),
+        (CommentToken, ~"(?#note)"), (FlagsToken(u | FLAG_NOCASE), ~"(?i)"),
+    ));
+    assert_eq!(tokens("(?x) a # b\n\\ "), vec!(
+        (FlagsToken(u | FLAG_IGNORE_SPACE), ~"(?x)"), (LiteralToken, ~"a"),
+        (CommentToken, ~"# b"), (EscapeToken, ~"\\ "),
+    ));
+    assert_eq!(tokens(r"a**\q(b"), vec!(
+        (LiteralToken, ~"a"), (ErrorToken, ~"*"), (RepeatToken, ~"*"),
+        (ErrorToken, ~"\\q"), (GroupOpenToken(u), ~"("),
+        (LiteralToken, ~"b"),
+    ));
+
+    let bt = FLAG_UNICODE | syntax::FLAG_BACKTRACK;
+    let toks: Vec<syntax::TokenKind> = syntax::tokenize(r"(?P<a>x)\1(?P=a)", bt)
+        .move_iter().map(|t| t.kind).collect();
+    assert_eq!(toks, vec!(GroupOpenToken(bt), LiteralToken, GroupCloseToken,
+                          BackrefToken, BackrefToken));
+    assert_eq!(syntax::tokenize("", u), vec!());
+}
+
+fn lints(re: &str) -> Vec<(LintKind, (uint, uint))> {
+    lint(re).unwrap().move_iter().map(|l| (l.kind, l.span)).collect()
+}
+
+#[test]
+fn lint_patterns() {
+    assert_eq!(lints(r"a()b(?:)(\b)(?=x)"), vec!(
+        (AlwaysEmptyGroup, (1, 3)), (AlwaysEmptyGroup, (4, 8)),
+        (AlwaysEmptyGroup, (8, 12)),
+    ));
+    assert_eq!(lints(r"(a+)*(?>b+)*(c+)?(d{2,})+"), vec!(
+        (NestedRepetition, (4, 5)), (NestedRepetition, (24, 25)),
+    ));
+    assert_eq!(lints(r"\#\ [\.x\]](?x)\#"), vec!(
+        (RedundantEscape, (0, 2)), (RedundantEscape, (2, 4)),
+        (RedundantEscape, (5, 7)),
+    ));
+    assert_eq!(lints(r"[A-z][a-z0-9][0-Z]"), vec!(
+        (SuspiciousRange, (1, 4)), (SuspiciousRange, (14, 17)),
+    ));
+    assert_eq!(lints(r"[a&&b][^\x00-\x{10FFFF}][a&&a]"), vec!(
+        (EmptyClass, (0, 6)), (EmptyClass, (6, 24)),
+    ));
+    assert_eq!(lints(r"[:alpha:][[:alpha:]]"), vec!(
+        (BarePosixClass, (0, 9)),
+    ));
+    assert_eq!(lints(r"(?P<word>\w+)\s+(?P=word)"), vec!());
+    assert!(lint("(a").is_err());
+}
+
 macro_rules! replace(
     ($name:ident, $which:ident, $re:expr,
      $search:expr, $replace:expr, $result:expr) => (
@@ -50,9 +1478,37 @@ replace!(rep_no_expand, replace,
 replace!(rep_named, replace_all,
          r"(?P<first>\S+)\s+(?P<last>\S+)(?P<space>\s*)",
          "w1 w2 w3 w4", "$last $first$space", "w2 w1 w4 w3")
+replace!(rep_named_short, replace_all,
+         r"(?<first>\S+)\s+(?<last>\S+)(?<space>\s*)",
+         "w1 w2 w3 w4", "$last $first$space", "w2 w1 w4 w3")
 replace!(rep_trim, replace_all, "^[ \t]+|[ \t]+$", " \t  trim me\t   \t",
          "", "trim me")
 
+#[test]
+fn replacen_no_expansion() {
+    let re = regex!(r"\d");
+    assert_eq!(re.replacen("1 2 3", 2, "x"), StrBuf::from_str("x x 3"));
+    assert_eq!(re.replacen("1 2 3", 2, NoExpand("$0")),
+               StrBuf::from_str("$0 $0 3"));
+    assert_eq!(re.replacen("1 2 3", 0, "<$0>"),
+               StrBuf::from_str("<1> <2> <3>"));
+}
+
+#[test]
+fn replace_cow() {
+    let re = regex!(r"\d");
+    match re.replace_all_cow("no digits", "x") {
+        Slice(text) => assert_eq!(text, "no digits"),
+        Owned(_) => fail!("text without a match should be borrowed"),
+    }
+    match re.replace_all_cow("1 2 3", NoExpand("$0")) {
+        Owned(new) => assert_eq!(new.as_slice(), "$0 $0 $0"),
+        Slice(_) => fail!("text with a match should be replaced"),
+    }
+    assert_eq!(re.replace_cow("1 2", "<$0>").as_slice(), "<1> 2");
+    assert_eq!(re.replacen_cow("1 2 3", 2, "x").as_slice(), "x x 3");
+}
+
 macro_rules! noparse(
     ($name:ident, $re:expr) => (
         #[test]
@@ -66,6 +1522,65 @@ macro_rules! noparse(
     );
 )
 
+fn error_kind(re: &str) -> ErrorKind {
+    match Regex::new(re) {
+        Ok(_) => fail!("Regex '{}' should cause a parse error.", re),
+        Err(err) => err.kind,
+    }
+}
+
+#[test]
+fn error_kinds() {
+    assert_eq!(error_kind(r"(a"), UnclosedParen);
+    assert_eq!(error_kind(r"a)"), UnopenedParen);
+    assert_eq!(error_kind(r"a|"), EmptyGroup);
+    assert_eq!(error_kind(r"a**"), DoubleRepeat);
+    assert_eq!(error_kind(r"a{100001}"),
+               RepetitionTooLarge(100001, 100000));
+    assert_eq!(error_kind(r"((a{100}){100}){100}"),
+               RepetitionTooLarge(1000000, 100000));
+    assert_eq!(error_kind(r"(?:a{2,}b{400}){300,}"),
+               RepetitionTooLarge(120000, 100000));
+    assert_eq!(error_kind(r"a{3,2}"), InvalidRepetitionRange(3, 2));
+    assert_eq!(error_kind(r"[z-a]"), InvalidRange('z', 'a'));
+    assert_eq!(error_kind(r"\q"), InvalidEscape('q'));
+    assert_eq!(error_kind(r"\c1"), InvalidControl('1'));
+    assert_eq!(error_kind(r"\p{Foo}"), UnknownUnicodeClass(~"Foo"));
+    assert_eq!(error_kind(r"(?P<a>x)(?P<a>y)"), DuplicateCaptureName(~"a"));
+    assert_eq!(error_kind(r"(?a)a"), UnrecognizedFlag('a'));
+    assert_eq!(error_kind(r"(?=a)"), BacktrackRequired);
+    assert_eq!(error_kind(r"(?!a)"), BacktrackRequired);
+    assert_eq!(error_kind(r"(?<=a)b"), BacktrackRequired);
+    assert_eq!(error_kind(r"(?<!a)b"), BacktrackRequired);
+    assert_eq!(error_kind(r"(?>a)"), BacktrackRequired);
+    assert_eq!(error_kind(r"(a)\1"), BacktrackRequired);
+    assert_eq!(error_kind(r"(a)(b)\2"), BacktrackRequired);
+    assert_eq!(error_kind(r"\8"), BacktrackRequired);
+
+    let err = RegexBuilder::new(r"(a)\2").backtrack(true).build().err();
+    assert_eq!(err.unwrap().kind, UndefinedGroup(2));
+}
+
+fn error_span(re: &str) -> ((uint, uint), ~str) {
+    match Regex::new(re) {
+        Ok(_) => fail!("Regex '{}' should cause a parse error.", re),
+        Err(err) => (err.span, err.caret(re)),
+    }
+}
+
+#[test]
+fn error_spans() {
+    assert_eq!(error_span(r"a{100001}"), ((1, 9), ~"a{100001}\n ^~~~~~~~"));
+    assert_eq!(error_span(r"(a{1000}){101}"),
+               ((9, 14), ~"(a{1000}){101}\n         ^~~~~"));
+    assert_eq!(error_span(r"ab\q"), ((2, 4), ~"ab\\q\n  ^~"));
+    assert_eq!(error_span(r"(?a)"), ((2, 3), ~"(?a)\n  ^"));
+    assert_eq!(error_span(r"(?i-)"), ((0, 5), ~"(?i-)\n^~~~~"));
+    assert_eq!(error_span(r"\p{Foo}"), ((0, 7), ~"\\p{Foo}\n^~~~~~~"));
+    assert_eq!(error_span(r"\x{12"), ((0, 5), ~"\\x{12\n^~~~~"));
+    assert_eq!(error_span(r"☃\q"), ((3, 5), ~"☃\\q\n ^~"));
+}
+
 noparse!(fail_double_repeat, "a**")
 noparse!(fail_no_repeat_arg, "*")
 noparse!(fail_no_repeat_arg_begin, "^*")
@@ -76,29 +1591,49 @@ noparse!(fail_class_no_begin, r"[\A]")
 noparse!(fail_class_no_end, r"[\z]")
 noparse!(fail_class_no_boundary, r"[\b]")
 noparse!(fail_open_paren, "(")
+noparse!(fail_unclosed_comment, "a(?#b")
 noparse!(fail_close_paren, ")")
 noparse!(fail_invalid_range, "[a-Z]")
 noparse!(fail_empty_capture_name, "(?P<>a)")
-noparse!(fail_empty_capture_exp, "(?P<name>)")
 noparse!(fail_bad_capture_name, "(?P<na-me>)")
 noparse!(fail_bad_flag, "(?a)a")
 noparse!(fail_empty_alt_before, "|a")
 noparse!(fail_empty_alt_after, "a|")
-noparse!(fail_counted_big_exact, "a{1001}")
-noparse!(fail_counted_big_min, "a{1001,}")
+noparse!(fail_counted_big_exact, "a{100001}")
+noparse!(fail_counted_big_min, "a{100001,}")
 noparse!(fail_counted_no_close, "a{1001")
+noparse!(fail_counted_empty, "a{,}")
+noparse!(fail_counted_big_max_only, "a{,100001}")
+noparse!(fail_counted_big_nested, "(?:a{1000}|b){101}")
 noparse!(fail_unfinished_cap, "(?")
 noparse!(fail_unfinished_escape, "\\")
 noparse!(fail_octal_digit, r"\8")
 noparse!(fail_hex_digit, r"\xG0")
 noparse!(fail_hex_short, r"\xF")
 noparse!(fail_hex_long_digits, r"\x{fffg}")
+noparse!(fail_hex_u_short, r"\u00e")
+noparse!(fail_hex_u_digit, r"\u00eg")
+noparse!(fail_hex_big_u_short, r"\U0001F60")
+noparse!(fail_hex_big_u_invalid, r"\U00110000")
+noparse!(fail_hex_u_surrogate, r"\uD800")
 noparse!(fail_flag_bad, "(?a)")
 noparse!(fail_flag_empty, "(?)")
 noparse!(fail_double_neg, "(?-i-i)")
 noparse!(fail_neg_empty, "(?i-)")
-noparse!(fail_empty_group, "()")
+noparse!(fail_empty_alt_in_group, "(a|)")
 noparse!(fail_dupe_named, "(?P<a>.)(?P<a>.)")
+noparse!(fail_dupe_named_mixed, "(?P<a>.)(?<a>.)")
+noparse!(fail_empty_capture_name_short, "(?<>a)")
+noparse!(fail_unicode_name_unknown, r"\p{Lettr}")
+noparse!(fail_class_linebreak, r"[\R]")
+noparse!(fail_class_final_newline, r"[\Z]")
+noparse!(fail_backref_named_no_backtrack, r"(?P<a>x)(?P=a)")
+noparse!(fail_lookahead_no_backtrack, r"a(?=b)")
+noparse!(fail_lookahead_negative_no_backtrack, r"a(?!b)")
+noparse!(fail_lookbehind_no_backtrack, r"(?<=a)b")
+noparse!(fail_lookbehind_negative_no_backtrack, r"(?<!a)b")
+noparse!(fail_atomic_no_backtrack, r"(?>a)")
+noparse!(fail_possessive_no_backtrack, r"a++")
 
 macro_rules! mat(
     ($name:ident, $re:expr, $text:expr, $($loc:tt)+) => (
@@ -162,8 +1697,196 @@ mat!(match_flag_case_dotnl_toggle_not, "(?is)a.(?-is)a.", "A\na\n", None)
 mat!(match_flag_case_dotnl_toggle_ok, "(?is)a.(?-is:a.)?", "A\na\n", Some((0, 2)))
 mat!(match_flag_multi, "(?m)(?:^\\d+$\n?)+", "123\n456\n789", Some((0, 11)))
 mat!(match_flag_ungreedy, "(?U)a+", "aa", Some((0, 1)))
+mat!(match_comment, r"a(?#comment)b", "ab", Some((0, 2)))
+mat!(match_comment_repeat, r"a(?#x)+", "aaa", Some((0, 3)))
+mat!(match_comment_parens, r"(?#(not a group)a(b)", "ab",
+     Some((0, 2)), Some((1, 2)))
 mat!(match_flag_ungreedy_greedy, "(?U)a+?", "aa", Some((0, 2)))
 mat!(match_flag_ungreedy_noop, "(?U)(?-U)a+", "aa", Some((0, 2)))
+mat!(match_flag_negate_keeps_others, "(?i)(?-s)a", "A", Some((0, 1)))
+
+// Exercise the literal prefix scan. The haystacks are long enough to be
+// scanned a word at a time.
+mat!(prefix_one_byte, r"y.", "xxxxxxxxxxxxxxxxxxxxxxxxyz", Some((24, 26)))
+mat!(prefix_first_byte_repeats, r"abcd",
+     "abcabcabcabcabcabcabcabcabcd", Some((24, 28)))
+mat!(prefix_dot_star, r"foo.*bar", "xxxx xx foo yy bar", Some((8, 18)))
+mat!(prefix_not_found, r"foo.*bar", "fofofofofofofofofo bar", None)
+mat!(prefix_unicode, r"☃.", "xxxxxxxxxxxxxxxxx☃☃", Some((17, 23)))
+mat!(prefix_long, r"abcdefghij\d", "abcdefghi abcdefghij abcdefghij1",
+     Some((21, 32)))
+mat!(prefix_long_repeats, r"aaaaaaaab", "aaaaaaaaaaaaaaaaab", Some((9, 18)))
+mat!(prefix_long_not_found, r"abcdefghij", "abcdefghiabcdefghi", None)
+mat!(prefix_long_unicode, r"☃☃☃☃ snowmen", "☃☃☃ snowmen ☃☃☃☃ snowmen",
+     Some((18, 38)))
+mat!(prefix_set, r"agggtaaa|tttaccct", "xxtttaccctxxagggtaaa", Some((2, 10)))
+mat!(prefix_set_overlap, r"abcd|bc", "xabcd", Some((1, 5)))
+mat!(prefix_set_overlap_later, r"bcde|abcdz", "abcdefabcdz", Some((1, 5)))
+mat!(prefix_set_captures, r"(foo|ba(r|z))\d", "ba foo bar baz1", Some((11, 15)))
+mat!(prefix_set_not_found, r"foo|bar", "fobabaz", None)
+mat!(prefix_set_nested_alt, r"(?:Jan|Feb)uary", "Janury Febuary", Some((7, 14)))
+mat!(prefix_set_class, r"[Ff]oo\d", "foo Foo1", Some((4, 8)))
+mat!(prefix_set_class_range, r"x[a-c]y", "xdy xby", Some((4, 7)))
+mat!(prefix_set_class_unicode, r"[☃☺]+!", "☃ ☺☃!", Some((4, 11)))
+mat!(prefix_nocase, r"(?i)error:", "errors: x ErRoR: y", Some((10, 16)))
+mat!(prefix_nocase_long, r"(?i)abcdefghijk", "xxABCDEFGHIJk", Some((2, 13)))
+mat!(prefix_nocase_long_s, r"(?i)sx", "ab\u017fx", Some((2, 5)))
+mat!(prefix_nocase_dotless_i, r"(?i)ix", "ab\u0131X", Some((2, 5)))
+mat!(prefix_nocase_not_found, r"(?i)foo", "fo fo of", None)
+mat!(suffix_anchored, r"[a-z]+xyz$", "123 abcxyz", Some((4, 10)))
+mat!(suffix_anchored_not_at_end, r"[a-z]+xyz$", "abcxyz ", None)
+mat!(suffix_anchored_leftmost, r"a*b*xyz$", "aaxyz bbxyz aabbxyz",
+     Some((12, 19)))
+mat!(suffix_anchored_begin, r"^a+xyz$", "aaaxyz", Some((0, 6)))
+mat!(suffix_anchored_begin_not, r"^a+xyz$", "baaaxyz", None)
+mat!(suffix_anchored_captures, r"(\w+) (\w+)xyz$", "ab cd efxyz",
+     Some((3, 11)), Some((3, 5)), Some((6, 8)))
+mat!(suffix_anchored_boundary, r"\bxyz$", "axyz bxyz xyz", Some((10, 13)))
+mat!(suffix_anchored_unicode, r"☃+xyz$", "☃ ☃☃xyz", Some((4, 13)))
+mat!(anchored_end_class, r"[a-z]+$", "abc 123 xyz", Some((8, 11)))
+mat!(anchored_end_alt, r"(?:a+|b+)$", "aab bba", Some((6, 7)))
+mat!(anchored_end_alt_not, r"a$|b", "xab", Some((2, 3)))
+mat!(anchored_end_empty, r"$", "abc", Some((3, 3)))
+mat!(anchored_end_lazy, r"(a+?)(a*)$", "baaa",
+     Some((1, 4)), Some((1, 2)), Some((2, 4)))
+mat!(inner_literal, r"\w+@gmail\.com", "foo@yahoo.com bar@gmail.com",
+     Some((14, 27)))
+mat!(inner_literal_not_found, r"\w+@gmail\.com", "foo@gmail.co", None)
+mat!(inner_literal_bounded, r"\d{2}-\d{2}-foo", "12-34-56-78-foo", Some((6, 15)))
+mat!(inner_literal_later, r"[a-z]{1,3}=x", "abc=y abc=x", Some((6, 11)))
+mat!(inner_literal_begin, r"^.{0,2}ab", "xxxxab", None)
+mat!(inner_literal_unicode, r"☃?☃?foo", "☃☃☃☃☃foo", Some((9, 18)))
+mat!(inner_literal_char_boundary, r".?.?foo", "☃☃☃☃☃foo", Some((9, 18)))
+mat!(inner_literal_nocase_len, r"(?i)ssx(?-i)foo", "ſſxfoo", Some((0, 8)))
+
+mat!(simplify_alt_chars, r"a|b|[x-z]|foo", "foo y", Some((0, 3)))
+mat!(simplify_alt_chars_order, r"x|xyz|y", "xyz", Some((0, 1)))
+mat!(simplify_alt_chars_casei, r"(?i)a|B", "b", Some((0, 1)))
+mat!(simplify_alt_captures, r"(a|b)|(c)", "c", Some((0, 1)), None, Some((0, 1)))
+mat!(simplify_rep_rep, r"(?:a+)*b", "aaab", Some((0, 4)))
+mat!(simplify_rep_rep_lazy, r"(?:a+?)*?", "aaa", Some((0, 0)))
+
+mat!(flag_x_space, r"(?x) a b c ", "abc", Some((0, 3)))
+mat!(flag_x_newline, "(?x)a\n\tb", "ab", Some((0, 2)))
+mat!(flag_x_comment, "(?x)a # comment\nb", "ab", Some((0, 2)))
+mat!(flag_x_comment_end, "(?x)a # comment", "a", Some((0, 1)))
+mat!(flag_x_escaped, r"(?x)a\ \#b", "a #b", Some((0, 4)))
+mat!(flag_x_class, r"(?x)a[ #]b", "a#b", Some((0, 3)))
+mat!(flag_x_rep, r"(?x)a +", "aaa", Some((0, 3)))
+mat!(flag_x_group, r"a (?x: b c ) d", "a bc d", Some((0, 6)))
+mat!(flag_x_off, r"(?x)a b(?-x) c", "ab c", Some((0, 4)))
+
+mat!(quoted, r"\Q.*?\E", ".*?", Some((0, 3)))
+mat!(quoted_no_meta, r"\Q.*?\E", "abc", None)
+mat!(quoted_surrounded, r"a\Q+\Eb", "a+b", Some((0, 3)))
+mat!(quoted_unclosed, r"a\Q(|)", "a(|)", Some((0, 4)))
+mat!(quoted_empty, r"a\Q\Eb", "ab", Some((0, 2)))
+mat!(quoted_escape, r"\Q\d\E", r"\d", Some((0, 2)))
+mat!(quoted_rep_last, r"\Qab\E+", "abbb", Some((0, 4)))
+mat!(quoted_nocase, r"(?i)\QaB\E", "Ab", Some((0, 2)))
+mat!(quoted_flag_x, r"(?x)\Qa b\E", "a b", Some((0, 3)))
+
+mat!(ascii_class, r"[[:alpha:]]+", "1aB2", Some((1, 3)))
+mat!(ascii_class_negated, r"[[:^alpha:]]+", "a12b", Some((1, 3)))
+mat!(ascii_class_in_negated, r"[^[:alpha:]]+", "a12b", Some((1, 3)))
+mat!(ascii_class_union, r"[[:digit:][:punct:]_]+", "a1_!b", Some((1, 4)))
+mat!(ascii_class_mixed, r"[x[:digit:]]+", "ax1b", Some((1, 3)))
+mat!(ascii_class_nocase, r"(?i)[[:lower:]]+", "1aB2", Some((1, 3)))
+mat!(ascii_class_bare, r"[:alpha:]+", "1aB2", Some((1, 3)))
+mat!(ascii_class_not_name, r"[:a]+", "b:a", Some((1, 3)))
+
+mat!(counted_max_only, r"a{,3}", "aaaa", Some((0, 3)))
+mat!(counted_max_only_zero, r"ba{,2}", "b", Some((0, 1)))
+mat!(counted_max_only_lazy, r"a{,3}?", "aaa", Some((0, 0)))
+
+mat!(empty_group, r"()", "abc", Some((0, 0)), Some((0, 0)))
+mat!(empty_group_cat, r"a()b", "ab", Some((0, 2)), Some((1, 1)))
+mat!(empty_group_named, r"a(?P<name>)", "ab", Some((0, 1)), Some((1, 1)))
+mat!(empty_group_noncapture, r"a(?:)b", "ab", Some((0, 2)))
+mat!(empty_group_rep, r"a(?:)*b", "ab", Some((0, 2)))
+mat!(empty_group_nested, r"(())", "a", Some((0, 0)), Some((0, 0)),
+     Some((0, 0)))
+mat!(empty_group_flags, r"(?i:)a", "A", Some((0, 1)))
+
+mat!(hex_u, r"\u00e9", "aé", Some((1, 3)))
+mat!(hex_u_rep, r"\u03bb+", "λλ", Some((0, 4)))
+mat!(hex_big_u, r"\U0001F600", "a😀", Some((1, 5)))
+mat!(hex_u_nocase, r"(?i)\u00C9", "é", Some((0, 2)))
+mat!(hex_u_class, r"[\u0041-\u005A]+", "aBCd", Some((1, 3)))
+
+mat!(octal_no_group, r"\1", "a\x01", Some((1, 2)))
+mat!(octal_after_group, r"(a)\2", "a\x02", Some((0, 2)), Some((0, 1)))
+mat!(octal_leading_zero, r"(a)\01", "a\x01", Some((0, 2)), Some((0, 1)))
+
+mat!(control_upper, r"\cA\cZ", "a\x01\x1A", Some((1, 3)))
+mat!(control_lower, r"\cm\cj", "a\r\n", Some((1, 3)))
+mat!(control_class, r"[\cI\cJ]+", "a\t\nb", Some((1, 3)))
+mat!(control_escape, r"\e\[\d+m", "a\x1B[31mb", Some((1, 6)))
+noparse!(fail_control_eof, r"\c")
+noparse!(fail_control_non_letter, r"\c[")
+noparse!(fail_control_non_ascii, r"\cé")
+
+mat!(class_range_escapes, r"[\x00-\x1F]+", "a\x00\t\x1Fb", Some((1, 4)))
+mat!(class_range_escape_start, r"[\t-z]+", "\x08\n0z{", Some((1, 4)))
+mat!(class_range_escape_end, r"[\t-\r]+", "a\t\x0B\rb", Some((1, 4)))
+mat!(class_range_escape_punct, r"[!-\-]+", "a!+-.", Some((1, 4)))
+mat!(class_range_escape_octal, r"[\101-\132]+", "aBCd", Some((1, 3)))
+mat!(class_escaped_close, r"[\]a]+", "b]a]", Some((1, 4)))
+mat!(class_escaped_close_range, r"[\[-\]]+", "a[\\]b", Some((1, 4)))
+noparse!(fail_class_range_escape_class, r"[a-\d]")
+noparse!(fail_class_range_escape_reverse, r"[\x41-\x40]")
+
+mat!(class_intersect, r"[a-z&&[^aeiou]]+", "bcdefg", Some((0, 3)))
+mat!(class_intersect_nested, r"[\w&&[^_]]+", "ab_c", Some((0, 2)))
+mat!(class_intersect_empty, r"x[a-c&&x-z]|y", "xa y", Some((3, 4)))
+mat!(class_subtract, r"[a-z--aeiou]+", "bcdefg", Some((0, 3)))
+mat!(class_subtract_named, r"[\p{Greek}--\p{Lu}]+", "ΔδΔ", Some((2, 4)))
+mat!(class_symdiff, r"[a-c~~b-d]+", "adbc", Some((0, 2)))
+mat!(class_setop_chain, r"[a-z--a--b&&a-d]+", "abcde", Some((2, 4)))
+mat!(class_setop_negated, r"[^a-z&&[^aeiou]]+", "bae", Some((1, 3)))
+mat!(class_setop_trailing_dash, r"[+--]+", "+,-", Some((0, 3)))
+mat!(class_setop_literal_amp, r"[&&]+", "&&", Some((0, 2)))
+
+mat!(flag_no_unicode_d, r"(?-u)\d+", "١٢3", Some((4, 5)))
+mat!(flag_no_unicode_s, r"(?-u)\s", "　 ", Some((3, 4)))
+mat!(flag_no_unicode_w, r"(?-u:\w+)", "δab", Some((2, 4)))
+mat!(flag_no_unicode_w_neg, r"(?-u)\W", "δ", Some((0, 2)))
+mat!(flag_no_unicode_class, r"(?-u)[\w]+", "δab", Some((2, 4)))
+mat!(flag_no_unicode_group_ends, r"(?-u:\d)\d", "1١", Some((0, 3)))
+mat!(flag_no_unicode_restore, r"(?-u)(?u)\d", "١", Some((0, 2)))
+mat!(flag_no_unicode_nocase, r"(?i-u)a\w", "Aδab", Some((3, 5)))
+
+mat!(perl_h, r"\h+", "a \t b", Some((1, 4)))
+mat!(perl_h_not_newline, r"\h", "\n", None)
+mat!(perl_h_neg, r"\H+", " ab ", Some((1, 3)))
+mat!(perl_v, r"\v+", "a\r\n\x0B\x0Cb", Some((1, 5)))
+mat!(perl_v_not_space, r"\v", " \t", None)
+mat!(perl_v_neg, r"\V+", "\nab\n", Some((1, 3)))
+mat!(perl_hv_class, r"[\h\v]+", "a \n\tb", Some((1, 4)))
+mat!(perl_h_neg_class, r"[^\h]+", " ab ", Some((1, 3)))
+mat!(perl_h_no_unicode, r"(?-u)\h", "　 ", Some((3, 4)))
+
+mat!(linebreak_crlf, r"a\Rb", "a\r\nb", Some((0, 4)))
+mat!(linebreak_lf, r"a\Rb", "a\nb", Some((0, 3)))
+mat!(linebreak_cr, r"a\Rb", "a\rb", Some((0, 3)))
+mat!(linebreak_unicode, r"a\Rb", "a\u2028b", Some((0, 5)))
+mat!(linebreak_unit, r"^\R$", "\r\n", Some((0, 2)))
+mat!(linebreak_rep, r"\R+", "a\n\r\n\rb", Some((1, 5)))
+mat!(linebreak_no_unicode, r"(?-u)\R", "\u2028\n", Some((3, 4)))
+
+mat!(end_final_newline, r"a\Z", "a", Some((0, 1)))
+mat!(end_final_newline_before, r"a\Z", "a\n", Some((0, 1)))
+mat!(end_final_newline_two, r"a\Z", "a\n\n", None)
+mat!(end_final_newline_middle, r"a\Z", "a\nb", None)
+mat!(end_final_newline_multi, r"(?m)a\Z", "a\nb", None)
+mat!(end_final_newline_empty, r"\Z", "\n", Some((0, 0)))
+mat!(end_final_newline_reverse, r"a\Z\n$", "a\n", Some((0, 2)))
+mat!(end_final_newline_reverse_not, r"a\Z\n$", "a\n\n", None)
+
+mat!(named_short, r"(?<a>x)(y)", "xy", Some((0, 2)), Some((0, 1)),
+     Some((1, 2)))
+mat!(named_short_mixed, r"(?P<a>x)(?<b>y)", "xy", Some((0, 2)), Some((0, 1)),
+     Some((1, 2)))
 
 // Some Unicode tests.
 mat!(uni_literal, r"Ⅰ", "Ⅰ", Some((0, 3)))
@@ -191,8 +1914,104 @@ mat!(uni_perl_s_not, r"\s+", "☃", None)
 mat!(uni_perl_s_neg, r"\S+", "☃", Some((0, 3)))
 
 // And do the same for word boundaries.
+// Unicode class names are matched loosely.
+mat!(uni_loose_case, r"\p{greek}+", "λόγος", Some((0, 10)))
+mat!(uni_loose_space, r"\p{ Letter }+", "aδ1", Some((0, 3)))
+mat!(uni_loose_underscore, r"\p{old_italic}", "𐌀", Some((0, 4)))
+mat!(uni_loose_long_name, r"\p{Lowercase_Letter}+", "aδA", Some((0, 3)))
+mat!(uni_loose_long_name_neg, r"\P{uppercase-letter}+", "aδA", Some((0, 3)))
+mat!(uni_loose_short, r"\p{lu}", "aδA", Some((3, 4)))
+mat!(uni_loose_one_letter, r"\pn", "a1", Some((1, 2)))
+mat!(uni_loose_scx, r"\p{scx:GREEK}", "͂", Some((0, 2)))
+
+// Binary properties.
+mat!(uni_prop_alphabetic, r"\p{Alphabetic}+", "aδⅡ1", Some((0, 6)))
+mat!(uni_prop_white_space, r"\p{White_Space}+", "a  \u0085b",
+     Some((1, 7)))
+mat!(uni_prop_cased, r"\p{Cased}+", "ʰaA1", Some((0, 4)))
+mat!(uni_prop_uppercase_neg, r"\P{Uppercase}", "Ⅱa", Some((3, 4)))
+mat!(uni_prop_hex_digit, r"\p{ASCII_Hex_Digit}+", "f0Ag", Some((0, 3)))
+mat!(uni_prop_loose, r"\p{white space}", "a b", Some((1, 2)))
+mat!(uni_prop_class, r"[\p{Math}\d]+", "1+2=3", Some((0, 5)))
+
+mat!(uni_perl_h, r"\h+", "a　 b", Some((1, 5)))
+mat!(uni_perl_v, r"\v+", "a\u2028\u0085b", Some((1, 6)))
+
+// Script extensions include characters that are shared between scripts.
+mat!(uni_scx, r"\p{scx:Greek}", "͂", Some((0, 2)))
+mat!(uni_scx_not_script, r"\p{Greek}", "͂", None)
+mat!(uni_scx_script, r"\p{scx:Greek}+", "λόγος", Some((0, 10)))
+mat!(uni_scx_shared, r"\p{scx:Devanagari}\p{scx:Bengali}", "॑॑",
+     Some((0, 6)))
+mat!(uni_scx_negated, r"\P{scx:Greek}", "͂a", Some((2, 3)))
+
+mat!(uni_prop_value_gc, r"\p{gc=Lu}+", "aΔA1", Some((1, 4)))
+mat!(uni_prop_value_gc_long, r"\p{General_Category=Lowercase_Letter}+",
+     "Aδa1", Some((1, 4)))
+mat!(uni_prop_value_script, r"\p{Script=Greek}+", "aλόγος", Some((1, 11)))
+mat!(uni_prop_value_sc, r"\P{sc=greek}", "λa", Some((2, 3)))
+mat!(uni_prop_value_scx, r"\p{scx=Greek}", "͂", Some((0, 2)))
+mat!(uni_prop_value_scx_long, r"\p{Script_Extensions=Greek}", "͂",
+     Some((0, 2)))
+mat!(uni_prop_value_class, r"[\p{sc=Greek}\p{gc=Nd}]+", "aδ1", Some((1, 4)))
+noparse!(fail_uni_prop_value_gc_script, r"\p{gc=Greek}")
+noparse!(fail_uni_prop_value_script_gc, r"\p{Script=Lu}")
+noparse!(fail_uni_prop_value_script_binary, r"\p{sc=Alphabetic}")
+noparse!(fail_uni_prop_value_unknown, r"\p{Foo=Greek}")
+
+mat!(uni_block, r"\p{Block=Greek_and_Coptic}+", "aͰϿ", Some((1, 5)))
+mat!(uni_block_short, r"\p{blk=greek and coptic}", "͂", Some((0, 2)))
+mat!(uni_block_in, r"\p{InBasic_Latin}+", "é~ab", Some((2, 5)))
+mat!(uni_block_in_alias, r"\p{InGreek}+", "aͰϿ", Some((1, 5)))
+mat!(uni_block_alias, r"\p{Block=Latin-1}", "aé", Some((1, 3)))
+mat!(uni_block_negated, r"\P{InGreek}", "λa", Some((2, 3)))
+mat!(uni_block_not_script, r"\p{InGreek}", "ἀ", None)
+mat!(uni_block_astral, r"\p{InEmoticons}", "a😀", Some((1, 5)))
+mat!(uni_block_class_name, r"\p{Inherited}", "\u0300", Some((0, 2)))
+noparse!(fail_uni_block_unknown, r"\p{InFoo}")
+
 mat!(uni_boundary_none, r"\d\b", "6δ", None)
 mat!(uni_boundary_ogham, r"\d\b", "6 ", Some((0, 1)))
+mat!(uni_boundary_french, r"\bété\b", "en été.", Some((3, 8)))
+mat!(uni_boundary_french_inner, r"\bt", "été", None)
+mat!(uni_boundary_greek, r"\bλόγος\b", "ο λόγος.", Some((3, 13)))
+mat!(uni_boundary_greek_not, r"\Bγ", "λόγος", Some((4, 6)))
+// Without the `u` flag, word boundaries only know about ASCII, like `\w`.
+mat!(ascii_boundary_none, r"(?-u)\bé", "é", None)
+mat!(ascii_boundary_after, r"(?-u:\b)x", "éx", Some((2, 3)))
+mat!(ascii_boundary_not, r"(?-u)\Bé", "éé", Some((0, 2)))
+
+#[test]
+fn conformance_dat() {
+    let files = [
+        ("basic", include_str!("../testdata/basic.dat"), 204u),
+        ("nullsubexpr", include_str!("../testdata/nullsubexpr.dat"), 50),
+        ("repetition", include_str!("../testdata/repetition.dat"), 91),
+    ];
+    for &(name, data, count) in files.iter() {
+        let tests = conformance::parse(name, data);
+        assert_eq!(tests.len(), count);
+        let failures = conformance::run(tests.as_slice(),
+                                        conformance::search_dynamic);
+        assert!(failures.is_empty(), "{}", failures);
+    }
+
+    let tests = conformance::parse("t", "E\ta(b)\tab\t(0,2)(1,1)\n\
+                                         BE\tSAME\tb\tNOMATCH\n\
+                                         E\tx{2\tx\tBADBR\n\
+                                         Ei\tA\ta\t(0,1)\n\
+                                         E$\ta\\n\ta\\n\t(0,2)\n");
+    let names: Vec<&str> = tests.iter().map(|t| t.name.as_slice()).collect();
+    assert_eq!(names, vec!("t_1", "t_2", "t_4", "t_5"));
+    assert_eq!(tests.get(1).pattern, ~"a(b)");
+    assert_eq!(tests.get(2).pattern, ~"(?i)A");
+    assert_eq!(tests.get(3).text, ~"a\n");
+    let failures = conformance::run(tests.as_slice(),
+                                    conformance::search_dynamic);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures.get(0).got,
+               Ok(Some(vec!(Some((0, 2)), Some((1, 2))))));
+}
 
 // A whole mess of tests from Glenn Fowler's regex test suite.
 // Generated by the 'src/etc/regex-match-tests' program.
@@ -33,15 +33,20 @@
 //
 // [1] - http://swtch.com/~rsc/regex/regex3.html
 
+use collections::HashSet;
 use std::cmp;
 use std::mem;
 use std::slice::MutableVector;
+use sync::Mutex;
 use compile::{
     Program,
     Match, OneChar, CharClass, Any, EmptyBegin, EmptyEnd, EmptyWordBoundary,
-    Save, Jump, Split,
+    Save, OneBackref, LookStart, AtomicStart, LookEnd, Jump, Split,
+    CountedLoop, CountedNext,
 };
-use parse::{FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED};
+use literals::find_prefix;
+use parse::{FLAG_NOCASE, FLAG_MULTI, FLAG_DOTNL, FLAG_NEGATED,
+            FLAG_UNICODE, FLAG_FINAL_NEWLINE};
 use parse::unicode::PERLW;
 
 pub type CaptureLocs = Vec<Option<uint>>;
@@ -68,25 +73,219 @@ pub enum MatchKind {
 /// locations of each submatch.
 pub fn run<'r, 't>(which: MatchKind, prog: &'r Program, input: &'t str,
                    start: uint, end: uint) -> CaptureLocs {
+    run_scratch(which, prog, &mut Scratch::new(prog), input, start, end)
+}
+
+/// Does the same as `run`, except the thread queues in `scratch` are used
+/// instead of allocating new ones. `scratch` must have been created for
+/// `prog`.
+pub fn run_scratch<'r, 't>(which: MatchKind, prog: &'r Program,
+                           scratch: &mut Scratch, input: &'t str,
+                           start: uint, end: uint) -> CaptureLocs {
     Nfa {
         which: which,
         prog: prog,
-        input: input,
+        input: Some(input),
+        start: start,
+        end: end,
+        ic: 0,
+        chars: CharReader::new(input),
+        slot_map: None,
+    }.run(scratch)
+}
+
+/// Does the same as `run_scratch`, except the search text is read from
+/// `input` (e.g., a `ChunkReader`). `start` and `end` are byte indices into
+/// its text.
+///
+/// The optimizations that look for literals in the text are skipped, since
+/// they need it to be a contiguous string.
+pub fn run_input<'r, I: Input>(which: MatchKind, prog: &'r Program,
+                               scratch: &mut Scratch, input: I,
+                               start: uint, end: uint) -> CaptureLocs {
+    Nfa {
+        which: which,
+        prog: prog,
+        input: None,
+        start: start,
+        end: end,
+        ic: 0,
+        chars: input,
+        slot_map: None,
+    }.run(scratch)
+}
+
+/// Searches for submatches like `run_scratch`, except only the save slots
+/// listed in `slots` are tracked. The others are `None` in the locations
+/// returned, and threads don't have to copy them. `slots` must be sorted and
+/// begin with `0` and `1` (the slots for the entire match).
+pub fn run_slots<'r, 't>(prog: &'r Program, scratch: &mut Scratch,
+                         slots: &[uint], input: &'t str,
+                         start: uint, end: uint) -> CaptureLocs {
+    let nslots = prog.num_slots();
+    let mut map = Vec::from_elem(nslots, None);
+    for (i, &slot) in slots.iter().enumerate() {
+        *map.get_mut(slot) = Some(i);
+    }
+    let locs = Nfa {
+        which: Submatches,
+        prog: prog,
+        input: Some(input),
         start: start,
         end: end,
         ic: 0,
         chars: CharReader::new(input),
-    }.run()
+        slot_map: Some(map),
+    }.run(scratch);
+    let mut all = Vec::from_elem(nslots, None);
+    for (&slot, &loc) in slots.iter().zip(locs.iter()) {
+        *all.get_mut(slot) = loc;
+    }
+    all
+}
+
+/// Runs the reverse of a program backwards from `end`, anchored at `end`.
+/// The starting position of the longest match that ends at `end` (and
+/// starts no earlier than `start`) is returned. (This is the leftmost start
+/// of any match ending at `end`.)
+///
+/// When `end` is known to be the end of the leftmost-first match, the
+/// position returned is where that match starts. This is how the start of a
+/// match is found without tracking any capture groups in the forward
+/// direction.
+///
+/// `prog` must be a reverse program (i.e., `Program.reverse`). `scratch`
+/// may be the same as the one used for the forward program.
+pub fn run_reverse<'r, 't>(prog: &'r Program, scratch: &mut Scratch,
+                       input: &'t str, start: uint, end: uint)
+                      -> Option<uint> {
+    Nfa {
+        which: Location,
+        prog: prog,
+        input: Some(input),
+        start: start,
+        end: end,
+        ic: 0,
+        chars: CharReader::new_reverse(input),
+        slot_map: None,
+    }.run_reverse(scratch)
 }
 
-struct Nfa<'r, 't> {
+/// Searches backwards from `end` for the match that ends last (but no later
+/// than `end`), and returns its start and end. Preferences like greediness
+/// are applied as the input is read from right to left, so of the matches
+/// that end there, the one returned is the match the reverse program would
+/// find first. No match may start before `start`.
+///
+/// `prog` must be a reverse program (see `Program::new_reverse`).
+pub fn rfind<'r, 't>(prog: &'r Program, scratch: &mut Scratch,
+                     input: &'t str, start: uint, end: uint)
+                    -> Option<(uint, uint)> {
+    Nfa {
+        which: Location,
+        prog: prog,
+        input: Some(input),
+        start: start,
+        end: end,
+        ic: 0,
+        chars: CharReader::new_reverse(input),
+        slot_map: None,
+    }.rfind(scratch)
+}
+
+/// Returns true if a match of the program that starts at `start` could
+/// still be found if `input` went on past `end`. That is, when the program is
+/// run from `start` (only), some thread is left at `end` waiting to read
+/// another character, or waiting on a word boundary assertion (which could
+/// hold once another character is read). It may be true even when no match
+/// is possible, but it's never false when one is.
+///
+/// The program must not need the backtracking engine.
+pub fn hits_end<'r, 't>(prog: &'r Program, scratch: &mut Scratch,
+                        input: &'t str, start: uint, end: uint) -> bool {
+    Nfa {
+        which: Exists,
+        prog: prog,
+        input: Some(input),
+        start: start,
+        end: end,
+        ic: 0,
+        chars: CharReader::new(input),
+        slot_map: None,
+    }.hits_end(scratch)
+}
+
+/// Scratch is the memory used by the NFA simulation during a search. It is
+/// sized for a particular program and can be reused by any number of
+/// searches with that program.
+///
+/// Allocating the thread queues is a significant cost for short searches
+/// (and for iterators that run many searches), which is why it's worth
+/// keeping them around.
+pub struct Scratch {
+    clist: Threads,
+    nlist: Threads,
+}
+
+impl Scratch {
+    /// Allocates thread queues large enough to run `prog` with any kind of
+    /// match.
+    pub fn new(prog: &Program) -> Scratch {
+        let (ninsts, nslots) = (prog.insts.len(), prog.num_slots());
+        let ncounters = prog.num_counters();
+        Scratch {
+            clist: Threads::new(ninsts, nslots, ncounters),
+            nlist: Threads::new(ninsts, nslots, ncounters),
+        }
+    }
+}
+
+/// ScratchPool keeps the scratch memory of finished searches so that later
+/// searches with the same program can reuse it.
+///
+/// Each search takes scratch memory out of the pool for as long as it runs,
+/// so many searches can run at the same time (e.g., in different tasks)
+/// without sharing any. The pool is only locked while taking or returning
+/// scratch memory.
+pub struct ScratchPool {
+    pool: Mutex<Vec<Scratch>>,
+}
+
+impl ScratchPool {
+    /// Creates an empty pool.
+    pub fn new() -> ScratchPool {
+        ScratchPool { pool: Mutex::new(vec!()) }
+    }
+
+    /// Takes scratch memory for `prog` out of the pool, or allocates new
+    /// memory if there is none. It should be returned with `put` when the
+    /// search is done.
+    pub fn get(&self, prog: &Program) -> Scratch {
+        match self.pool.lock().pop() {
+            Some(scratch) => scratch,
+            None => Scratch::new(prog),
+        }
+    }
+
+    /// Returns scratch memory to the pool.
+    pub fn put(&self, scratch: Scratch) {
+        self.pool.lock().push(scratch)
+    }
+}
+
+struct Nfa<'r, 't, I> {
     which: MatchKind,
     prog: &'r Program,
-    input: &'t str,
+    // The search text, if it's contiguous. (The literal optimizations can't
+    // be used otherwise.)
+    input: Option<&'t str>,
     start: uint,
     end: uint,
     ic: uint,
-    chars: CharReader<'t>,
+    chars: I,
+    // Maps each save slot to where it's kept in a thread, if only some of
+    // them are tracked. (See `run_slots`.)
+    slot_map: Option<Vec<Option<uint>>>,
 }
 
 /// Indicates the next action to take after a single non-empty instruction
@@ -104,19 +303,71 @@ pub enum StepState {
     StepContinue,
 }
 
-impl<'r, 't> Nfa<'r, 't> {
-    fn run(&mut self) -> CaptureLocs {
-        let ncaps = match self.which {
+impl<'r, 't, I: Input> Nfa<'r, 't, I> {
+    fn run(&mut self, scratch: &mut Scratch) -> CaptureLocs {
+        let nslots = match self.which {
             Exists => 0,
-            Location => 1,
-            Submatches => self.prog.num_captures(),
+            Location => 2,
+            Submatches => match self.slot_map {
+                None => self.prog.num_slots(),
+                Some(ref map) => map.iter().filter(|i| i.is_some()).count(),
+            },
         };
+
+        // If every match must end at the end of the input, then run the
+        // reverse program backwards from the end to find where the match
+        // starts. (If there's a literal suffix, check for it first.) Only
+        // submatches require running the forward program, and it can start
+        // right at the beginning of the match.
+        if self.prog.anchored_end && self.input.is_some() {
+            let (prog, input) = (self.prog, self.input.unwrap());
+            let suffix = prog.suffix.as_slice();
+            if self.end != input.len()
+               || !input.slice(self.start, self.end).ends_with(suffix) {
+                return self.no_match()
+            }
+            let rev = &**prog.reverse.get_ref();
+            match run_reverse(rev, scratch, input, self.start, self.end) {
+                None => return self.no_match(),
+                Some(s) => match self.which {
+                    Exists => return vec![Some(0), Some(0)],
+                    Location => return vec![Some(s), Some(self.end)],
+                    Submatches => self.start = s,
+                },
+            }
+        }
+
+        // If every match must contain an inner literal, then look for it
+        // before doing anything else. If it's there and the number of bytes
+        // that can precede it in a match is bounded, then no match can start
+        // before a certain point, so skip ahead to it.
+        if self.prog.inner.len() > 0 && self.input.is_some() {
+            let (prog, input) = (self.prog, self.input.unwrap());
+            let needle = prog.inner.as_slice().as_bytes();
+            let haystack = input.as_bytes().slice(self.start, self.end);
+            match find_prefix(needle, prog.inner_skip.as_slice(), haystack) {
+                None => return self.no_match(),
+                Some(i) => match prog.inner_offset {
+                    Some(offset) if i > offset => {
+                        let mut s = self.start + i - offset;
+                        while !input.is_char_boundary(s) {
+                            s -= 1;
+                        }
+                        self.start = s;
+                    }
+                    _ => {}
+                },
+            }
+        }
+
         let mut matched = false;
-        let ninsts = self.prog.insts.len();
-        let mut clist = &mut Threads::new(self.which, ninsts, ncaps);
-        let mut nlist = &mut Threads::new(self.which, ninsts, ncaps);
+        let mut clist = &mut scratch.clist;
+        let mut nlist = &mut scratch.nlist;
+        clist.reset(self.which);
+        nlist.reset(self.which);
 
-        let mut groups = Vec::from_elem(ncaps * 2, None);
+        let mut groups = Vec::from_elem(nslots, None);
+        let mut counts = Vec::from_elem(clist.ncounters, 0u);
 
         // Determine if the expression starts with a '^' so we can avoid
         // simulating .*?
@@ -127,6 +378,7 @@ impl<'r, 't> Nfa<'r, 't> {
                 EmptyBegin(flags) if flags & FLAG_MULTI == 0 => true,
                 _ => false,
             };
+        let anchored = prefix_anchor || self.prog.anchored_start;
 
         self.ic = self.start;
         let mut next_ic = self.chars.set(self.start);
@@ -138,15 +390,20 @@ impl<'r, 't> Nfa<'r, 't> {
                     break
                 }
 
+                // An anchored search can't start a match anywhere else.
+                if self.prog.anchored_start && self.ic > self.start {
+                    break
+                }
+
                 // If there are no threads to try, then we'll have to start
                 // over at the beginning of the regex.
                 // BUT, if there's a literal prefix for the program, try to
                 // jump ahead quickly. If it can't be found, then we can bail
                 // out early.
-                if self.prog.prefix.len() > 0 && clist.size == 0 {
-                    let needle = self.prog.prefix.as_slice().as_bytes();
-                    let haystack = self.input.as_bytes().slice_from(self.ic);
-                    match find_prefix(needle, haystack) {
+                if self.prog.has_prefix() && self.input.is_some() {
+                    let input = self.input.unwrap();
+                    let haystack = input.as_bytes().slice_from(self.ic);
+                    match self.prog.find_prefix(haystack) {
                         None => break,
                         Some(i) => {
                             self.ic += i;
@@ -159,8 +416,9 @@ impl<'r, 't> Nfa<'r, 't> {
             // This simulates a preceding '.*?' for every regex by adding
             // a state starting at the current position in the input for the
             // beginning of the program only if we don't already have a match.
-            if clist.size == 0 || (!prefix_anchor && !matched) {
-                self.add(clist, 0, groups.as_mut_slice())
+            // (An anchored search only adds it at the start.)
+            if clist.size == 0 || (!anchored && !matched) {
+                self.add(clist, 0, groups.as_mut_slice(), counts.as_mut_slice())
             }
 
             // Now we try to read the next character.
@@ -172,8 +430,10 @@ impl<'r, 't> Nfa<'r, 't> {
             let mut i = 0;
             while i < clist.size {
                 let pc = clist.pc(i);
-                let step_state = self.step(groups.as_mut_slice(), nlist,
-                                           clist.groups(i), pc);
+                let step_state = {
+                    let (caps, counts) = clist.thread(i);
+                    self.step(groups.as_mut_slice(), nlist, caps, counts, pc)
+                };
                 match step_state {
                     StepMatchEarlyReturn => return vec![Some(0), Some(0)],
                     StepMatch => { matched = true; clist.empty() },
@@ -191,8 +451,158 @@ impl<'r, 't> Nfa<'r, 't> {
         }
     }
 
+    // Returns the capture locations for a search that didn't match.
+    fn no_match(&self) -> CaptureLocs {
+        match self.which {
+            Exists | Location => vec![None, None],
+            Submatches => Vec::from_elem(self.prog.num_slots(), None),
+        }
+    }
+
+    // This is like `run`, except it expects a reverse program and a
+    // `CharReader` that reads backwards. The search is anchored at `end` and
+    // keeps going until every thread dies, so that the longest match (i.e.,
+    // the one that starts earliest) is found.
+    fn run_reverse(&mut self, scratch: &mut Scratch) -> Option<uint> {
+        let mut clist = &mut scratch.clist;
+        let mut nlist = &mut scratch.nlist;
+        clist.reset(Location);
+        nlist.reset(Location);
+
+        let mut groups = vec![None, None];
+        let mut counts = Vec::from_elem(clist.ncounters, 0u);
+        let mut found = None;
+
+        self.ic = self.end;
+        let mut next_ic = self.chars.set(self.end);
+        self.add(clist, 0, groups.as_mut_slice(), counts.as_mut_slice());
+        while clist.size > 0 {
+            // Once we've reached `start`, the only thing left to do is to
+            // check for threads that have matched.
+            let done = self.ic <= self.start;
+            self.ic = next_ic;
+            next_ic = self.chars.advance();
+
+            let mut i = 0;
+            while i < clist.size {
+                let pc = clist.pc(i);
+                let step_state = {
+                    let (caps, counts) = clist.thread(i);
+                    self.step(groups.as_mut_slice(), nlist, caps, counts, pc)
+                };
+                match step_state {
+                    StepMatch => found = groups[0],
+                    StepMatchEarlyReturn | StepContinue => {}
+                }
+                i += 1;
+            }
+            mem::swap(&mut clist, &mut nlist);
+            nlist.empty();
+            if done {
+                break
+            }
+        }
+        found
+    }
+
+    // This runs the program from `start` only, until every thread dies or
+    // `end` is reached. (See `hits_end`.)
+    fn hits_end(&mut self, scratch: &mut Scratch) -> bool {
+        let mut clist = &mut scratch.clist;
+        let mut nlist = &mut scratch.nlist;
+        clist.reset(Exists);
+        nlist.reset(Exists);
+
+        let mut groups = vec!();
+        let mut counts = Vec::from_elem(clist.ncounters, 0u);
+
+        self.ic = self.start;
+        let mut next_ic = self.chars.set(self.start);
+        self.add(clist, 0, groups.as_mut_slice(), counts.as_mut_slice());
+        while clist.size > 0 {
+            if self.ic >= self.end {
+                return range(0, clist.size).any(|i| {
+                    match *self.prog.insts.get(clist.pc(i)) {
+                        OneChar(_, _) | CharClass(_, _) | Any(_)
+                        | EmptyWordBoundary(_) => true,
+                        _ => false,
+                    }
+                })
+            }
+            self.ic = next_ic;
+            next_ic = self.chars.advance();
+
+            let mut i = 0;
+            while i < clist.size {
+                let pc = clist.pc(i);
+                // A match doesn't stop the search, since what's wanted is
+                // whether it could go on.
+                {
+                    let (caps, counts) = clist.thread(i);
+                    self.step(groups.as_mut_slice(), nlist, caps, counts, pc);
+                }
+                i += 1;
+            }
+            mem::swap(&mut clist, &mut nlist);
+            nlist.empty();
+        }
+        false
+    }
+
+    // This is like `run_reverse`, except the search isn't anchored at `end`.
+    // A thread is started at every position until there's a match, and the
+    // threads started earlier (i.e., further to the right) are preferred.
+    fn rfind(&mut self, scratch: &mut Scratch) -> Option<(uint, uint)> {
+        let mut clist = &mut scratch.clist;
+        let mut nlist = &mut scratch.nlist;
+        clist.reset(Location);
+        nlist.reset(Location);
+
+        let mut groups = vec![None, None];
+        let mut counts = Vec::from_elem(clist.ncounters, 0u);
+        let mut matched = false;
+
+        self.ic = self.end;
+        let mut next_ic = self.chars.set(self.end);
+        loop {
+            if clist.size == 0 && matched {
+                break
+            }
+            if !matched {
+                self.add(clist, 0, groups.as_mut_slice(), counts.as_mut_slice())
+            }
+            let done = self.ic <= self.start;
+            self.ic = next_ic;
+            next_ic = self.chars.advance();
+
+            let mut i = 0;
+            while i < clist.size {
+                let pc = clist.pc(i);
+                let step_state = {
+                    let (caps, counts) = clist.thread(i);
+                    self.step(groups.as_mut_slice(), nlist, caps, counts, pc)
+                };
+                match step_state {
+                    StepMatch => { matched = true; clist.empty() }
+                    StepMatchEarlyReturn | StepContinue => {}
+                }
+                i += 1;
+            }
+            mem::swap(&mut clist, &mut nlist);
+            nlist.empty();
+            if done {
+                break
+            }
+        }
+        if matched {
+            Some((groups[0].unwrap(), groups[1].unwrap()))
+        } else {
+            None
+        }
+    }
+
     fn step(&self, groups: &mut [Option<uint>], nlist: &mut Threads,
-            caps: &mut [Option<uint>], pc: uint)
+            caps: &mut [Option<uint>], counts: &mut [uint], pc: uint)
            -> StepState {
         match *self.prog.insts.get(pc) {
             Match => {
@@ -214,37 +624,40 @@ impl<'r, 't> Nfa<'r, 't> {
                 }
             }
             OneChar(c, flags) => {
-                if self.char_eq(flags & FLAG_NOCASE > 0, self.chars.prev, c) {
-                    self.add(nlist, pc+1, caps);
+                if self.char_eq(flags & FLAG_NOCASE > 0, self.chars.prev(), c) {
+                    self.add(nlist, pc+1, caps, counts);
                 }
             }
             CharClass(ref ranges, flags) => {
-                if self.chars.prev.is_some() {
-                    let c = self.chars.prev.unwrap();
+                if self.chars.prev().is_some() {
+                    let c = self.chars.prev().unwrap();
                     let negate = flags & FLAG_NEGATED > 0;
                     let casei = flags & FLAG_NOCASE > 0;
                     let found = ranges.as_slice();
                     let found = found.bsearch(|&rc| class_cmp(casei, c, rc));
                     let found = found.is_some();
                     if (found && !negate) || (!found && negate) {
-                        self.add(nlist, pc+1, caps);
+                        self.add(nlist, pc+1, caps, counts);
                     }
                 }
             }
             Any(flags) => {
                 if flags & FLAG_DOTNL > 0
-                   || !self.char_eq(false, self.chars.prev, '\n') {
-                    self.add(nlist, pc+1, caps)
+                   || !self.char_eq(false, self.chars.prev(), '\n') {
+                    self.add(nlist, pc+1, caps, counts)
                 }
             }
             EmptyBegin(_) | EmptyEnd(_) | EmptyWordBoundary(_)
-            | Save(_) | Jump(_) | Split(_, _) => {},
+            | Save(_) | OneBackref(_, _) | LookStart(_, _, _) | AtomicStart(_)
+            | LookEnd | Jump(_) | Split(_, _) | CountedLoop(_, _, _, _, _)
+            | CountedNext(_, _, _) => {},
         }
         StepContinue
     }
 
-    fn add(&self, nlist: &mut Threads, pc: uint, groups: &mut [Option<uint>]) {
-        if nlist.contains(pc) {
+    fn add(&self, nlist: &mut Threads, pc: uint, groups: &mut [Option<uint>],
+           counts: &mut [uint]) {
+        if nlist.contains(pc, counts) {
             return
         }
         // We have to add states to the threads list even if their empty.
@@ -264,59 +677,122 @@ impl<'r, 't> Nfa<'r, 't> {
         match *self.prog.insts.get(pc) {
             EmptyBegin(flags) => {
                 let multi = flags & FLAG_MULTI > 0;
-                nlist.add(pc, groups, true);
+                nlist.add(pc, groups, counts, true);
                 if self.chars.is_begin()
-                   || (multi && self.char_is(self.chars.prev, '\n')) {
-                    self.add(nlist, pc + 1, groups)
+                   || (multi && self.char_is(self.chars.prev(), '\n')) {
+                    self.add(nlist, pc + 1, groups, counts)
+                }
+            }
+            EmptyEnd(flags) if flags & FLAG_FINAL_NEWLINE > 0 => {
+                nlist.add(pc, groups, counts, true);
+                if self.chars.is_end_or_final_newline() {
+                    self.add(nlist, pc + 1, groups, counts)
                 }
             }
             EmptyEnd(flags) => {
                 let multi = flags & FLAG_MULTI > 0;
-                nlist.add(pc, groups, true);
+                nlist.add(pc, groups, counts, true);
                 if self.chars.is_end()
-                   || (multi && self.char_is(self.chars.cur, '\n')) {
-                    self.add(nlist, pc + 1, groups)
+                   || (multi && self.char_is(self.chars.cur(), '\n')) {
+                    self.add(nlist, pc + 1, groups, counts)
                 }
             }
             EmptyWordBoundary(flags) => {
-                nlist.add(pc, groups, true);
-                if self.chars.is_word_boundary() == !(flags & FLAG_NEGATED > 0) {
-                    self.add(nlist, pc + 1, groups)
+                nlist.add(pc, groups, counts, true);
+                let boundary =
+                    if flags & FLAG_UNICODE > 0 {
+                        self.chars.is_word_boundary()
+                    } else {
+                        self.chars.is_ascii_word_boundary()
+                    };
+                if boundary == !(flags & FLAG_NEGATED > 0) {
+                    self.add(nlist, pc + 1, groups, counts)
                 }
             }
             Save(slot) => {
-                nlist.add(pc, groups, true);
+                nlist.add(pc, groups, counts, true);
                 match self.which {
                     Location if slot <= 1 => {
                         let old = groups[slot];
                         groups[slot] = Some(self.ic);
-                        self.add(nlist, pc + 1, groups);
-                        groups[slot] = old;
-                    }
-                    Submatches => {
-                        let old = groups[slot];
-                        groups[slot] = Some(self.ic);
-                        self.add(nlist, pc + 1, groups);
+                        self.add(nlist, pc + 1, groups, counts);
                         groups[slot] = old;
                     }
-                    Exists | Location => self.add(nlist, pc + 1, groups),
+                    Submatches => match self.slot_index(slot) {
+                        None => self.add(nlist, pc + 1, groups, counts),
+                        Some(i) => {
+                            let old = groups[i];
+                            groups[i] = Some(self.ic);
+                            self.add(nlist, pc + 1, groups, counts);
+                            groups[i] = old;
+                        }
+                    },
+                    Exists | Location =>
+                        self.add(nlist, pc + 1, groups, counts),
                 }
             }
             Jump(to) => {
-                nlist.add(pc, groups, true);
-                self.add(nlist, to, groups)
+                nlist.add(pc, groups, counts, true);
+                self.add(nlist, to, groups, counts)
             }
             Split(x, y) => {
-                nlist.add(pc, groups, true);
-                self.add(nlist, x, groups);
-                self.add(nlist, y, groups);
+                nlist.add(pc, groups, counts, true);
+                self.add(nlist, x, groups, counts);
+                self.add(nlist, y, groups, counts);
+            }
+            CountedLoop(k, min, max, greedy, exit) => {
+                nlist.add(pc, groups, counts, true);
+                let n = counts[k];
+                if n < min {
+                    self.add(nlist, pc + 1, groups, counts)
+                } else if max == Some(n) {
+                    self.exit_loop(nlist, k, exit, groups, counts)
+                } else if greedy {
+                    self.add(nlist, pc + 1, groups, counts);
+                    self.exit_loop(nlist, k, exit, groups, counts);
+                } else {
+                    self.exit_loop(nlist, k, exit, groups, counts);
+                    self.add(nlist, pc + 1, groups, counts);
+                }
+            }
+            CountedNext(k, limit, start) => {
+                nlist.add(pc, groups, counts, true);
+                let n = counts[k];
+                counts[k] = cmp::min(n + 1, limit);
+                self.add(nlist, start, groups, counts);
+                counts[k] = n;
             }
             Match | OneChar(_, _) | CharClass(_, _) | Any(_) => {
-                nlist.add(pc, groups, false);
+                nlist.add(pc, groups, counts, false);
             }
+            // Programs with backreferences, lookarounds or atomic groups are
+            // run by the backtracking engine instead. (See `backtrack.rs`.)
+            OneBackref(_, _) | LookStart(_, _, _) | AtomicStart(_)
+            | LookEnd => unreachable!(),
+        }
+    }
+
+    // Returns where a save slot is kept in a thread's capture locations, or
+    // `None` if it isn't tracked.
+    #[inline]
+    fn slot_index(&self, slot: uint) -> Option<uint> {
+        match self.slot_map {
+            None => Some(slot),
+            Some(ref map) => *map.get(slot),
         }
     }
 
+    // Leaves the counted loop with counter `k` for the instruction at `exit`.
+    // The counter is reset, so that every thread that isn't in the loop has
+    // the same counter.
+    fn exit_loop(&self, nlist: &mut Threads, k: uint, exit: uint,
+                 groups: &mut [Option<uint>], counts: &mut [uint]) {
+        let n = counts[k];
+        counts[k] = 0;
+        self.add(nlist, exit, groups, counts);
+        counts[k] = n;
+    }
+
     // FIXME: For case insensitive comparisons, it uses the uppercase
     // character and tests for equality. IIUC, this does not generalize to
     // all of Unicode. I believe we need to check the entire fold for each
@@ -342,6 +818,10 @@ impl<'r, 't> Nfa<'r, 't> {
 /// CharReader is responsible for maintaining a "previous" and a "current"
 /// character. This one-character lookahead is necessary for assertions that
 /// look one character before or after the current position.
+///
+/// A CharReader can also read the input backwards (see `new_reverse`), in
+/// which case the "previous" character is the one *after* the current
+/// position and the "current" character is the one before it.
 pub struct CharReader<'t> {
     /// The previous character read. It is None only when processing the first
     /// character of the input.
@@ -350,6 +830,7 @@ pub struct CharReader<'t> {
     pub cur: Option<char>,
     input: &'t str,
     next: uint,
+    reverse: bool,
 }
 
 impl<'t> CharReader<'t> {
@@ -362,13 +843,25 @@ impl<'t> CharReader<'t> {
             cur: None,
             input: input,
             next: 0,
+            reverse: false,
        }
     }
 
+    /// Returns a new CharReader that reads the input given backwards.
+    /// `set` and `advance` return the byte index of the start of the current
+    /// character instead of the end. (This is `0` when there are no more
+    /// characters to read.)
+    pub fn new_reverse(input: &'t str) -> CharReader<'t> {
+        CharReader { reverse: true, ..CharReader::new(input) }
+    }
+
     /// Sets the previous and current character given any arbitrary byte
     /// index (at a unicode codepoint boundary).
     #[inline]
     pub fn set(&mut self, ic: uint) -> uint {
+        if self.reverse {
+            return self.set_reverse(ic)
+        }
         self.prev = None;
         self.cur = None;
         self.next = 0;
@@ -395,6 +888,9 @@ impl<'t> CharReader<'t> {
     /// character in the input (and therefore does half as many UTF8 decodings).
     #[inline]
     pub fn advance(&mut self) -> uint {
+        if self.reverse {
+            return self.advance_reverse()
+        }
         self.prev = self.cur;
         if self.next < self.input.len() {
             let cur = self.input.char_range_at(self.next);
@@ -407,6 +903,35 @@ impl<'t> CharReader<'t> {
         self.next
     }
 
+    fn set_reverse(&mut self, ic: uint) -> uint {
+        self.prev = None;
+        self.cur = None;
+        self.next = 0;
+
+        if ic < self.input.len() {
+            self.prev = Some(self.input.char_at(ic));
+        }
+        if ic > 0 {
+            let i = cmp::min(ic, self.input.len());
+            let cur = self.input.char_range_at_reverse(i);
+            self.cur = Some(cur.ch);
+            self.next = cur.next;
+        }
+        self.next
+    }
+
+    fn advance_reverse(&mut self) -> uint {
+        self.prev = self.cur;
+        if self.next > 0 {
+            let cur = self.input.char_range_at_reverse(self.next);
+            self.cur = Some(cur.ch);
+            self.next = cur.next;
+        } else {
+            self.cur = None;
+        }
+        self.next
+    }
+
     /// Returns true if and only if this is the beginning of the input
     /// (ignoring the range of the input to search).
     #[inline]
@@ -417,9 +942,41 @@ impl<'t> CharReader<'t> {
     #[inline]
     pub fn is_end(&self) -> bool { self.cur.is_none() }
 
+    /// Returns true if and only if this is the end of the input or just
+    /// before a newline that ends the input (ignoring the range of the input
+    /// to search). Unlike `is_end`, this doesn't depend on the direction
+    /// that the input is read in.
+    pub fn is_end_or_final_newline(&self) -> bool {
+        // The character to the right of the current position and the
+        // position itself.
+        let (right, ic) =
+            if self.reverse {
+                let len = self.cur.map_or(0, |c| c.len_utf8_bytes());
+                (self.prev, self.next + len)
+            } else {
+                let len = self.cur.map_or(0, |c| c.len_utf8_bytes());
+                (self.cur, self.next - len)
+            };
+        match right {
+            None => true,
+            Some('\n') => ic + 1 == self.input.len(),
+            Some(_) => false,
+        }
+    }
+
     /// Returns true if and only if the current position is a word boundary.
     /// (Ignoring the range of the input to search.)
     pub fn is_word_boundary(&self) -> bool {
+        self.is_boundary(is_word)
+    }
+
+    /// Returns true if and only if the current position is a word boundary,
+    /// where only `[0-9A-Za-z_]` are word characters.
+    pub fn is_ascii_word_boundary(&self) -> bool {
+        self.is_boundary(is_ascii_word)
+    }
+
+    fn is_boundary(&self, is_word: fn(Option<char>) -> bool) -> bool {
         if self.is_begin() {
             return is_word(self.cur)
         }
@@ -431,9 +988,211 @@ impl<'t> CharReader<'t> {
     }
 }
 
+/// Input is a source of text that the VM can search, read one character at
+/// a time. The text doesn't have to be a contiguous string (see
+/// `Regex::is_match_input`).
+///
+/// Positions are byte indices into the text as if it were encoded as UTF-8.
+/// The reader is at a position between two characters: the "previous" one
+/// before it and the "current" one after it.
+pub trait Input {
+    /// Returns the length of the text in bytes.
+    fn len(&self) -> uint;
+
+    /// Returns the current position.
+    fn pos(&self) -> uint;
+
+    /// Moves to position `ic`, which is on a character boundary (or past
+    /// the end of the text). Returns the position after the current
+    /// character, or one past the end of the text if there isn't one.
+    fn set(&mut self, ic: uint) -> uint;
+
+    /// Moves past the current character, which becomes the previous one,
+    /// and returns what `set` would. (So at the end of the text, there's no
+    /// previous character afterward either.)
+    fn advance(&mut self) -> uint;
+
+    /// Returns the character before the current position, or `None` at the
+    /// beginning of the text.
+    fn prev(&self) -> Option<char>;
+
+    /// Returns the character after the current position, or `None` at the
+    /// end of the text.
+    fn cur(&self) -> Option<char>;
+
+    /// Returns true if and only if this is the beginning of the text.
+    fn is_begin(&self) -> bool { self.prev().is_none() }
+
+    /// Returns true if and only if this is the end of the text.
+    fn is_end(&self) -> bool { self.cur().is_none() }
+
+    /// Returns true if and only if this is the end of the text or just
+    /// before a newline that ends the text.
+    fn is_end_or_final_newline(&self) -> bool {
+        match self.cur() {
+            None => true,
+            Some('\n') => self.pos() + 1 == self.len(),
+            Some(_) => false,
+        }
+    }
+
+    /// Returns true if and only if the current position is a word boundary.
+    fn is_word_boundary(&self) -> bool {
+        is_word(self.cur()) != is_word(self.prev())
+    }
+
+    /// Returns true if and only if the current position is a word boundary,
+    /// where only `[0-9A-Za-z_]` are word characters.
+    fn is_ascii_word_boundary(&self) -> bool {
+        is_ascii_word(self.cur()) != is_ascii_word(self.prev())
+    }
+}
+
+impl<'t> Input for CharReader<'t> {
+    fn len(&self) -> uint { self.input.len() }
+
+    fn pos(&self) -> uint {
+        let len = self.cur.map_or(0, |c| c.len_utf8_bytes());
+        if self.reverse {
+            self.next + len
+        } else if self.cur.is_some() {
+            self.next - len
+        } else {
+            self.input.len()
+        }
+    }
+
+    fn set(&mut self, ic: uint) -> uint { self.set(ic) }
+    fn advance(&mut self) -> uint { self.advance() }
+    fn prev(&self) -> Option<char> { self.prev }
+    fn cur(&self) -> Option<char> { self.cur }
+
+    // A reverse reader's current character is before its position.
+    fn is_end_or_final_newline(&self) -> bool {
+        self.is_end_or_final_newline()
+    }
+}
+
+/// ChunkReader is like a CharReader (reading forwards), except the input is
+/// a list of strings that are read as if they were joined together. Byte
+/// indices are into the joined text.
+pub struct ChunkReader<'t> {
+    prev: Option<char>,
+    cur: Option<char>,
+    chunks: &'t [&'t str],
+    // The index in the joined text where each chunk starts.
+    starts: Vec<uint>,
+    len: uint,
+    // The chunk that holds the current character.
+    chunk: uint,
+    next: uint,
+}
+
+impl<'t> ChunkReader<'t> {
+    /// Returns a new ChunkReader for the chunks given.
+    pub fn new(chunks: &'t [&'t str]) -> ChunkReader<'t> {
+        let mut starts = Vec::with_capacity(chunks.len());
+        let mut len = 0;
+        for chunk in chunks.iter() {
+            starts.push(len);
+            len += chunk.len();
+        }
+        ChunkReader {
+            prev: None,
+            cur: None,
+            chunks: chunks,
+            starts: starts,
+            len: len,
+            chunk: 0,
+            next: 0,
+        }
+    }
+
+    // Returns the chunk that holds the byte at index `i` (which must be less
+    // than the length of the text). Empty chunks are never returned.
+    fn locate(&self, i: uint) -> uint {
+        // Find the last chunk that starts at or before `i`.
+        let (mut lo, mut hi) = (0, self.chunks.len());
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if *self.starts.get(mid) <= i {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    // Makes the character at index `i` (which must be less than the length
+    // of the text) the current one. The current chunk must not be after
+    // the one that holds it.
+    fn read(&mut self, i: uint) {
+        while i >= *self.starts.get(self.chunk)
+                   + self.chunks[self.chunk].len() {
+            self.chunk += 1;
+        }
+        let start = *self.starts.get(self.chunk);
+        let cur = self.chunks[self.chunk].char_range_at(i - start);
+        self.cur = Some(cur.ch);
+        self.next = start + cur.next;
+    }
+}
+
+impl<'t> Input for ChunkReader<'t> {
+    fn len(&self) -> uint { self.len }
+
+    fn pos(&self) -> uint {
+        match self.cur {
+            None => self.len,
+            Some(c) => self.next - c.len_utf8_bytes(),
+        }
+    }
+
+    fn set(&mut self, ic: uint) -> uint {
+        self.prev = None;
+        self.cur = None;
+        self.next = 0;
+
+        if self.len == 0 {
+            return 1
+        }
+        if ic > 0 {
+            let i = cmp::min(ic, self.len);
+            let k = self.locate(i - 1);
+            let chunk = self.chunks[k];
+            let prev = chunk.char_range_at_reverse(i - *self.starts.get(k));
+            self.prev = Some(prev.ch);
+        }
+        if ic < self.len {
+            self.chunk = self.locate(ic);
+            self.read(ic);
+            self.next
+        } else {
+            self.len + 1
+        }
+    }
+
+    fn advance(&mut self) -> uint {
+        self.prev = self.cur;
+        if self.next < self.len {
+            let next = self.next;
+            self.read(next);
+        } else {
+            self.cur = None;
+            self.next = self.len + 1;
+        }
+        self.next
+    }
+
+    fn prev(&self) -> Option<char> { self.prev }
+    fn cur(&self) -> Option<char> { self.cur }
+}
+
 struct Thread {
     pc: uint,
     groups: Vec<Option<uint>>,
+    counts: Vec<uint>,
 }
 
 struct Threads {
@@ -441,6 +1200,11 @@ struct Threads {
     queue: Vec<Thread>,
     sparse: Vec<uint>,
     size: uint,
+    nslots: uint,
+    ncounters: uint,
+    // The instruction and counters of every thread, if the program has
+    // counted loops.
+    counted: HashSet<(uint, Vec<uint>)>,
 }
 
 impl Threads {
@@ -450,21 +1214,52 @@ impl Threads {
     // throughout execution. That is, there should be zero allocation during
     // the execution of a VM.
     //
+    // The queue is sized for the largest kind of match (i.e., `Submatches`)
+    // so that the same queue can be reused by subsequent searches regardless
+    // of what kind of match they ask for. (See `Scratch`.)
+    //
     // See http://research.swtch.com/sparse for the deets.
-    fn new(which: MatchKind, num_insts: uint, ncaps: uint) -> Threads {
+    //
+    // A thread is identified by its instruction *and* its counters, so a
+    // program with counted loops can have more than one thread at the same
+    // instruction (and more threads than instructions, in which case the
+    // queue grows). The sparse set can't tell those threads apart, so they
+    // are also kept in a hash set, which keeps lookup constant time no
+    // matter how many threads share an instruction. (Only programs with
+    // counted loops pay for its allocations.)
+    fn new(num_insts: uint, nslots: uint, ncounters: uint) -> Threads {
         Threads {
-            which: which,
-            queue: Vec::from_fn(num_insts, |_| {
-                Thread { pc: 0, groups: Vec::from_elem(ncaps * 2, None) }
-            }),
+            which: Exists,
+            queue: Vec::from_fn(num_insts, |_| Thread::new(nslots, ncounters)),
             sparse: Vec::from_elem(num_insts, 0u),
             size: 0,
+            nslots: nslots,
+            ncounters: ncounters,
+            counted: HashSet::new(),
         }
     }
 
-    fn add(&mut self, pc: uint, groups: &[Option<uint>], empty: bool) {
+    // Prepares the queue for a new search. Since the sparse set doesn't need
+    // to be cleared, this is constant time.
+    #[inline]
+    fn reset(&mut self, which: MatchKind) {
+        self.which = which;
+        self.empty();
+    }
+
+    fn add(&mut self, pc: uint, groups: &[Option<uint>], counts: &[uint],
+           empty: bool) {
+        if self.size == self.queue.len() {
+            self.queue.push(Thread::new(self.nslots, self.ncounters));
+        }
+        if self.ncounters > 0 {
+            self.counted.insert((pc, Vec::from_slice(counts)));
+        }
         let t = self.queue.get_mut(self.size);
         t.pc = pc;
+        for (count, &n) in t.counts.mut_iter().zip(counts.iter()) {
+            *count = n;
+        }
         match (empty, self.which) {
             (_, Exists) | (true, _) => {},
             (false, Location) => {
@@ -482,14 +1277,21 @@ impl Threads {
     }
 
     #[inline]
-    fn contains(&self, pc: uint) -> bool {
+    fn contains(&self, pc: uint, counts: &[uint]) -> bool {
         let s = *self.sparse.get(pc);
-        s < self.size && self.queue.get(s).pc == pc
+        if s >= self.size || self.queue.get(s).pc != pc {
+            return false
+        }
+        self.ncounters == 0
+        || self.counted.contains(&(pc, Vec::from_slice(counts)))
     }
 
     #[inline]
     fn empty(&mut self) {
         self.size = 0;
+        if self.ncounters > 0 {
+            self.counted.clear();
+        }
     }
 
     #[inline]
@@ -498,8 +1300,20 @@ impl Threads {
     }
 
     #[inline]
-    fn groups<'r>(&'r mut self, i: uint) -> &'r mut [Option<uint>] {
-        self.queue.get_mut(i).groups.as_mut_slice()
+    fn thread<'r>(&'r mut self, i: uint)
+                 -> (&'r mut [Option<uint>], &'r mut [uint]) {
+        let t = self.queue.get_mut(i);
+        (t.groups.as_mut_slice(), t.counts.as_mut_slice())
+    }
+}
+
+impl Thread {
+    fn new(nslots: uint, ncounters: uint) -> Thread {
+        Thread {
+            pc: 0,
+            groups: Vec::from_elem(nslots, None),
+            counts: Vec::from_elem(ncounters, 0u),
+        }
     }
 }
 
@@ -527,6 +1341,17 @@ pub fn is_word(c: Option<char>) -> bool {
     }
 }
 
+/// Returns true if the character is an ASCII word character, i.e., one of
+/// `[0-9A-Za-z_]`. This is used for word boundaries when the `u` flag is
+/// cleared.
+pub fn is_ascii_word(c: Option<char>) -> bool {
+    match c {
+        Some('_') | Some('0' .. '9') | Some('a' .. 'z') | Some('A' .. 'Z') =>
+            true,
+        _ => false,
+    }
+}
+
 /// Given a character and a single character class range, return an ordering
 /// indicating whether the character is less than the start of the range,
 /// in the range (inclusive) or greater than the end of the range.
@@ -535,8 +1360,8 @@ pub fn is_word(c: Option<char>) -> bool {
 ///
 /// This function is meant to be used with a binary search.
 #[inline]
-fn class_cmp(casei: bool, mut textc: char,
-             (mut start, mut end): (char, char)) -> Ordering {
+pub fn class_cmp(casei: bool, mut textc: char,
+                 (mut start, mut end): (char, char)) -> Ordering {
     if casei {
         // FIXME: This is pretty ridiculous. All of this case conversion
         // can be moved outside this function:
@@ -557,31 +1382,3 @@ fn class_cmp(casei: bool, mut textc: char,
         Less
     }
 }
-
-/// Returns the starting location of `needle` in `haystack`.
-/// If `needle` is not in `haystack`, then `None` is returned.
-///
-/// Note that this is using a naive substring algorithm.
-#[inline]
-pub fn find_prefix(needle: &[u8], haystack: &[u8]) -> Option<uint> {
-    let (hlen, nlen) = (haystack.len(), needle.len());
-    if nlen > hlen || nlen == 0 {
-        return None
-    }
-    let mut hayi = 0u;
-    'HAYSTACK: loop {
-        if hayi > hlen - nlen {
-            break
-        }
-        let mut nedi = 0;
-        while nedi < nlen {
-            if haystack[hayi+nedi] != needle[nedi] {
-                hayi += 1;
-                continue 'HAYSTACK
-            }
-            nedi += 1;
-        }
-        return Some(hayi)
-    }
-    None
-}